@@ -8,6 +8,7 @@ export interface StellarConfig {
   networkPassphrase: string;
   rpcUrl: string;
   contractId: string;
+  seriesId: number;
   ownerKeypair: Keypair;
 }
 
@@ -44,12 +45,19 @@ export function getStellarConfig(): StellarConfig {
     throw new Error('PREDICTION_MARKET_CONTRACT_ID is not set in environment variables');
   }
 
+  // Series 0 is the default series created by the contract constructor
+  const seriesId = Number(process.env.PREDICTION_MARKET_SERIES_ID || 0);
+  if (!Number.isInteger(seriesId) || seriesId < 0) {
+    throw new Error('PREDICTION_MARKET_SERIES_ID must be a non-negative integer');
+  }
+
   const ownerKeypair = Keypair.fromSecret(ownerSecretKey);
 
   return {
     networkPassphrase,
     rpcUrl,
     contractId,
+    seriesId,
     ownerKeypair,
   };
 }
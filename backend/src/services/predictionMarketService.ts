@@ -40,7 +40,9 @@ export class PredictionMarketService {
 
   async genesisStartRound(): Promise<TransactionResult> {
     try {
-      const tx = await this.client.genesis_start_round();
+      const tx = await this.client.genesis_start_round({
+        series_id: this.config.seriesId,
+      });
 
       const result = await submitAndWaitForTransaction(
         tx.toXDR(),
@@ -62,7 +64,9 @@ export class PredictionMarketService {
 
   async genesisLockRound(): Promise<TransactionResult> {
     try {
-      const tx = await this.client.genesis_lock_round();
+      const tx = await this.client.genesis_lock_round({
+        series_id: this.config.seriesId,
+      });
 
       const result = await submitAndWaitForTransaction(
         tx.toXDR(),
@@ -84,7 +88,9 @@ export class PredictionMarketService {
 
   async executeRound(): Promise<TransactionResult> {
     try {
-      const tx = await this.client.execute_round();
+      const tx = await this.client.execute_round({
+        series_id: this.config.seriesId,
+      });
 
       const result = await submitAndWaitForTransaction(
         tx.toXDR(),
@@ -106,7 +112,9 @@ export class PredictionMarketService {
 
   async getIsGenesisStarted(): Promise<boolean> {
     try {
-      const result = await this.client.get_is_genesis_started();
+      const result = await this.client.get_is_genesis_started({
+        series_id: this.config.seriesId,
+      });
       return Boolean(result.result);
     } catch (error) {
       console.error("Error in getIsGenesisStarted:", error);
@@ -116,7 +124,9 @@ export class PredictionMarketService {
 
   async getIsGenesisLocked(): Promise<boolean> {
     try {
-      const result = await this.client.get_is_genesis_locked();
+      const result = await this.client.get_is_genesis_locked({
+        series_id: this.config.seriesId,
+      });
       return Boolean(result.result);
     } catch (error) {
       console.error("Error in getIsGenesisLocked:", error);
@@ -126,7 +136,9 @@ export class PredictionMarketService {
 
   async getCurrentEpoch(): Promise<bigint> {
     try {
-      const result = await this.client.get_current_epoch();
+      const result = await this.client.get_current_epoch({
+        series_id: this.config.seriesId,
+      });
       return BigInt(result.result?.toString() || "0");
     } catch (error) {
       console.error("Error in getCurrentEpoch:", error);
@@ -136,7 +148,10 @@ export class PredictionMarketService {
 
   async getRound(epoch: bigint): Promise<RoundInfo> {
     try {
-      const result = await this.client.get_round({ epoch });
+      const result = await this.client.get_round({
+        series_id: this.config.seriesId,
+        epoch,
+      });
       const round = result.result;
 
       if (!round) {
@@ -176,7 +191,9 @@ export class PredictionMarketService {
 
   async getIntervalSeconds(): Promise<bigint> {
     try {
-      const result = await this.client.get_interval_seconds();
+      const result = await this.client.get_interval_seconds({
+        series_id: this.config.seriesId,
+      });
       return BigInt(result.result?.toString() || "0");
     } catch (error) {
       console.error("Error in getIntervalSeconds:", error);
@@ -0,0 +1,253 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, Symbol, Vec};
+use stellar_access::ownable::{set_owner, Ownable};
+use stellar_macros::{default_impl, only_owner};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketConfig {
+    pub asset: Symbol,
+    pub owner: Address,
+    pub interval_seconds: u64,
+    pub buffer_seconds: u64,
+    pub min_bet_amount: i128,
+    pub token: Address,
+    pub treasury_fee: u32,
+    pub flash_loan_fee: u32,
+    pub oracle: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketInfo {
+    pub id: u32,
+    pub address: Address,
+    pub asset: Symbol,
+    pub interval_seconds: u64,
+    pub token: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    WasmHash,
+    MarketCount,
+    Markets(u32),
+    MarketIds(Symbol, u64, Address),
+}
+
+/////////////////////// EVENTS //////////////////////////////////
+
+fn emit_wasm_hash_updated_event(e: &Env, wasm_hash: &BytesN<32>) {
+    let topics = (Symbol::new(e, "WASM_HASH_UPDATED"),);
+    e.events().publish(topics, wasm_hash.clone());
+}
+
+fn emit_market_deployed_event(e: &Env, market: &MarketInfo) {
+    let topics = (Symbol::new(e, "MARKET_DEPLOYED"), market.id);
+    e.events().publish(
+        topics,
+        (
+            market.address.clone(),
+            market.asset.clone(),
+            market.interval_seconds,
+            market.token.clone(),
+        ),
+    );
+}
+
+// Maximum number of markets returned by one page of `list_markets`
+const MAX_PAGE_SIZE: u32 = 50;
+
+#[contract]
+pub struct MarketFactory;
+
+#[contractimpl]
+impl MarketFactory {
+    /// Constructor to initialize the Market Factory contract
+    /// # Parameters
+    /// - `owner`: Address of the contract owner
+    /// - `wasm_hash`: Hash of the uploaded prediction market wasm
+    pub fn __constructor(e: &Env, owner: Address, wasm_hash: BytesN<32>) {
+        // Ensure Only Owner Can Call Constructor
+        owner.require_auth();
+
+        // Set Owner
+        set_owner(e, &owner);
+
+        // Initialize Prediction Market Wasm Hash
+        e.storage().instance().set(&DataKey::WasmHash, &wasm_hash);
+
+        // Initialize Market Count to 0
+        e.storage().instance().set(&DataKey::MarketCount, &0u32);
+    }
+
+    /// Function to set the prediction market wasm used by future deployments
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `wasm_hash`: Hash of the uploaded prediction market wasm
+    /// # Events
+    /// - `WASM_HASH_UPDATED`: Emitted when the wasm hash is updated
+    #[only_owner]
+    pub fn set_wasm_hash(e: &Env, wasm_hash: BytesN<32>) {
+        e.storage().instance().set(&DataKey::WasmHash, &wasm_hash);
+
+        // Emit an Event for Wasm Hash Updated
+        emit_wasm_hash_updated_event(e, &wasm_hash);
+    }
+
+    /// Function to deploy and register a new prediction market
+    /// Only callable by the owner, the owner of the market should also authorize
+    /// The fees, interval and minimum bet are validated by the market's own constructor
+    /// # Parameters
+    /// - `config`: The asset, timeframe and constructor arguments of the market
+    /// # Returns
+    /// - `Address`: The address of the new market
+    /// # Events
+    /// - `MARKET_DEPLOYED`: Emitted when a market is deployed
+    #[only_owner]
+    pub fn deploy_market(e: &Env, config: MarketConfig) -> Address {
+        // CHECK: The buffer should fit in a round, the market itself does not enforce it
+        assert!(
+            config.buffer_seconds > 0 && config.buffer_seconds <= config.interval_seconds,
+            "INVALID_BUFFER_SECONDS"
+        );
+
+        // CHECK: Only one market per asset, interval and token
+        let market_key = DataKey::MarketIds(
+            config.asset.clone(),
+            config.interval_seconds,
+            config.token.clone(),
+        );
+
+        assert!(
+            !e.storage().instance().has(&market_key),
+            "MARKET_ALREADY_EXISTS"
+        );
+
+        let market_count = Self::get_market_count(e);
+
+        let wasm_hash = Self::get_wasm_hash(e);
+
+        // Deploy the market with a salt derived from its id
+        let address = e
+            .deployer()
+            .with_current_contract(Self::get_market_salt(e, market_count))
+            .deploy_v2(
+                wasm_hash,
+                (
+                    config.owner,
+                    config.interval_seconds,
+                    config.buffer_seconds,
+                    config.min_bet_amount,
+                    config.token.clone(),
+                    config.treasury_fee,
+                    config.flash_loan_fee,
+                    config.oracle,
+                    config.asset.clone(),
+                ),
+            );
+
+        let market = MarketInfo {
+            id: market_count,
+            address: address.clone(),
+            asset: config.asset,
+            interval_seconds: config.interval_seconds,
+            token: config.token,
+        };
+
+        // Register the Market
+        e.storage()
+            .instance()
+            .set(&DataKey::Markets(market.id), &market);
+
+        e.storage().instance().set(&market_key, &market.id);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::MarketCount, &(market_count + 1));
+
+        // Emit an Event for Market Deployed
+        emit_market_deployed_event(e, &market);
+
+        address
+    }
+
+    //////////////////////////////// GETTERS ////////////////////////////////
+
+    pub fn get_wasm_hash(e: &Env) -> BytesN<32> {
+        e.storage()
+            .instance()
+            .get(&DataKey::WasmHash)
+            .expect("WASM_HASH_NOT_FOUND")
+    }
+
+    pub fn get_market_count(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MarketCount)
+            .expect("MARKET_COUNT_NOT_FOUND")
+    }
+
+    pub fn get_market_info(e: &Env, id: u32) -> MarketInfo {
+        e.storage()
+            .instance()
+            .get(&DataKey::Markets(id))
+            .expect("MARKET_NOT_FOUND")
+    }
+
+    /// Readonly function to find the market of an asset, interval and token
+    /// # Parameters
+    /// - `asset`: The asset the market predicts
+    /// - `interval_seconds`: The round duration of the market
+    /// - `token`: The betting token of the market
+    pub fn get_market(
+        e: &Env,
+        asset: Symbol,
+        interval_seconds: u64,
+        token: Address,
+    ) -> Option<Address> {
+        let id: Option<u32> =
+            e.storage()
+                .instance()
+                .get(&DataKey::MarketIds(asset, interval_seconds, token));
+
+        id.map(|id| Self::get_market_info(e, id).address)
+    }
+
+    /// Readonly function to list registered markets in deployment order
+    /// # Parameters
+    /// - `start`: The id of the first market of the page
+    /// - `limit`: The maximum number of markets to return, capped at 50
+    pub fn list_markets(e: &Env, start: u32, limit: u32) -> Vec<MarketInfo> {
+        let market_count = Self::get_market_count(e);
+
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(market_count);
+
+        let mut markets = Vec::new(e);
+
+        for id in start..end {
+            markets.push_back(Self::get_market_info(e, id));
+        }
+
+        markets
+    }
+
+    //////////////////////////////// INTERNALS ////////////////////////////////
+
+    /// Internal function to derive the deployment salt of a market
+    /// # Parameters
+    /// - `id`: The id of the market
+    fn get_market_salt(e: &Env, id: u32) -> BytesN<32> {
+        let mut salt = [0u8; 32];
+
+        salt[28..].copy_from_slice(&id.to_be_bytes());
+
+        BytesN::from_array(e, &salt)
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl Ownable for MarketFactory {}
@@ -0,0 +1,191 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, BytesN as _},
+    Address, BytesN, Env, Symbol,
+};
+
+use crate::contract::{MarketConfig, MarketFactory, MarketFactoryClient};
+
+// Import the Prediction Market wasm, built by `stellar contract build` before running the
+// deployment tests with `cargo test --features deployment-tests`
+#[cfg(feature = "deployment-tests")]
+mod prediction_market {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/prediction_market.wasm"
+    );
+}
+
+fn init_test<'a>(env: &Env) -> (Address, BytesN<32>, MarketFactoryClient<'a>) {
+    env.mock_all_auths();
+
+    let owner = Address::generate(env);
+    let wasm_hash = BytesN::<32>::random(env);
+
+    let contract_id = env.register(MarketFactory, (&owner, &wasm_hash));
+    let client = MarketFactoryClient::new(env, &contract_id);
+
+    (owner, wasm_hash, client)
+}
+
+/// Uploads the prediction market wasm and points the factory at it
+#[cfg(feature = "deployment-tests")]
+fn upload_market_wasm(env: &Env, client: &MarketFactoryClient) {
+    // Uploading and deploying a full market exceeds the default test budget
+    env.budget().reset_unlimited();
+
+    let wasm_hash = env.deployer().upload_contract_wasm(prediction_market::WASM);
+
+    client.set_wasm_hash(&wasm_hash);
+}
+
+fn market_config(env: &Env, owner: &Address) -> MarketConfig {
+    MarketConfig {
+        asset: Symbol::new(env, "XLM"),
+        owner: owner.clone(),
+        interval_seconds: 300,
+        buffer_seconds: 60,
+        min_bet_amount: 10_000_000,
+        token: Address::generate(env),
+        treasury_fee: 500,
+        flash_loan_fee: 50,
+        oracle: Address::generate(env),
+    }
+}
+
+#[test]
+fn test_init() {
+    let env = Env::default();
+    let (owner, wasm_hash, client) = init_test(&env);
+
+    assert_eq!(client.get_owner(), Some(owner));
+    assert_eq!(client.get_wasm_hash(), wasm_hash);
+    assert_eq!(client.get_market_count(), 0);
+    assert_eq!(client.list_markets(&0, &10).len(), 0);
+}
+
+#[test]
+fn test_set_wasm_hash() {
+    let env = Env::default();
+    let (_, _, client) = init_test(&env);
+
+    let wasm_hash = BytesN::<32>::random(&env);
+
+    client.set_wasm_hash(&wasm_hash);
+
+    assert_eq!(client.get_wasm_hash(), wasm_hash);
+}
+
+#[test]
+#[cfg(feature = "deployment-tests")]
+fn test_deploy_market_with_high_fee() {
+    let env = Env::default();
+    let (owner, _, client) = init_test(&env);
+
+    upload_market_wasm(&env, &client);
+
+    let mut config = market_config(&env, &owner);
+    config.treasury_fee = 1001;
+
+    // The market constructor rejects the fee
+    assert!(client.try_deploy_market(&config).is_err());
+    assert_eq!(client.get_market_count(), 0);
+}
+
+#[test]
+fn test_get_unknown_market() {
+    let env = Env::default();
+    let (owner, _, client) = init_test(&env);
+
+    let config = market_config(&env, &owner);
+
+    assert_eq!(
+        client.get_market(&config.asset, &config.interval_seconds, &config.token),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "INVALID_BUFFER_SECONDS")]
+fn test_deploy_market_with_buffer_above_interval() {
+    let env = Env::default();
+    let (owner, _, client) = init_test(&env);
+
+    let mut config = market_config(&env, &owner);
+    config.buffer_seconds = config.interval_seconds + 1;
+
+    client.deploy_market(&config); // Should panic
+}
+
+#[test]
+#[cfg(feature = "deployment-tests")]
+fn test_deploy_market() {
+    let env = Env::default();
+    let (owner, _, client) = init_test(&env);
+
+    upload_market_wasm(&env, &client);
+
+    let mut config = market_config(&env, &owner);
+    config.asset = Symbol::new(&env, "BTC");
+
+    let address = client.deploy_market(&config);
+
+    assert_eq!(client.get_market_count(), 1);
+    assert_eq!(
+        client.get_market(&config.asset, &config.interval_seconds, &config.token),
+        Some(address.clone())
+    );
+
+    let market_info = client.get_market_info(&0);
+    assert_eq!(market_info.address, address);
+    assert_eq!(market_info.asset, config.asset);
+
+    // The market prices the registered asset
+    let market_client = prediction_market::Client::new(&env, &address);
+    assert_eq!(market_client.get_market_asset(), config.asset);
+    assert_eq!(market_client.get_token_address(), config.token);
+}
+
+#[test]
+#[cfg(feature = "deployment-tests")]
+#[should_panic(expected = "MARKET_ALREADY_EXISTS")]
+fn test_deploy_duplicate_market() {
+    let env = Env::default();
+    let (owner, _, client) = init_test(&env);
+
+    upload_market_wasm(&env, &client);
+
+    let config = market_config(&env, &owner);
+
+    client.deploy_market(&config);
+    client.deploy_market(&config); // Should panic
+}
+
+#[test]
+#[cfg(feature = "deployment-tests")]
+fn test_list_markets() {
+    let env = Env::default();
+    let (owner, _, client) = init_test(&env);
+
+    upload_market_wasm(&env, &client);
+
+    let mut config = market_config(&env, &owner);
+
+    for interval_seconds in [300u64, 900, 3600] {
+        config.interval_seconds = interval_seconds;
+
+        client.deploy_market(&config);
+    }
+
+    let first_page = client.list_markets(&0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().interval_seconds, 300);
+    assert_eq!(first_page.get(1).unwrap().interval_seconds, 900);
+
+    let second_page = client.list_markets(&2, &2);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().id, 2);
+    assert_eq!(second_page.get(0).unwrap().interval_seconds, 3600);
+
+    assert_eq!(client.list_markets(&3, &2).len(), 0);
+}
@@ -1,11 +1,16 @@
-use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, Symbol, Vec};
+use core::cmp::Ordering;
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, token, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
+};
 use stellar_access::ownable::{set_owner, Ownable};
 use stellar_macros::{default_impl, only_owner};
 
 use crate::{contract::reflector_oracle::Asset, flash::FlashLoanClient};
 
 // Error codes
-#[contracttype]
+#[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
@@ -37,6 +42,12 @@ pub enum Error {
     TransferFailed = 26,
     Paused = 27,
     NotPaused = 28,
+    BetAmountTooHigh = 29,
+    RoundCapExceeded = 30,
+    UserCapExceeded = 31,
+    SelfExcluded = 32,
+    WagerLimitExceeded = 33,
+    AccessDenied = 34,
 }
 
 #[contracttype]
@@ -44,6 +55,12 @@ pub enum Error {
 pub enum Position {
     Bull,
     Bear,
+    Bucket(u32),
+    Asset(Symbol),
+    BigMove,
+    SmallMove,
+    Yes,
+    No,
 }
 
 #[contracttype]
@@ -52,6 +69,18 @@ pub struct BetInfo {
     pub position: Position,
     pub amount: i128,
     pub claimed: bool,
+    pub token: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenPool {
+    pub total_amount: i128,
+    pub bull_amount: i128,
+    pub bear_amount: i128,
+    pub reward_base_cal_amount: i128,
+    pub reward_amount: i128,
+    pub bucket_amounts: Vec<i128>,
 }
 
 #[contracttype]
@@ -68,6 +97,185 @@ pub struct Round {
     pub bear_amount: i128,
     pub reward_base_cal_amount: i128,
     pub reward_amount: i128,
+    pub token_pools: Map<Address, TokenPool>,
+    pub bucket_amounts: Vec<i128>,
+    pub winning_bucket: Option<u32>,
+    pub rival_lock_price: i128,
+    pub rival_close_price: i128,
+    pub move_threshold: u32,
+    pub event_outcome: Option<bool>,
+    pub house_amount: i128,
+    pub house_bull_liability: i128,
+    pub house_bear_liability: i128,
+    pub treasury_fee: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IntentFunding {
+    Balance,
+    Allowance,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetIntent {
+    pub user: Address,
+    pub series_id: u32,
+    pub epoch: u128,
+    pub position: Position,
+    pub token: Address,
+    pub amount: i128,
+    pub nonce: u64,
+    pub expiry: u64,
+    pub funding: IntentFunding,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserStats {
+    pub total_wagered: i128,
+    pub total_claimed: i128,
+    pub rounds_played: u32,
+    pub rounds_won: u32,
+    pub current_win_streak: u32,
+    pub best_win_streak: u32,
+    pub net_profit: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub user: Address,
+    pub score: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RankingMetric {
+    NetProfit,
+    WinCount,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Season {
+    pub id: u32,
+    pub series_id: u32,
+    pub start_epoch: u128,
+    pub end_epoch: u128,
+    pub metric: RankingMetric,
+    pub prize_pool: i128,
+    pub payout_table: Vec<u32>,
+    pub finalized: bool,
+    pub winners: Vec<Address>,
+    pub finalized_timestamp: u64,
+    pub claimed_amount: i128,
+    pub swept: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralStats {
+    pub referred_count: u32,
+    pub total_earned: Map<Address, i128>,
+    pub claimable: Map<Address, i128>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StandingOrder {
+    pub series_id: u32,
+    pub position: Position,
+    pub amount: i128,
+    pub remaining_rounds: u32,
+    pub last_epoch: u128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum P2PBetStatus {
+    Open,
+    Matched,
+    Cancelled,
+    Settled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct P2PBet {
+    pub series_id: u32,
+    pub epoch: u128,
+    pub proposer: Address,
+    pub position: Position,
+    pub stake: i128,
+    pub counter_stake: i128,
+    pub counterparty: Option<Address>,
+    pub taker: Option<Address>,
+    pub status: P2PBetStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserCap {
+    pub amount: i128,
+    pub rounds: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessMode {
+    Open,
+    Blocklist,
+    Allowlist,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserLimit {
+    pub max_wager_per_day: i128,
+    pub pending_max_wager_per_day: Option<i128>,
+    pub pending_timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCurve {
+    pub min_fee: u32,
+    pub max_fee: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HouseOdds {
+    pub bull_odds: u32,
+    pub bear_odds: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedOddsBet {
+    pub position: Position,
+    pub amount: i128,
+    pub odds: u32,
+    pub claimed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventInfo {
+    pub question: String,
+    pub resolver: Address,
+    pub outcome: Option<bool>,
+    pub resolved_timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultPosition {
+    pub shares: i128,
+    pub last_deposit_timestamp: u64,
+    pub last_withdraw_timestamp: u64,
 }
 
 #[contracttype]
@@ -75,67 +283,567 @@ pub struct Round {
 pub enum DataKey {
     Token,
     OracleAddress,
-    IntervalSeconds,
-    BufferSeconds,
+    MarketAsset,
+    IntervalSeconds(u32),
+    BufferSeconds(u32),
     MinBetAmount,
     TreasuryFee,
     TreasuryAmount,
-    CurrentEpoch,
-    IsGenesisStarted,
-    IsGenesisLocked,
+    CurrentEpoch(u32),
+    IsGenesisStarted(u32),
+    IsGenesisLocked(u32),
     Paused,
     Initialized,
-    Rounds(u128),
-    BetInfos(u128, Address),
-    UserRounds(Address),
+    Rounds(u32, u128),
+    BetInfos(u32, u128, Address),
+    UserRounds(u32, Address),
+    SeriesCount,
+    SeriesBuckets(u32),
+    StrikeSeries(u32),
+    SeriesAssets(u32),
+    VolatilitySeries(u32),
+    EventSeries(u32),
+    EventInfos(u32, u128),
+    HouseOdds(u32),
+    FeeCurve,
+    MaxBetAmount,
+    MaxRoundAmount,
+    UserCap,
+    SelfExclusions(Address),
+    UserLimits(Address),
+    UserDailyWagers(Address),
+    AccessMode,
+    ComplianceManager,
+    Blocklist(Address),
+    Allowlist(Address),
+    HouseMaxSideExposure,
+    HouseMaxRoundExposure,
+    HouseExposure,
+    FixedOddsBets(u32, u128, Address),
     FlashLoanFee,
     FlashTreasuryAmount,
+    VaultTotalAssets,
+    VaultTotalShares,
+    VaultPositions(Address),
+    VaultCooldownSeconds,
+    VaultTreasuryFeeShare,
+    AllowedTokens,
+    TokenMinBetAmount(Address),
+    TokenRates(Address),
+    TokenTreasuryAmount(Address),
+    Balances(Address, Address),
+    Operator,
+    SigningKeys(Address),
+    IntentNonces(Address),
+    GiftPayers(Address),
+    Referrers(Address),
+    ReferralStats(Address),
+    ReferralFeeShare,
+    RoundReferralStakes(u32, u128, Address),
+    UserStats(Address),
+    SeasonUserStats(u32, Address),
+    Leaderboard(u32),
+    UnsettledStatsRounds(Address),
+    SeasonCount,
+    Seasons(u32),
+    SeasonPrizeClaimed(u32, Address),
+    JackpotAmount,
+    JackpotFeeShare,
+    JackpotInterval,
+    JackpotEntries(u128),
+    JackpotRoundEntries(u128),
+    JackpotPeriods,
+    StandingOrders(Address),
+    StandingOrderUsers(u32),
+    StandingOrderMinEscrow,
+    P2PBetCount,
+    P2PBets(u32),
+}
+
+/// Where the stake of a bet is collected from
+enum StakeSource {
+    /// Transferred from the wallet of the given address
+    Wallet(Address),
+    /// Debited from the internal balance of the given address
+    Balance(Address),
+    /// Pulled from the owner by the spender under a token allowance
+    Allowance(Address, Address),
+    /// Already held by the contract, e.g. winnings claimed in the same call
+    Held,
 }
 
 /////////////////////// EVENTS //////////////////////////////////
 
 fn emit_round_started_event(
     e: &Env,
+    series_id: u32,
     epoch: u128,
     start_timestamp: u64,
     lock_timestamp: u64,
     close_timestamp: u64,
 ) {
-    let topics = (Symbol::new(e, "ROUND_STARTED"), epoch);
+    let topics = (Symbol::new(e, "ROUND_STARTED"), series_id, epoch);
     e.events()
         .publish(topics, (start_timestamp, lock_timestamp, close_timestamp));
 }
 
-fn emit_round_locked_event(e: &Env, epoch: u128, lock_timestamp: u64, lock_price: i128) {
-    let topics = (Symbol::new(e, "ROUND_LOCKED"), epoch);
+fn emit_round_locked_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    lock_timestamp: u64,
+    lock_price: i128,
+) {
+    let topics = (Symbol::new(e, "ROUND_LOCKED"), series_id, epoch);
     e.events().publish(topics, (lock_timestamp, lock_price));
 }
 
-fn emit_bet_placed_event(e: &Env, epoch: u128, user: Address, amount: i128, position: Position) {
-    let topics = (Symbol::new(e, "BET_PLACED"), epoch, user.clone());
+fn emit_bet_placed_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    user: Address,
+    amount: i128,
+    position: Position,
+) {
+    let topics = (Symbol::new(e, "BET_PLACED"), series_id, epoch, user.clone());
     e.events().publish(topics, (amount, position));
 }
 
-fn emit_round_ended_event(e: &Env, epoch: u128, close_timestamp: u64, close_price: i128) {
-    let topics = (Symbol::new(e, "ROUND_ENDED"), epoch);
+fn emit_round_ended_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    close_timestamp: u64,
+    close_price: i128,
+) {
+    let topics = (Symbol::new(e, "ROUND_ENDED"), series_id, epoch);
     e.events().publish(topics, (close_timestamp, close_price));
 }
 
-fn emit_rewards_calculated_event(e: &Env, epoch: u128, reward_amount: i128, treasury_amt: i128) {
-    let topics = (Symbol::new(e, "REWARDS_CALCULATED"), epoch);
+fn emit_rewards_calculated_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    reward_amount: i128,
+    treasury_amt: i128,
+) {
+    let topics = (Symbol::new(e, "REWARDS_CALCULATED"), series_id, epoch);
+    e.events().publish(topics, (reward_amount, treasury_amt));
+}
+
+fn emit_token_rewards_calculated_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    token: &Address,
+    reward_amount: i128,
+    treasury_amt: i128,
+) {
+    let topics = (
+        Symbol::new(e, "TOKEN_REWARDS_CALCULATED"),
+        series_id,
+        epoch,
+        token.clone(),
+    );
     e.events().publish(topics, (reward_amount, treasury_amt));
 }
 
-fn emit_flash_loan_event(e: &Env, receiver: &Address, amount: i128, fee_amount: i128) {
-    let topics = (Symbol::new(e, "FLASH_LOAN"), receiver.clone());
+fn emit_token_added_event(e: &Env, token: &Address, min_bet_amount: i128, rate: i128) {
+    let topics = (Symbol::new(e, "TOKEN_ADDED"), token.clone());
+    e.events().publish(topics, (min_bet_amount, rate));
+}
+
+fn emit_token_rate_updated_event(e: &Env, token: &Address, rate: i128) {
+    let topics = (Symbol::new(e, "TOKEN_RATE_UPDATED"), token.clone());
+    e.events().publish(topics, rate);
+}
+
+fn emit_token_removed_event(e: &Env, token: &Address) {
+    let topics = (Symbol::new(e, "TOKEN_REMOVED"), token.clone());
+    e.events().publish(topics, ());
+}
+
+fn emit_claimed_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    user: &Address,
+    token: &Address,
+    amount: i128,
+) {
+    let topics = (Symbol::new(e, "CLAIMED"), series_id, epoch, user.clone());
+    e.events().publish(topics, (token.clone(), amount));
+}
+
+fn emit_balance_credited_event(
+    e: &Env,
+    user: &Address,
+    token: &Address,
+    reason: Symbol,
+    amount: i128,
+    balance: i128,
+) {
+    let topics = (
+        Symbol::new(e, "BALANCE_CREDITED"),
+        user.clone(),
+        token.clone(),
+    );
+    e.events().publish(topics, (reason, amount, balance));
+}
+
+fn emit_balance_debited_event(
+    e: &Env,
+    user: &Address,
+    token: &Address,
+    reason: Symbol,
+    amount: i128,
+    balance: i128,
+) {
+    let topics = (
+        Symbol::new(e, "BALANCE_DEBITED"),
+        user.clone(),
+        token.clone(),
+    );
+    e.events().publish(topics, (reason, amount, balance));
+}
+
+fn emit_signing_key_registered_event(e: &Env, user: &Address, public_key: &BytesN<32>) {
+    let topics = (Symbol::new(e, "SIGNING_KEY_REGISTERED"), user.clone());
+    e.events().publish(topics, public_key.clone());
+}
+
+fn emit_bet_intent_executed_event(e: &Env, user: &Address, nonce: u64, intent: &BetIntent) {
+    let topics = (Symbol::new(e, "BET_INTENT_EXECUTED"), user.clone());
+    e.events()
+        .publish(topics, (nonce, intent.series_id, intent.epoch));
+}
+
+fn emit_bet_sponsored_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    payer: &Address,
+    beneficiary: &Address,
+    amount: i128,
+) {
+    let topics = (
+        Symbol::new(e, "BET_SPONSORED"),
+        series_id,
+        epoch,
+        payer.clone(),
+    );
+    e.events().publish(topics, (beneficiary.clone(), amount));
+}
+
+fn emit_gift_payers_updated_event(e: &Env, beneficiary: &Address, payers: &Vec<Address>) {
+    let topics = (Symbol::new(e, "GIFT_PAYERS_UPDATED"), beneficiary.clone());
+    e.events().publish(topics, payers.clone());
+}
+
+fn emit_referrer_registered_event(e: &Env, user: &Address, referrer: &Address) {
+    let topics = (Symbol::new(e, "REFERRER_REGISTERED"), user.clone());
+    e.events().publish(topics, referrer.clone());
+}
+
+fn emit_referral_rewards_claimed_event(e: &Env, referrer: &Address, token: &Address, amount: i128) {
+    let topics = (Symbol::new(e, "REFERRAL_REWARDS_CLAIMED"), referrer.clone());
+    e.events().publish(topics, (token.clone(), amount));
+}
+
+fn emit_season_created_event(e: &Env, season: &Season) {
+    let topics = (Symbol::new(e, "SEASON_CREATED"), season.id);
+    e.events()
+        .publish(topics, (season.start_epoch, season.end_epoch));
+}
+
+fn emit_season_funded_event(e: &Env, season_id: u32, funder: &Address, amount: i128) {
+    let topics = (Symbol::new(e, "SEASON_FUNDED"), season_id);
+    e.events().publish(topics, (funder.clone(), amount));
+}
+
+fn emit_season_finalized_event(e: &Env, season_id: u32, winners: &Vec<Address>) {
+    let topics = (Symbol::new(e, "SEASON_FINALIZED"), season_id);
+    e.events().publish(topics, winners.clone());
+}
+
+fn emit_season_swept_event(e: &Env, season_id: u32, amount: i128) {
+    let topics = (Symbol::new(e, "SEASON_SWEPT"), season_id);
+    e.events().publish(topics, amount);
+}
+
+fn emit_season_prize_claimed_event(e: &Env, season_id: u32, user: &Address, amount: i128) {
+    let topics = (
+        Symbol::new(e, "SEASON_PRIZE_CLAIMED"),
+        season_id,
+        user.clone(),
+    );
+    e.events().publish(topics, amount);
+}
+
+fn emit_jackpot_won_event(e: &Env, period: u128, winner: &Address, amount: i128) {
+    let topics = (Symbol::new(e, "JACKPOT_WON"), period, winner.clone());
+    e.events().publish(topics, amount);
+}
+
+fn emit_standing_order_created_event(e: &Env, user: &Address, order: &StandingOrder) {
+    let topics = (Symbol::new(e, "STANDING_ORDER_CREATED"), user.clone());
+    e.events().publish(
+        topics,
+        (order.position.clone(), order.amount, order.remaining_rounds),
+    );
+}
+
+fn emit_standing_order_cancelled_event(e: &Env, user: &Address, refund_amount: i128) {
+    let topics = (Symbol::new(e, "STANDING_ORDER_CANCELLED"), user.clone());
+    e.events().publish(topics, refund_amount);
+}
+
+fn emit_p2p_bet_proposed_event(e: &Env, bet_id: u32, bet: &P2PBet) {
+    let topics = (
+        Symbol::new(e, "P2P_BET_PROPOSED"),
+        bet_id,
+        bet.proposer.clone(),
+    );
+    e.events().publish(
+        topics,
+        (
+            bet.series_id,
+            bet.epoch,
+            bet.position.clone(),
+            bet.stake,
+            bet.counter_stake,
+            bet.counterparty.clone(),
+        ),
+    );
+}
+
+fn emit_p2p_bet_accepted_event(e: &Env, bet_id: u32, taker: &Address) {
+    let topics = (Symbol::new(e, "P2P_BET_ACCEPTED"), bet_id, taker.clone());
+    e.events().publish(topics, ());
+}
+
+fn emit_p2p_bet_cancelled_event(e: &Env, bet_id: u32) {
+    let topics = (Symbol::new(e, "P2P_BET_CANCELLED"), bet_id);
+    e.events().publish(topics, ());
+}
+
+fn emit_p2p_bet_settled_event(e: &Env, bet_id: u32, winner: &Option<Address>, payout: i128) {
+    let topics = (Symbol::new(e, "P2P_BET_SETTLED"), bet_id);
+    e.events().publish(topics, (winner.clone(), payout));
+}
+
+fn emit_strike_round_created_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    strike_price: i128,
+    expiry_timestamp: u64,
+) {
+    let topics = (Symbol::new(e, "STRIKE_ROUND_CREATED"), series_id, epoch);
+    e.events().publish(topics, (strike_price, expiry_timestamp));
+}
+
+fn emit_event_round_created_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    question: &String,
+    resolver: &Address,
+    close_timestamp: u64,
+) {
+    let topics = (Symbol::new(e, "EVENT_ROUND_CREATED"), series_id, epoch);
+    e.events().publish(
+        topics,
+        (question.clone(), resolver.clone(), close_timestamp),
+    );
+}
+
+fn emit_event_resolved_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    resolver: &Address,
+    outcome: bool,
+) {
+    let topics = (Symbol::new(e, "EVENT_RESOLVED"), series_id, epoch);
+    e.events().publish(topics, (resolver.clone(), outcome));
+}
+
+fn emit_event_overridden_event(e: &Env, series_id: u32, epoch: u128, outcome: bool) {
+    let topics = (Symbol::new(e, "EVENT_OVERRIDDEN"), series_id, epoch);
+    e.events().publish(topics, outcome);
+}
+
+fn emit_fixed_odds_bet_placed_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    user: &Address,
+    bet: &FixedOddsBet,
+) {
+    let topics = (
+        Symbol::new(e, "FIXED_ODDS_BET_PLACED"),
+        series_id,
+        epoch,
+        user.clone(),
+    );
+    e.events()
+        .publish(topics, (bet.position.clone(), bet.amount, bet.odds));
+}
+
+fn emit_fixed_odds_claimed_event(
+    e: &Env,
+    series_id: u32,
+    epoch: u128,
+    user: &Address,
+    amount: i128,
+) {
+    let topics = (
+        Symbol::new(e, "FIXED_ODDS_CLAIMED"),
+        series_id,
+        epoch,
+        user.clone(),
+    );
+    e.events().publish(topics, amount);
+}
+
+fn emit_house_book_settled_event(e: &Env, series_id: u32, epoch: u128, house_pnl: i128) {
+    let topics = (Symbol::new(e, "HOUSE_BOOK_SETTLED"), series_id, epoch);
+    e.events().publish(topics, house_pnl);
+}
+
+fn emit_house_book_released_event(e: &Env, series_id: u32, epoch: u128, exposure: i128) {
+    let topics = (Symbol::new(e, "HOUSE_BOOK_RELEASED"), series_id, epoch);
+    e.events().publish(topics, exposure);
+}
+
+fn emit_self_excluded_event(e: &Env, user: &Address, until_timestamp: u64) {
+    let topics = (Symbol::new(e, "SELF_EXCLUDED"), user.clone());
+    e.events().publish(topics, until_timestamp);
+}
+
+fn emit_user_limit_updated_event(e: &Env, user: &Address, limit: &UserLimit) {
+    let topics = (Symbol::new(e, "USER_LIMIT_UPDATED"), user.clone());
+    e.events().publish(
+        topics,
+        (
+            limit.max_wager_per_day,
+            limit.pending_max_wager_per_day,
+            limit.pending_timestamp,
+        ),
+    );
+}
+
+fn emit_access_mode_updated_event(e: &Env, mode: AccessMode) {
+    let topics = (Symbol::new(e, "ACCESS_MODE_UPDATED"),);
+    e.events().publish(topics, mode);
+}
+
+fn emit_compliance_manager_updated_event(e: &Env, manager: &Address) {
+    let topics = (Symbol::new(e, "COMPLIANCE_MANAGER_UPDATED"),);
+    e.events().publish(topics, manager.clone());
+}
+
+fn emit_blocklist_updated_event(e: &Env, user: &Address, blocked: bool) {
+    let topics = (Symbol::new(e, "BLOCKLIST_UPDATED"), user.clone());
+    e.events().publish(topics, blocked);
+}
+
+fn emit_allowlist_updated_event(e: &Env, user: &Address, allowed: bool) {
+    let topics = (Symbol::new(e, "ALLOWLIST_UPDATED"), user.clone());
+    e.events().publish(topics, allowed);
+}
+
+fn emit_series_created_event(e: &Env, series_id: u32, interval_seconds: u64, buffer_seconds: u64) {
+    let topics = (Symbol::new(e, "SERIES_CREATED"), series_id);
+    e.events()
+        .publish(topics, (interval_seconds, buffer_seconds));
+}
+
+fn emit_flash_loan_event(
+    e: &Env,
+    initiator: &Address,
+    receiver: &Address,
+    amount: i128,
+    fee_amount: i128,
+) {
+    let topics = (
+        Symbol::new(e, "FLASH_LOAN"),
+        initiator.clone(),
+        receiver.clone(),
+    );
     e.events().publish(topics, (amount, fee_amount));
 }
 
+fn emit_vault_deposit_event(e: &Env, lp: &Address, amount: i128, shares: i128) {
+    let topics = (Symbol::new(e, "VAULT_DEPOSIT"), lp.clone());
+    e.events().publish(topics, (amount, shares));
+}
+
+fn emit_vault_withdraw_event(e: &Env, lp: &Address, amount: i128, shares: i128) {
+    let topics = (Symbol::new(e, "VAULT_WITHDRAW"), lp.clone());
+    e.events().publish(topics, (amount, shares));
+}
+
+fn emit_vault_fees_accrued_event(e: &Env, source: Symbol, amount: i128) {
+    let topics = (Symbol::new(e, "VAULT_FEES_ACCRUED"), source);
+    e.events().publish(topics, amount);
+}
+
 /////////////////////// CONSTANTS //////////////////////////////////
 
 // Maximum treasury fee: 10%
 const MAX_TREASURY_FEE: u32 = 1000; // 10%
 
+// Default cooldown between vault deposits and withdrawals: 1 day
+const DEFAULT_VAULT_COOLDOWN_SECONDS: u64 = 86_400;
+
+// Maximum share of the treasury fee that can be routed to the vault: 100%
+const MAX_VAULT_TREASURY_FEE_SHARE: u32 = 10_000;
+
+// Maximum share of the treasury fee that can be paid to referrers: 50%
+const MAX_REFERRAL_FEE_SHARE: u32 = 5_000;
+
+// Number of users kept on each season leaderboard
+const LEADERBOARD_SIZE: u32 = 10;
+
+// Time after the last round of a season for stats to be synced before finalization: 1 day
+const SEASON_GRACE_SECONDS: u64 = 86_400;
+
+// Time after finalization for winners to claim their season prize: 30 days
+const SEASON_PRIZE_CLAIM_SECONDS: u64 = 2_592_000;
+
+// Maximum share of the treasury fee that can fund the jackpot: 50%
+const MAX_JACKPOT_FEE_SHARE: u32 = 5_000;
+
+// Default number of rounds between jackpot draws
+const DEFAULT_JACKPOT_INTERVAL: u128 = 100;
+
+// Maximum number of active standing orders of a series, bounds the cost of executing them
+const MAX_STANDING_ORDERS: u32 = 50;
+
+// Series created by the constructor, the jackpot follows its rounds
+const DEFAULT_SERIES_ID: u32 = 0;
+
+// Maximum number of bucket boundaries of a series: 10 buckets
+const MAX_BUCKET_BOUNDARIES: u32 = 9;
+
+// Maximum number of rounds covered by the rolling cap of a user
+const MAX_USER_CAP_ROUNDS: u32 = 100;
+
+// Scale of the rate of an allowlisted token in the betting token
+const TOKEN_RATE_SCALE: i128 = 10_000_000;
+
+// Maximum number of payers a beneficiary can allow to gift it bets
+const MAX_GIFT_PAYERS: u32 = 10;
+
+// Cooldown before a loosened wager limit applies: 7 days
+const USER_LIMIT_COOLDOWN_SECONDS: u64 = 604_800;
+
+// Length of the day of a daily wager limit
+const SECONDS_PER_DAY: u64 = 86_400;
+
 // Import Rflector Oracle contarct using its wasm file
 pub mod reflector_oracle {
     soroban_sdk::contractimport!(file = "./src/reflector-oracle.wasm");
@@ -153,6 +861,8 @@ impl PredictionMarket {
     /// - `min_bet_amount`: Minimum amount required to place a bet
     /// - `token_address`: Address of the token used for betting
     /// - `treasury_fee`: Fee percentage taken by the treasury (scaled by 100, e.g., 100 = 1%)
+    /// - `oracle_address`: Address of the Reflector oracle
+    /// - `asset`: Symbol of the oracle asset predicted by the market, e.g., XLM
     pub fn __constructor(
         e: &Env,
         owner: Address,
@@ -163,6 +873,7 @@ impl PredictionMarket {
         treasury_fee: u32,
         flash_loan_fee: u32,
         oracle_address: Address,
+        asset: Symbol,
     ) {
         // Ensure Only Owner Can Call Constructor
         owner.require_auth();
@@ -174,18 +885,27 @@ impl PredictionMarket {
             "FLASH_LOAN_FEE_TOO_HIGH"
         );
 
+        // Ensure that rounds of the default series have a duration
+        assert!(intervals_seconds > 0, "INVALID_INTERVAL_SECONDS");
+
+        // Ensure that bets have a positive minimum
+        assert!(min_bet_amount > 0, "INVALID_MIN_BET_AMOUNT");
+
         // Set Owner
         set_owner(e, &owner);
 
-        // Initialize Interval Seconds
+        // The owner relays signed intents until an operator is set
+        e.storage().instance().set(&DataKey::Operator, &owner);
+
+        // The owner manages the access lists until a compliance manager is set
         e.storage()
             .instance()
-            .set(&DataKey::IntervalSeconds, &intervals_seconds);
+            .set(&DataKey::ComplianceManager, &owner);
 
-        // Initialize Buffer Seconds
+        // Participation is open until an access mode is set
         e.storage()
             .instance()
-            .set(&DataKey::BufferSeconds, &buffer_seconds);
+            .set(&DataKey::AccessMode, &AccessMode::Open);
 
         // Initialize Minimum Bet Amount
         e.storage()
@@ -195,11 +915,14 @@ impl PredictionMarket {
         // Initialize Token Address used for paying bets
         e.storage().instance().set(&DataKey::Token, &token_address);
 
-        // Initialize Oracle Address used for fetching XLM price
+        // Initialize Oracle Address used for fetching asset prices
         e.storage()
             .instance()
             .set(&DataKey::OracleAddress, &oracle_address);
 
+        // Initialize the Asset priced by single asset series
+        e.storage().instance().set(&DataKey::MarketAsset, &asset);
+
         // Initialize Treasury Fee
         e.storage()
             .instance()
@@ -213,39 +936,106 @@ impl PredictionMarket {
         // Initialize Treasury Amount to 0
         e.storage().instance().set(&DataKey::TreasuryAmount, &0i128);
 
-        // Initialize Current Epoch to 0
-        e.storage().instance().set(&DataKey::CurrentEpoch, &0u128);
-
         // Set Initialized Flag to true
         e.storage().instance().set(&DataKey::Initialized, &true);
 
         // Set Paused Flag to false
         e.storage().instance().set(&DataKey::Paused, &false);
 
-        // Set Genesis Started Flag to false
-        e.storage()
-            .instance()
-            .set(&DataKey::IsGenesisStarted, &false);
+        // Initialize the default series with the constructor interval and buffer
+        e.storage().instance().set(&DataKey::SeriesCount, &0u32);
 
-        // Set Genesis Locked Flag to false
-        e.storage()
-            .instance()
-            .set(&DataKey::IsGenesisLocked, &false);
+        Self::init_series(e, intervals_seconds, buffer_seconds);
 
         // Initialize Flash Treasury Amount to 0
         e.storage()
             .instance()
             .set(&DataKey::FlashTreasuryAmount, &0i128);
+
+        // Initialize the House Vault as empty
+        e.storage()
+            .instance()
+            .set(&DataKey::VaultTotalAssets, &0i128);
+        e.storage()
+            .instance()
+            .set(&DataKey::VaultTotalShares, &0i128);
+
+        // Initialize Vault Cooldown
+        e.storage().instance().set(
+            &DataKey::VaultCooldownSeconds,
+            &DEFAULT_VAULT_COOLDOWN_SECONDS,
+        );
+
+        // By default no part of the treasury fee goes to the vault
+        e.storage()
+            .instance()
+            .set(&DataKey::VaultTreasuryFeeShare, &0u32);
+
+        // Only the betting token is accepted until others are allowlisted
+        e.storage()
+            .instance()
+            .set(&DataKey::AllowedTokens, &Vec::<Address>::new(e));
+
+        // Referral program is off until a fee share is set
+        e.storage()
+            .instance()
+            .set(&DataKey::ReferralFeeShare, &0u32);
+
+        // Initialize Season Count to 0
+        e.storage().instance().set(&DataKey::SeasonCount, &0u32);
+
+        // Standing orders need no minimum escrow until one is set
+        e.storage()
+            .instance()
+            .set(&DataKey::StandingOrderMinEscrow, &0i128);
+
+        // Initialize P2P Bet Count to 0
+        e.storage().instance().set(&DataKey::P2PBetCount, &0u32);
+
+        // Bet caps are off until set
+        e.storage().instance().set(&DataKey::MaxBetAmount, &0i128);
+        e.storage().instance().set(&DataKey::MaxRoundAmount, &0i128);
+        e.storage().instance().set(
+            &DataKey::UserCap,
+            &UserCap {
+                amount: 0,
+                rounds: 0,
+            },
+        );
+
+        // House mode is off until exposure limits are set
+        e.storage()
+            .instance()
+            .set(&DataKey::HouseMaxSideExposure, &0i128);
+        e.storage()
+            .instance()
+            .set(&DataKey::HouseMaxRoundExposure, &0i128);
+        e.storage().instance().set(&DataKey::HouseExposure, &0i128);
+
+        // Jackpot is off until a fee share is set
+        e.storage().instance().set(&DataKey::JackpotAmount, &0i128);
+        e.storage().instance().set(&DataKey::JackpotFeeShare, &0u32);
+        e.storage()
+            .instance()
+            .set(&DataKey::JackpotInterval, &DEFAULT_JACKPOT_INTERVAL);
     }
 
-    /// Function to start the genesis round
+    /// Function to start the genesis round of a series
     /// Only callable by the owner
+    /// # Parameters
+    /// - `series_id`: The id of the series
     #[only_owner]
-    pub fn genesis_start_round(e: &Env) {
+    pub fn genesis_start_round(e: &Env, series_id: u32) {
+        // CHECK: Strike and event series have no round lifecycle
+        assert!(
+            !Self::is_strike_series(e, series_id) && !Self::is_event_series(e, series_id),
+            "INVALID_SERIES"
+        );
+
         let is_genesis_started: bool = e
             .storage()
             .instance()
-            .get(&DataKey::IsGenesisStarted)
+            .get(&DataKey::IsGenesisStarted(series_id))
             .expect("IS_GENESIS_STARTED_NOT_FOUND");
 
         assert!(!is_genesis_started, "GENESIS_ALREADY_STARTED");
@@ -253,7 +1043,7 @@ impl PredictionMarket {
         let current_epoch: u128 = e
             .storage()
             .instance()
-            .get(&DataKey::CurrentEpoch)
+            .get(&DataKey::CurrentEpoch(series_id))
             .expect("CURRENT_EPOCH_NOT_FOUND");
 
         let new_epoch = current_epoch + 1;
@@ -261,25 +1051,27 @@ impl PredictionMarket {
         // Advance Current Epoch by 1
         e.storage()
             .instance()
-            .set(&DataKey::CurrentEpoch, &new_epoch);
+            .set(&DataKey::CurrentEpoch(series_id), &new_epoch);
 
         // Start New Round
-        Self::start_round(e, new_epoch);
+        Self::start_round(e, series_id, new_epoch);
 
         // Set Genesis Started Flag to true
         e.storage()
             .instance()
-            .set(&DataKey::IsGenesisStarted, &true);
+            .set(&DataKey::IsGenesisStarted(series_id), &true);
     }
 
-    /// Function to lock the genesis round
+    /// Function to lock the genesis round of a series
     /// Only callable by the owner
+    /// # Parameters
+    /// - `series_id`: The id of the series
     #[only_owner]
-    pub fn genesis_lock_round(e: &Env) {
+    pub fn genesis_lock_round(e: &Env, series_id: u32) {
         let is_genesis_locked: bool = e
             .storage()
             .instance()
-            .get(&DataKey::IsGenesisLocked)
+            .get(&DataKey::IsGenesisLocked(series_id))
             .expect("IS_GENESIS_LOCKED_NOT_FOUND");
 
         assert!(!is_genesis_locked, "GENESIS_ALREADY_LOCKED");
@@ -287,7 +1079,7 @@ impl PredictionMarket {
         let is_genesis_started: bool = e
             .storage()
             .instance()
-            .get(&DataKey::IsGenesisStarted)
+            .get(&DataKey::IsGenesisStarted(series_id))
             .expect("IS_GENESIS_STARTED_NOT_FOUND");
 
         assert!(is_genesis_started, "GENESIS_NOT_STARTED");
@@ -295,48 +1087,52 @@ impl PredictionMarket {
         let current_epoch: u128 = e
             .storage()
             .instance()
-            .get(&DataKey::CurrentEpoch)
+            .get(&DataKey::CurrentEpoch(series_id))
             .expect("CURRENT_EPOCH_NOT_FOUND");
 
         // Get Token Price from Oracle
-        let current_price = Self::get_token_price(e);
+        let current_price = Self::get_token_price(e, series_id);
 
         // Safely Lock the Round
-        Self::safe_lock_round(e, current_epoch, current_price);
+        Self::safe_lock_round(e, series_id, current_epoch, current_price);
 
         // Advance Current Epoch by 1
         let new_epoch = current_epoch + 1;
 
         e.storage()
             .instance()
-            .set(&DataKey::CurrentEpoch, &new_epoch);
+            .set(&DataKey::CurrentEpoch(series_id), &new_epoch);
 
         // Start New Round
-        Self::start_round(e, new_epoch);
+        Self::start_round(e, series_id, new_epoch);
 
         // Set Genesis Locked Flag to true
-        e.storage().instance().set(&DataKey::IsGenesisLocked, &true);
+        e.storage()
+            .instance()
+            .set(&DataKey::IsGenesisLocked(series_id), &true);
     }
 
-    /// Function to execute a round
+    /// Function to execute a round of a series
     /// Only callable by the owner
+    /// # Parameters
+    /// - `series_id`: The id of the series
     /// # Events
     /// - `ROUND_LOCKED`: Emitted when a round is locked
     /// - `ROUND_ENDED`: Emitted when a round is ended
     /// - `REWARDS_CALCULATED`: Emitted when rewards are calculated
     /// - `ROUND_STARTED`: Emitted when a new round is started
     #[only_owner]
-    pub fn execute_round(e: &Env) {
+    pub fn execute_round(e: &Env, series_id: u32) {
         let is_genesis_locked: bool = e
             .storage()
             .instance()
-            .get(&DataKey::IsGenesisLocked)
+            .get(&DataKey::IsGenesisLocked(series_id))
             .expect("IS_GENESIS_LOCKED_NOT_FOUND");
 
         let is_genesis_started: bool = e
             .storage()
             .instance()
-            .get(&DataKey::IsGenesisStarted)
+            .get(&DataKey::IsGenesisStarted(series_id))
             .expect("IS_GENESIS_STARTED_NOT_FOUND");
 
         assert!(
@@ -347,304 +1143,4821 @@ impl PredictionMarket {
         let current_epoch: u128 = e
             .storage()
             .instance()
-            .get(&DataKey::CurrentEpoch)
+            .get(&DataKey::CurrentEpoch(series_id))
             .expect("CURRENT_EPOCH_NOT_FOUND");
 
         // Get Token Price from Oracle
-        let current_price = Self::get_token_price(e);
+        let current_price = Self::get_token_price(e, series_id);
 
         // Safely Lock the current round
-        Self::safe_lock_round(e, current_epoch, current_price);
+        Self::safe_lock_round(e, series_id, current_epoch, current_price);
         // Safely End the n - 1 round
-        Self::safe_end_round(e, current_epoch - 1, current_price);
+        Self::safe_end_round(e, series_id, current_epoch - 1, current_price);
         // Calculate Rewards for the n - 1 round
-        Self::calculate_rewards(e, current_epoch - 1);
+        Self::calculate_rewards(e, series_id, current_epoch - 1);
 
         // Advance Current Epoch by 1
         let new_epoch = current_epoch + 1;
         e.storage()
             .instance()
-            .set(&DataKey::CurrentEpoch, &new_epoch);
+            .set(&DataKey::CurrentEpoch(series_id), &new_epoch);
 
         // Safe start New Round
-        Self::safe_start_round(e, new_epoch);
+        Self::safe_start_round(e, series_id, new_epoch);
     }
 
-    /// Function to place a bet on the bull side
+    /// Function to open a strike round on whether the price will be above or below a strike at expiry
+    /// Bull bets win above the strike and Bear bets below it, a close at the strike goes to the treasury
+    /// Only callable by the owner
     /// # Parameters
-    /// - `epoch`: The epoch of the round to bet on
-    /// - `user`: The address of the user placing the bet
-    /// - `amount`: The amount of tokens to bet
+    /// - `series_id`: The id of the strike series
+    /// - `strike_price`: The strike price, in oracle price units
+    /// - `lock_timestamp`: The time betting closes
+    /// - `expiry_timestamp`: The time the round is settled against the strike
+    /// # Returns
+    /// - `u128`: The epoch of the new round
     /// # Events
-    /// - `BET_PLACED`: Emitted when a bet is placed
-    pub fn bet_bull(e: &Env, epoch: u128, user: Address, amount: i128) {
-        // User should authorize the bet
-        user.require_auth();
+    /// - `ROUND_STARTED`: Emitted when the round is created
+    /// - `STRIKE_ROUND_CREATED`: Emitted with the strike and expiry of the round
+    #[only_owner]
+    pub fn create_strike_round(
+        e: &Env,
+        series_id: u32,
+        strike_price: i128,
+        lock_timestamp: u64,
+        expiry_timestamp: u64,
+    ) -> u128 {
+        // CHECK: Series should be a strike series
+        assert!(Self::is_strike_series(e, series_id), "INVALID_SERIES");
 
-        let current_epoch: u128 = e
-            .storage()
-            .instance()
-            .get(&DataKey::CurrentEpoch)
-            .expect("CURRENT_EPOCH_NOT_FOUND");
+        assert!(strike_price > 0, "INVALID_STRIKE_PRICE");
 
-        // CHECK: Epoch should be the current epoch
-        assert!(epoch == current_epoch, "INVALID_ROUND");
+        let start_timestamp = e.ledger().timestamp();
 
-        // CHECK: Round should be bettable
-        assert!(Self::is_bettable(e, epoch), "ROUND_NOT_BETTABLE");
+        // CHECK: Betting should close in the future, at or before expiry
+        assert!(
+            start_timestamp < lock_timestamp && lock_timestamp <= expiry_timestamp,
+            "INVALID_ROUND_TIMESTAMPS"
+        );
 
-        // CHECK: Amount should be greater than minimum bet amount
-        let min_bet_amount: i128 = e
-            .storage()
-            .instance()
-            .get(&DataKey::MinBetAmount)
-            .expect("MIN_BET_AMOUNT_NOT_FOUND");
+        let epoch = Self::get_current_epoch(e, series_id) + 1;
 
-        assert!(amount >= min_bet_amount, "BET_AMOUNT_TOO_LOW");
+        e.storage()
+            .instance()
+            .set(&DataKey::CurrentEpoch(series_id), &epoch);
 
-        // CHECK: User should not have already placed a bet in this round
-        assert!(!Self::has_bet(e, epoch, &user), "ALREADY_BET_FOR_ROUND");
+        // The strike plays the part of the lock price
+        let round = Round {
+            epoch,
+            start_timestamp,
+            lock_timestamp,
+            close_timestamp: expiry_timestamp,
+            lock_price: strike_price,
+            close_price: 0,
+            total_amount: 0,
+            bull_amount: 0,
+            bear_amount: 0,
+            reward_base_cal_amount: 0,
+            reward_amount: 0,
+            token_pools: Map::new(e),
+            bucket_amounts: Vec::new(e),
+            winning_bucket: None,
+            rival_lock_price: 0,
+            rival_close_price: 0,
+            move_threshold: 0,
+            event_outcome: None,
+            house_amount: 0,
+            house_bull_liability: 0,
+            house_bear_liability: 0,
+            treasury_fee: 0,
+        };
 
-        // Get Token Addressw
-        let token_address: Address = e
-            .storage()
+        e.storage()
             .instance()
-            .get(&DataKey::Token)
-            .expect("TOKEN_ADDRESS_NOT_FOUND");
+            .set(&DataKey::Rounds(series_id, epoch), &round);
 
-        // Create Token Client
-        let token_client = token::Client::new(e, &token_address);
-
-        // Safely transfer tokens from user to contract
-        Self::safe_transfer_tokens(
+        // Emit an Event for Round Started
+        emit_round_started_event(
             e,
-            &token_client,
-            &user,
-            &e.current_contract_address(),
-            amount,
+            series_id,
+            epoch,
+            start_timestamp,
+            lock_timestamp,
+            expiry_timestamp,
         );
 
-        // Update Round Info
-        let mut round: Round = e
-            .storage()
-            .instance()
-            .get(&DataKey::Rounds(epoch))
-            .expect("ROUND_NOT_FOUND");
-
-        round.total_amount += amount;
-        round.bull_amount += amount;
-
-        // Store Updated Round in Storage
-        e.storage().instance().set(&DataKey::Rounds(epoch), &round);
-
-        // Record Bet Info
-        let bet_info = BetInfo {
-            position: Position::Bull,
-            amount,
-            claimed: false,
-        };
+        // Emit an Event for Strike Round Created
+        emit_strike_round_created_event(e, series_id, epoch, strike_price, expiry_timestamp);
 
-        // Store Bet Info in Storage
-        let bet_info_key = DataKey::BetInfos(epoch, user.clone());
+        epoch
+    }
 
-        e.storage().instance().set(&bet_info_key, &bet_info);
+    /// Function to settle a strike round with the oracle price at its expiry
+    /// Only callable by the owner, within the buffer seconds after expiry
+    /// # Parameters
+    /// - `series_id`: The id of the strike series
+    /// - `epoch`: The epoch of the round
+    /// # Events
+    /// - `ROUND_ENDED`: Emitted when the round is ended
+    /// - `REWARDS_CALCULATED`: Emitted when rewards are calculated
+    #[only_owner]
+    pub fn settle_strike_round(e: &Env, series_id: u32, epoch: u128) {
+        // CHECK: Series should be a strike series
+        assert!(Self::is_strike_series(e, series_id), "INVALID_SERIES");
 
-        // Get User Rounds (returns an empty vec if none exist)
-        let mut user_rounds: Vec<u128> = e
-            .storage()
-            .instance()
-            .get(&DataKey::UserRounds(user.clone()))
-            .unwrap_or(Vec::new(&e));
+        let round = Self::get_round(e, series_id, epoch);
 
-        // Add Round to User Rounds
-        user_rounds.push_back(epoch);
+        // Get Token Price from Oracle as it was at expiry, not at the time of the call
+        let expiry_price =
+            Self::get_asset_oracle_price_at(e, Self::get_market_asset(e), round.close_timestamp);
 
-        // Store Updated User Rounds in Storage
-        e.storage()
-            .instance()
-            .set(&DataKey::UserRounds(user.clone()), &user_rounds);
+        // Safely End the Round
+        Self::safe_end_round(e, series_id, epoch, expiry_price);
 
-        // Emit an Event for Bet Placed
-        emit_bet_placed_event(e, epoch, user, amount, Position::Bull);
+        // Calculate Rewards for the Round
+        Self::calculate_rewards(e, series_id, epoch);
     }
 
-    /// Function to place a bet on the bear side
+    /// Function to open a yes/no round on an event settled by a resolver
+    /// Only callable by the owner
     /// # Parameters
-    /// - `epoch`: The epoch of the round to bet on
-    /// - `user`: The address of the user placing the bet
-    /// - `amount`: The amount of tokens to bet
+    /// - `series_id`: The id of the event series
+    /// - `question`: The event the round is bet on
+    /// - `resolver`: The address allowed to resolve the outcome of the event
+    /// - `lock_timestamp`: The time betting closes
+    /// - `close_timestamp`: The time from which the event can be resolved
+    /// # Returns
+    /// - `u128`: The epoch of the new round
     /// # Events
-    /// - `BET_PLACED`: Emitted when a bet is placed
-    pub fn bet_bear(e: &Env, epoch: u128, user: Address, amount: i128) {
-        // User should authorize the bet
-        user.require_auth();
+    /// - `ROUND_STARTED`: Emitted when the round is created
+    /// - `EVENT_ROUND_CREATED`: Emitted with the question and resolver of the round
+    #[only_owner]
+    pub fn create_event_round(
+        e: &Env,
+        series_id: u32,
+        question: String,
+        resolver: Address,
+        lock_timestamp: u64,
+        close_timestamp: u64,
+    ) -> u128 {
+        // CHECK: Series should be an event series
+        assert!(Self::is_event_series(e, series_id), "INVALID_SERIES");
 
-        let current_epoch: u128 = e
-            .storage()
-            .instance()
-            .get(&DataKey::CurrentEpoch)
-            .expect("CURRENT_EPOCH_NOT_FOUND");
+        let start_timestamp = e.ledger().timestamp();
 
-        // CHECK: Epoch should be the current epoch
-        assert!(epoch == current_epoch, "INVALID_ROUND");
+        // CHECK: Betting should close in the future, at or before the event
+        assert!(
+            start_timestamp < lock_timestamp && lock_timestamp <= close_timestamp,
+            "INVALID_ROUND_TIMESTAMPS"
+        );
 
-        // CHECK: Round should be bettable
-        assert!(Self::is_bettable(e, epoch), "ROUND_NOT_BETTABLE");
+        let epoch = Self::get_current_epoch(e, series_id) + 1;
 
-        // CHECK: Amount should be greater than minimum bet amount
-        let min_bet_amount: i128 = e
-            .storage()
+        e.storage()
             .instance()
-            .get(&DataKey::MinBetAmount)
-            .expect("MIN_BET_AMOUNT_NOT_FOUND");
+            .set(&DataKey::CurrentEpoch(series_id), &epoch);
 
-        assert!(amount >= min_bet_amount, "BET_AMOUNT_TOO_LOW");
+        let round = Round {
+            epoch,
+            start_timestamp,
+            lock_timestamp,
+            close_timestamp,
+            lock_price: 0,
+            close_price: 0,
+            total_amount: 0,
+            bull_amount: 0,
+            bear_amount: 0,
+            reward_base_cal_amount: 0,
+            reward_amount: 0,
+            token_pools: Map::new(e),
+            bucket_amounts: Vec::new(e),
+            winning_bucket: None,
+            rival_lock_price: 0,
+            rival_close_price: 0,
+            move_threshold: 0,
+            event_outcome: None,
+            house_amount: 0,
+            house_bull_liability: 0,
+            house_bear_liability: 0,
+            treasury_fee: 0,
+        };
 
-        // CHECK: User should not have already placed a bet in this round
-        assert!(!Self::has_bet(e, epoch, &user), "ALREADY_BET_FOR_ROUND");
+        e.storage()
+            .instance()
+            .set(&DataKey::Rounds(series_id, epoch), &round);
 
-        // Get Token Address
-        let token_address: Address = e
-            .storage()
+        let event_info = EventInfo {
+            question,
+            resolver,
+            outcome: None,
+            resolved_timestamp: 0,
+        };
+
+        e.storage()
             .instance()
-            .get(&DataKey::Token)
-            .expect("TOKEN_ADDRESS_NOT_FOUND");
+            .set(&DataKey::EventInfos(series_id, epoch), &event_info);
 
-        // Create Token Client
-        let token_client = token::Client::new(e, &token_address);
+        // Emit an Event for Round Started
+        emit_round_started_event(
+            e,
+            series_id,
+            epoch,
+            start_timestamp,
+            lock_timestamp,
+            close_timestamp,
+        );
 
-        // Safely transfer tokens from user to contract
-        Self::safe_transfer_tokens(
+        // Emit an Event for Event Round Created
+        emit_event_round_created_event(
             e,
-            &token_client,
-            &user,
-            &e.current_contract_address(),
-            amount,
+            series_id,
+            epoch,
+            &event_info.question,
+            &event_info.resolver,
+            close_timestamp,
         );
 
-        // Update Round Info
-        let mut round: Round = e
-            .storage()
-            .instance()
-            .get(&DataKey::Rounds(epoch))
-            .expect("ROUND_NOT_FOUND");
+        epoch
+    }
 
-        round.total_amount += amount;
-        round.bear_amount += amount;
+    /// Function for the resolver to report the outcome of an event round
+    /// The outcome opens a dispute window during which the owner can override it
+    /// # Parameters
+    /// - `series_id`: The id of the event series
+    /// - `epoch`: The epoch of the round
+    /// - `outcome`: Whether the event happened
+    /// # Events
+    /// - `EVENT_RESOLVED`: Emitted when the outcome is reported
+    pub fn resolve_event(e: &Env, series_id: u32, epoch: u128, outcome: bool) {
+        let mut event_info = Self::get_event_info(e, series_id, epoch);
 
-        // Store Updated Round in Storage
-        e.storage().instance().set(&DataKey::Rounds(epoch), &round);
+        // Resolver should authorize the outcome
+        event_info.resolver.require_auth();
 
-        // Record Bet Info
-        let bet_info = BetInfo {
-            position: Position::Bear,
-            amount,
-            claimed: false,
-        };
+        // CHECK: Event should not have been resolved yet
+        assert!(event_info.outcome.is_none(), "EVENT_ALREADY_RESOLVED");
 
-        // Store Bet Info in Storage
-        let bet_info_key = DataKey::BetInfos(epoch, user.clone());
+        let round = Self::get_round(e, series_id, epoch);
 
-        e.storage().instance().set(&bet_info_key, &bet_info);
+        let current_timestamp = e.ledger().timestamp();
 
-        // Get User Rounds (returns an empty vec if none exist)
-        let mut user_rounds: Vec<u128> = e
-            .storage()
-            .instance()
-            .get(&DataKey::UserRounds(user.clone()))
-            .unwrap_or(Vec::new(&e));
+        // CHECK: Current time should be after or equal to close timestamp
+        assert!(
+            current_timestamp >= round.close_timestamp,
+            "CANNOT_RESOLVE_BEFORE_CLOSE_TIMESTAMP"
+        );
 
-        // Add Round to User Rounds
-        user_rounds.push_back(epoch);
+        // CHECK: Dispute window should end before the round becomes refundable
+        assert!(
+            current_timestamp + Self::get_event_dispute_seconds(e, series_id)
+                <= round.close_timestamp + Self::get_buffer_seconds(e, series_id),
+            "CANNOT_RESOLVE_OUTSIDE_BUFFER"
+        );
+
+        event_info.outcome = Some(outcome);
+        event_info.resolved_timestamp = current_timestamp;
 
-        // Store Updated User Rounds in Storage
         e.storage()
             .instance()
-            .set(&DataKey::UserRounds(user.clone()), &user_rounds);
+            .set(&DataKey::EventInfos(series_id, epoch), &event_info);
 
-        // Emit an Event for Bet Placed
-        emit_bet_placed_event(e, epoch, user, amount, Position::Bear);
+        // Emit an Event for Event Resolved
+        emit_event_resolved_event(e, series_id, epoch, &event_info.resolver, outcome);
     }
 
-    /// Flash loan function to borrow tokens temporarily    
+    /// Function to override the outcome of an event round during its dispute window
+    /// Only callable by the owner
     /// # Parameters
-    /// - `amount`: The amount of tokens to borrow
-    /// - `receiver`: The address of the receiver of the tokens
-    pub fn flash_loan(e: &Env, amount: i128, receiver: Address) {
-        // Get The Flash Loan Fee
-        let flash_loan_fee: u32 = e
-            .storage()
-            .instance()
-            .get(&DataKey::FlashLoanFee)
-            .expect("FLASH_LOAN_FEE_NOT_FOUND");
+    /// - `series_id`: The id of the event series
+    /// - `epoch`: The epoch of the round
+    /// - `outcome`: Whether the event happened
+    /// # Events
+    /// - `EVENT_OVERRIDDEN`: Emitted when the outcome is overridden
+    #[only_owner]
+    pub fn override_event_outcome(e: &Env, series_id: u32, epoch: u128, outcome: bool) {
+        let mut event_info = Self::get_event_info(e, series_id, epoch);
 
-        // Calculate The Fee Amount
-        let fee_amount: i128 = (amount * flash_loan_fee as i128) / 10_000;
+        // CHECK: Event should have been resolved
+        assert!(event_info.outcome.is_some(), "EVENT_NOT_RESOLVED");
 
-        // Get Token Address
-        let token_address: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .expect("TOKEN_ADDRESS_NOT_FOUND");
+        // CHECK: Dispute window should still be open
+        assert!(
+            e.ledger().timestamp()
+                < event_info.resolved_timestamp + Self::get_event_dispute_seconds(e, series_id),
+            "DISPUTE_WINDOW_CLOSED"
+        );
 
-        // Create Token Client
-        let token_client = token::Client::new(e, &token_address);
+        event_info.outcome = Some(outcome);
 
-        let current_contract_address = e.current_contract_address();
+        e.storage()
+            .instance()
+            .set(&DataKey::EventInfos(series_id, epoch), &event_info);
 
-        // Get The token balance before the flash loan
-        let balance_before: i128 = token_client.balance(&current_contract_address);
+        // Emit an Event for Event Overridden
+        emit_event_overridden_event(e, series_id, epoch, outcome);
+    }
 
-        // Safely transfer tokens from contract to receiver
-        Self::safe_transfer_tokens(
-            e,
-            &token_client,
-            &current_contract_address,
-            &receiver,
+    /// Function to settle an event round with its outcome once the dispute window is over
+    /// Callable by anyone, before the round becomes refundable
+    /// # Parameters
+    /// - `series_id`: The id of the event series
+    /// - `epoch`: The epoch of the round
+    /// # Events
+    /// - `REWARDS_CALCULATED`: Emitted when rewards are calculated
+    pub fn settle_event_round(e: &Env, series_id: u32, epoch: u128) {
+        let event_info = Self::get_event_info(e, series_id, epoch);
+
+        // CHECK: Event should have been resolved
+        let outcome = event_info.outcome.expect("EVENT_NOT_RESOLVED");
+
+        let current_timestamp = e.ledger().timestamp();
+
+        // CHECK: Dispute window should be over
+        assert!(
+            current_timestamp
+                >= event_info.resolved_timestamp + Self::get_event_dispute_seconds(e, series_id),
+            "DISPUTE_WINDOW_OPEN"
+        );
+
+        let mut round = Self::get_round(e, series_id, epoch);
+
+        // CHECK: Round should not have been settled yet
+        assert!(round.event_outcome.is_none(), "EVENT_ALREADY_SETTLED");
+
+        // CHECK: Current time should be within buffer seconds of close timestamp
+        assert!(
+            current_timestamp <= round.close_timestamp + Self::get_buffer_seconds(e, series_id),
+            "CANNOT_END_OUTSIDE_BUFFER"
+        );
+
+        round.event_outcome = Some(outcome);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Rounds(series_id, epoch), &round);
+
+        // Calculate Rewards for the Round
+        Self::calculate_rewards(e, series_id, epoch);
+    }
+
+    /// Function to place a bet on the bull side
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_bull(e: &Env, series_id: u32, epoch: u128, user: Address, amount: i128) {
+        // User should authorize the bet
+        user.require_auth();
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token_address,
             amount,
+            Position::Bull,
+            StakeSource::Wallet(user.clone()),
         );
+    }
 
-        // Receiver should implement the FlashLoanReceiver trait
-        FlashLoanClient::new(e, &receiver).execute_flash_loan(
-            &current_contract_address,
+    /// Function to place a bet on the bear side
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_bear(e: &Env, series_id: u32, epoch: u128, user: Address, amount: i128) {
+        // User should authorize the bet
+        user.require_auth();
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
             &token_address,
-            &amount,
-            &fee_amount,
+            amount,
+            Position::Bear,
+            StakeSource::Wallet(user.clone()),
         );
+    }
 
-        // Get The token balance after the flash loan
-        let balance_after: i128 = token_client.balance(&current_contract_address);
+    /// Function to place a bet on a price bucket of a bucket series
+    /// # Parameters
+    /// - `series_id`: The id of the bucket series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `bucket`: The index of the bucket, 0 being the lowest price change
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_bucket(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: Address,
+        bucket: u32,
+        amount: i128,
+    ) {
+        // User should authorize the bet
+        user.require_auth();
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token_address,
+            amount,
+            Position::Bucket(bucket),
+            StakeSource::Wallet(user.clone()),
+        );
+    }
+
+    /// Function to place a bet on an asset of a head-to-head series
+    /// # Parameters
+    /// - `series_id`: The id of the head-to-head series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `asset`: The asset expected to have the higher percentage change
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_asset(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: Address,
+        asset: Symbol,
+        amount: i128,
+    ) {
+        // User should authorize the bet
+        user.require_auth();
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token_address,
+            amount,
+            Position::Asset(asset),
+            StakeSource::Wallet(user.clone()),
+        );
+    }
+
+    /// Function to place a bet on a big move of a volatility series
+    /// # Parameters
+    /// - `series_id`: The id of the volatility series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_big_move(e: &Env, series_id: u32, epoch: u128, user: Address, amount: i128) {
+        // User should authorize the bet
+        user.require_auth();
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token_address,
+            amount,
+            Position::BigMove,
+            StakeSource::Wallet(user.clone()),
+        );
+    }
+
+    /// Function to place a bet on a small move of a volatility series
+    /// # Parameters
+    /// - `series_id`: The id of the volatility series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_small_move(e: &Env, series_id: u32, epoch: u128, user: Address, amount: i128) {
+        // User should authorize the bet
+        user.require_auth();
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token_address,
+            amount,
+            Position::SmallMove,
+            StakeSource::Wallet(user.clone()),
+        );
+    }
+
+    /// Function to place a bet on yes in an event series
+    /// # Parameters
+    /// - `series_id`: The id of the event series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_yes(e: &Env, series_id: u32, epoch: u128, user: Address, amount: i128) {
+        // User should authorize the bet
+        user.require_auth();
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token_address,
+            amount,
+            Position::Yes,
+            StakeSource::Wallet(user.clone()),
+        );
+    }
+
+    /// Function to place a bet on no in an event series
+    /// # Parameters
+    /// - `series_id`: The id of the event series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_no(e: &Env, series_id: u32, epoch: u128, user: Address, amount: i128) {
+        // User should authorize the bet
+        user.require_auth();
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token_address,
+            amount,
+            Position::No,
+            StakeSource::Wallet(user.clone()),
+        );
+    }
+
+    /// Function to place a bet at the fixed odds quoted by the house for a series
+    /// The bet is backed by the vault and stays out of the parimutuel pools
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `position`: The side of the bet
+    /// - `amount`: The amount of tokens to bet
+    /// - `min_odds`: The lowest odds the user accepts (scaled by 10_000, e.g., 19_000 = 1.9x)
+    /// # Events
+    /// - `FIXED_ODDS_BET_PLACED`: Emitted when the bet is placed
+    pub fn bet_fixed_odds(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: Address,
+        position: Position,
+        amount: i128,
+        min_odds: u32,
+    ) {
+        // User should authorize the bet
+        user.require_auth();
+
+        let current_epoch = Self::get_current_epoch(e, series_id);
+
+        // CHECK: Epoch should be the current epoch, strike and event rounds can be open side by side
+        assert!(
+            epoch == current_epoch
+                || Self::is_strike_series(e, series_id)
+                || Self::is_event_series(e, series_id),
+            "INVALID_ROUND"
+        );
+
+        // CHECK: Round should be bettable
+        assert!(Self::is_bettable(e, series_id, epoch), "ROUND_NOT_BETTABLE");
+
+        let token_address = Self::get_token_address(e);
+
+        // CHECK: Amount should be greater than minimum bet amount
+        let min_bet_amount = Self::get_token_min_bet_amount(e, token_address.clone());
+
+        assert!(amount >= min_bet_amount, "BET_AMOUNT_TOO_LOW");
+
+        // CHECK: Bet should be allowed by the caps and the limits of the user
+        if let Some(error) =
+            Self::get_bet_restriction(e, series_id, epoch, &user, &token_address, amount)
+        {
+            panic_with_error!(e, error);
+        }
+
+        let bet_key = DataKey::FixedOddsBets(series_id, epoch, user.clone());
+
+        // CHECK: User should not have already placed a fixed-odds bet in this round
+        assert!(
+            !e.storage().instance().has(&bet_key),
+            "ALREADY_BET_FOR_ROUND"
+        );
+
+        let mut round = Self::get_round(e, series_id, epoch);
+
+        // CHECK: Position should match the market type of the series
+        assert!(
+            Self::is_valid_position(e, series_id, &position),
+            "INVALID_POSITION"
+        );
+
+        let is_bull_side = Self::get_pool_side(e, series_id, &position) == Position::Bull;
+
+        // CHECK: House should quote odds on the side
+        let house_odds = Self::get_house_odds(e, series_id);
+
+        let odds = if is_bull_side {
+            house_odds.bull_odds
+        } else {
+            house_odds.bear_odds
+        };
+
+        assert!(odds != 0, "HOUSE_MODE_DISABLED");
+        assert!(odds >= min_odds, "ODDS_BELOW_MINIMUM");
+
+        let payout = (amount * odds as i128) / 10_000;
+
+        let prev_round_exposure = Self::get_house_round_exposure(&round);
+
+        round.house_amount += amount;
+
+        let side_liability = if is_bull_side {
+            round.house_bull_liability += payout;
+            round.house_bull_liability
+        } else {
+            round.house_bear_liability += payout;
+            round.house_bear_liability
+        };
+
+        // CHECK: Bet should stay within the risk budget of the house
+        assert!(
+            side_liability <= Self::get_house_max_side_exposure(e),
+            "HOUSE_EXPOSURE_TOO_HIGH"
+        );
+
+        let round_exposure = Self::get_house_round_exposure(&round);
+
+        assert!(
+            round_exposure <= Self::get_house_max_round_exposure(e),
+            "HOUSE_EXPOSURE_TOO_HIGH"
+        );
+
+        // CHECK: Vault should cover the worst case of every open round
+        let house_exposure = Self::get_house_exposure(e) + round_exposure - prev_round_exposure;
+
+        assert!(
+            house_exposure <= Self::get_vault_total_assets(e),
+            "HOUSE_EXPOSURE_TOO_HIGH"
+        );
+
+        let token_client = token::Client::new(e, &token_address);
+
+        // Safely transfer tokens from user to contract
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &user,
+            &e.current_contract_address(),
+            amount,
+        );
+
+        e.storage()
+            .instance()
+            .set(&DataKey::HouseExposure, &house_exposure);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Rounds(series_id, epoch), &round);
+
+        // Count the bet in the daily wager of the user
+        Self::record_wager(e, &user, &token_address, amount);
+
+        let bet = FixedOddsBet {
+            position,
+            amount,
+            odds,
+            claimed: false,
+        };
+
+        e.storage().instance().set(&bet_key, &bet);
+
+        // Emit an Event for Fixed Odds Bet Placed
+        emit_fixed_odds_bet_placed_event(e, series_id, epoch, &user, &bet);
+    }
+
+    /// Function to claim the payout of a winning fixed-odds bet, or its refund if the round was never ended
+    /// # Parameters
+    /// - `user`: The address of the user claiming
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// # Returns
+    /// - `i128`: The amount paid to the user
+    /// # Events
+    /// - `FIXED_ODDS_CLAIMED`: Emitted when the payout is claimed
+    pub fn claim_fixed_odds(e: &Env, user: Address, series_id: u32, epoch: u128) -> i128 {
+        // User should authorize the claim
+        user.require_auth();
+
+        let bet_key = DataKey::FixedOddsBets(series_id, epoch, user.clone());
+
+        let mut bet: FixedOddsBet = e
+            .storage()
+            .instance()
+            .get(&bet_key)
+            .expect("BET_INFO_NOT_FOUND");
+
+        // CHECK: Bet should not have been claimed yet
+        assert!(!bet.claimed, "ALREADY_CLAIMED");
+
+        let mut round = Self::get_round(e, series_id, epoch);
+
+        let amount = if Self::is_claimable(e, series_id, &round, &bet.position) {
+            (bet.amount * bet.odds as i128) / 10_000
+        } else if Self::is_refundable(e, series_id, &round) {
+            // Release the exposure of the refunded round if nobody did it yet
+            Self::release_house_book(e, series_id, &mut round);
+
+            bet.amount
+        } else {
+            panic!("NOT_ELIGIBLE_FOR_CLAIM");
+        };
+
+        bet.claimed = true;
+
+        e.storage().instance().set(&bet_key, &bet);
+
+        let token_client = token::Client::new(e, &Self::get_token_address(e));
+
+        // Safely transfer tokens from contract to user
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &e.current_contract_address(),
+            &user,
+            amount,
+        );
+
+        // Emit an Event for Fixed Odds Claimed
+        emit_fixed_odds_claimed_event(e, series_id, epoch, &user, amount);
+
+        amount
+    }
+
+    /// Function to release the exposure the vault reserved for the fixed-odds bets of a refunded round
+    /// Callable by anyone, the stakes stay claimable by their bettors
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the refunded round
+    /// # Events
+    /// - `HOUSE_BOOK_RELEASED`: Emitted with the exposure released
+    pub fn release_house_exposure(e: &Env, series_id: u32, epoch: u128) {
+        let mut round = Self::get_round(e, series_id, epoch);
+
+        // CHECK: Round should be refunded
+        assert!(
+            Self::is_refundable(e, series_id, &round),
+            "ROUND_NOT_REFUNDABLE"
+        );
+
+        Self::release_house_book(e, series_id, &mut round);
+    }
+
+    /// Function to place a bet on the bull side with an allowlisted token
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `token`: The address of the token used for the bet
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_bull_with_token(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: Address,
+        token: Address,
+        amount: i128,
+    ) {
+        // User should authorize the bet
+        user.require_auth();
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token,
+            amount,
+            Position::Bull,
+            StakeSource::Wallet(user.clone()),
+        );
+    }
+
+    /// Function to place a bet on the bear side with an allowlisted token
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `token`: The address of the token used for the bet
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_bear_with_token(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: Address,
+        token: Address,
+        amount: i128,
+    ) {
+        // User should authorize the bet
+        user.require_auth();
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token,
+            amount,
+            Position::Bear,
+            StakeSource::Wallet(user.clone()),
+        );
+    }
+
+    /// Function to place a bet on the bull side funded from the internal balance
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `token`: The address of the token used for the bet
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    /// - `BALANCE_DEBITED`: Emitted when the stake is debited from the balance
+    pub fn bet_bull_from_balance(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: Address,
+        token: Address,
+        amount: i128,
+    ) {
+        // User should authorize the bet
+        user.require_auth();
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token,
+            amount,
+            Position::Bull,
+            StakeSource::Balance(user.clone()),
+        );
+    }
+
+    /// Function to place a bet on the bear side funded from the internal balance
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `token`: The address of the token used for the bet
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    /// - `BALANCE_DEBITED`: Emitted when the stake is debited from the balance
+    pub fn bet_bear_from_balance(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: Address,
+        token: Address,
+        amount: i128,
+    ) {
+        // User should authorize the bet
+        user.require_auth();
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token,
+            amount,
+            Position::Bear,
+            StakeSource::Balance(user.clone()),
+        );
+    }
+
+    /// Function to place a bet on the bull side funded under a token allowance
+    /// `user` must have approved `spender`; when `spender` is this contract the user
+    /// authorizes the bet, otherwise the spender does
+    /// # Parameters
+    /// - `spender`: The address spending the allowance
+    /// - `user`: The address of the user funding and owning the bet
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_bull_from(
+        e: &Env,
+        spender: Address,
+        user: Address,
+        series_id: u32,
+        epoch: u128,
+        amount: i128,
+    ) {
+        Self::require_spender_auth(e, &spender, &user);
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token_address,
+            amount,
+            Position::Bull,
+            StakeSource::Allowance(spender, user.clone()),
+        );
+    }
+
+    /// Function to place a bet on the bear side funded under a token allowance
+    /// `user` must have approved `spender`; when `spender` is this contract the user
+    /// authorizes the bet, otherwise the spender does
+    /// # Parameters
+    /// - `spender`: The address spending the allowance
+    /// - `user`: The address of the user funding and owning the bet
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_bear_from(
+        e: &Env,
+        spender: Address,
+        user: Address,
+        series_id: u32,
+        epoch: u128,
+        amount: i128,
+    ) {
+        Self::require_spender_auth(e, &spender, &user);
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token_address,
+            amount,
+            Position::Bear,
+            StakeSource::Allowance(spender, user.clone()),
+        );
+    }
+
+    /// Function to place a bull bet paid by `payer` on behalf of `beneficiary`
+    /// The bet and its claim rights belong to the beneficiary
+    /// Only the payer authorizes the bet, so it can be gifted to an offline beneficiary,
+    /// unless the beneficiary opted in to an allowlist of payers with `set_gift_payers`
+    /// # Parameters
+    /// - `payer`: The address funding the bet
+    /// - `beneficiary`: The address owning the bet
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    /// - `BET_SPONSORED`: Emitted with the payer of the bet
+    pub fn bet_bull_for(
+        e: &Env,
+        payer: Address,
+        beneficiary: Address,
+        series_id: u32,
+        epoch: u128,
+        amount: i128,
+    ) {
+        // Payer should authorize the bet
+        payer.require_auth();
+
+        // CHECK: Payer should be allowed by the beneficiary if it restricted its gifts
+        Self::require_gift_payer(e, &payer, &beneficiary);
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &beneficiary,
+            &token_address,
+            amount,
+            Position::Bull,
+            StakeSource::Wallet(payer.clone()),
+        );
+
+        // Emit an Event for Bet Sponsored
+        emit_bet_sponsored_event(e, series_id, epoch, &payer, &beneficiary, amount);
+    }
+
+    /// Function to place a bear bet paid by `payer` on behalf of `beneficiary`
+    /// The bet and its claim rights belong to the beneficiary
+    /// Only the payer authorizes the bet, so it can be gifted to an offline beneficiary,
+    /// unless the beneficiary opted in to an allowlist of payers with `set_gift_payers`
+    /// # Parameters
+    /// - `payer`: The address funding the bet
+    /// - `beneficiary`: The address owning the bet
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `amount`: The amount of tokens to bet
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    /// - `BET_SPONSORED`: Emitted with the payer of the bet
+    pub fn bet_bear_for(
+        e: &Env,
+        payer: Address,
+        beneficiary: Address,
+        series_id: u32,
+        epoch: u128,
+        amount: i128,
+    ) {
+        // Payer should authorize the bet
+        payer.require_auth();
+
+        // CHECK: Payer should be allowed by the beneficiary if it restricted its gifts
+        Self::require_gift_payer(e, &payer, &beneficiary);
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &beneficiary,
+            &token_address,
+            amount,
+            Position::Bear,
+            StakeSource::Wallet(payer.clone()),
+        );
+
+        // Emit an Event for Bet Sponsored
+        emit_bet_sponsored_event(e, series_id, epoch, &payer, &beneficiary, amount);
+    }
+
+    /// Function for a beneficiary to only accept gifted bets from a list of payers
+    /// An empty list accepts gifts from any payer, which is the default
+    /// # Parameters
+    /// - `beneficiary`: The address receiving the gifted bets
+    /// - `payers`: The payers allowed to gift bets, at most 10
+    /// # Events
+    /// - `GIFT_PAYERS_UPDATED`: Emitted when the list is updated
+    pub fn set_gift_payers(e: &Env, beneficiary: Address, payers: Vec<Address>) {
+        // Beneficiary should authorize the list
+        beneficiary.require_auth();
+
+        assert!(payers.len() <= MAX_GIFT_PAYERS, "TOO_MANY_GIFT_PAYERS");
+
+        e.storage()
+            .instance()
+            .set(&DataKey::GiftPayers(beneficiary.clone()), &payers);
+
+        // Emit an Event for Gift Payers Updated
+        emit_gift_payers_updated_event(e, &beneficiary, &payers);
+    }
+
+    /// Function to place a bet on the bull side through a referral link
+    /// The referrer is bound to the user on its first bet, it is ignored once the user has bet
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `amount`: The amount of tokens to bet
+    /// - `referrer`: The address of the referrer of the user
+    /// # Events
+    /// - `REFERRER_REGISTERED`: Emitted when the referrer is bound to the user
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_bull_referred(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: Address,
+        amount: i128,
+        referrer: Address,
+    ) {
+        // User should authorize the bet
+        user.require_auth();
+
+        // Bind the referrer before the bet so it earns on the first bet
+        if Self::get_user_stats(e, user.clone()).rounds_played == 0
+            && Self::get_referrer(e, user.clone()).is_none()
+        {
+            Self::bind_referrer(e, &user, &referrer);
+        }
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token_address,
+            amount,
+            Position::Bull,
+            StakeSource::Wallet(user.clone()),
+        );
+    }
+
+    /// Function to place a bet on the bear side through a referral link
+    /// The referrer is bound to the user on its first bet, it is ignored once the user has bet
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `amount`: The amount of tokens to bet
+    /// - `referrer`: The address of the referrer of the user
+    /// # Events
+    /// - `REFERRER_REGISTERED`: Emitted when the referrer is bound to the user
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    pub fn bet_bear_referred(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: Address,
+        amount: i128,
+        referrer: Address,
+    ) {
+        // User should authorize the bet
+        user.require_auth();
+
+        // Bind the referrer before the bet so it earns on the first bet
+        if Self::get_user_stats(e, user.clone()).rounds_played == 0
+            && Self::get_referrer(e, user.clone()).is_none()
+        {
+            Self::bind_referrer(e, &user, &referrer);
+        }
+
+        let token_address = Self::get_token_address(e);
+
+        Self::place_bet(
+            e,
+            series_id,
+            epoch,
+            &user,
+            &token_address,
+            amount,
+            Position::Bear,
+            StakeSource::Wallet(user.clone()),
+        );
+    }
+
+    /// Function to register the referrer of a user ahead of its first bet
+    /// The referrer can only be set once, before the first bet of the user, the bet functions
+    /// with a referrer bind it on the first bet instead
+    /// # Parameters
+    /// - `user`: The address of the referred user
+    /// - `referrer`: The address of the referrer
+    /// # Events
+    /// - `REFERRER_REGISTERED`: Emitted when a referrer is registered
+    pub fn register_referrer(e: &Env, user: Address, referrer: Address) {
+        // User should authorize the registration
+        user.require_auth();
+
+        // CHECK: Referrer should not be set yet
+        assert!(
+            Self::get_referrer(e, user.clone()).is_none(),
+            "REFERRER_ALREADY_SET"
+        );
+
+        // CHECK: User should not have placed a bet yet
+        assert!(
+            Self::get_user_stats(e, user.clone()).rounds_played == 0,
+            "REFERRER_AFTER_FIRST_BET"
+        );
+
+        Self::bind_referrer(e, &user, &referrer);
+    }
+
+    /// Function to claim the referral rewards accrued in every token
+    /// # Parameters
+    /// - `referrer`: The address of the referrer
+    /// # Events
+    /// - `REFERRAL_REWARDS_CLAIMED`: Emitted for every token paid out
+    pub fn claim_referral_rewards(e: &Env, referrer: Address) {
+        // Referrer should authorize the claim
+        referrer.require_auth();
+
+        let mut stats = Self::get_referral_stats(e, referrer.clone());
+
+        let claimable = stats.claimable.clone();
+
+        stats.claimable = Map::new(e);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::ReferralStats(referrer.clone()), &stats);
+
+        let current_contract_address = e.current_contract_address();
+
+        for (token, amount) in claimable.iter() {
+            let token_client = token::Client::new(e, &token);
+
+            // Safely transfer tokens from contract to referrer
+            Self::safe_transfer_tokens(
+                e,
+                &token_client,
+                &current_contract_address,
+                &referrer,
+                amount,
+            );
+
+            // Emit an Event for Referral Rewards Claimed
+            emit_referral_rewards_claimed_event(e, &referrer, &token, amount);
+        }
+    }
+
+    /// Function to add betting tokens to the prize pool of a season
+    /// # Parameters
+    /// - `funder`: The address funding the prize pool
+    /// - `season_id`: The id of the season
+    /// - `amount`: The amount of tokens to add
+    /// # Events
+    /// - `SEASON_FUNDED`: Emitted when the prize pool is funded
+    pub fn fund_season(e: &Env, funder: Address, season_id: u32, amount: i128) {
+        // Funder should authorize the deposit
+        funder.require_auth();
+
+        assert!(amount > 0, "INVALID_AMOUNT");
+
+        let mut season = Self::get_season(e, season_id);
+
+        assert!(!season.finalized, "SEASON_ALREADY_FINALIZED");
+
+        let token_address = Self::get_token_address(e);
+        let token_client = token::Client::new(e, &token_address);
+
+        // Safely transfer tokens from funder to contract
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &funder,
+            &e.current_contract_address(),
+            amount,
+        );
+
+        season.prize_pool += amount;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Seasons(season_id), &season);
+
+        // Emit an Event for Season Funded
+        emit_season_funded_event(e, season_id, &funder, amount);
+    }
+
+    /// Function to claim the prize of a finalized season
+    /// Prizes can be claimed until the owner sweeps the season
+    /// # Parameters
+    /// - `season_id`: The id of the season
+    /// - `user`: The address of a top finisher
+    /// # Events
+    /// - `SEASON_PRIZE_CLAIMED`: Emitted when a prize is claimed
+    pub fn claim_season_prize(e: &Env, season_id: u32, user: Address) {
+        // User should authorize the claim
+        user.require_auth();
+
+        let mut season = Self::get_season(e, season_id);
+
+        assert!(season.finalized, "SEASON_NOT_FINALIZED");
+
+        assert!(!season.swept, "SEASON_SWEPT");
+
+        let prize_claimed_key = DataKey::SeasonPrizeClaimed(season_id, user.clone());
+
+        assert!(
+            !e.storage().instance().has(&prize_claimed_key),
+            "ALREADY_CLAIMED"
+        );
+
+        let rank = season.winners.first_index_of(&user).expect("NOT_A_WINNER");
+
+        let amount = (season.prize_pool * season.payout_table.get_unchecked(rank) as i128) / 10_000;
+
+        e.storage().instance().set(&prize_claimed_key, &true);
+
+        season.claimed_amount += amount;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Seasons(season_id), &season);
+
+        let token_address = Self::get_token_address(e);
+        let token_client = token::Client::new(e, &token_address);
+
+        // Safely transfer tokens from contract to user
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &e.current_contract_address(),
+            &user,
+            amount,
+        );
+
+        // Emit an Event for Season Prize Claimed
+        emit_season_prize_claimed_event(e, season_id, &user, amount);
+    }
+
+    /// Function to register the ed25519 key used to sign bet intents
+    /// # Parameters
+    /// - `user`: The address of the user
+    /// - `public_key`: The ed25519 public key of the user
+    /// # Events
+    /// - `SIGNING_KEY_REGISTERED`: Emitted when a signing key is registered
+    pub fn register_signing_key(e: &Env, user: Address, public_key: BytesN<32>) {
+        // User should authorize the key registration
+        user.require_auth();
+
+        e.storage()
+            .instance()
+            .set(&DataKey::SigningKeys(user.clone()), &public_key);
+
+        // Emit an Event for Signing Key Registered
+        emit_signing_key_registered_event(e, &user, &public_key);
+    }
+
+    /// Function to place a bet from an intent signed off-chain by the user
+    /// Only callable by the operator, who pays the transaction fees
+    /// # Parameters
+    /// - `intent`: The bet intent signed by the user
+    /// - `signature`: The ed25519 signature of `get_bet_intent_payload(intent)`
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    /// - `BET_INTENT_EXECUTED`: Emitted when the intent is consumed
+    pub fn execute_bet_intent(e: &Env, intent: BetIntent, signature: BytesN<64>) {
+        // Operator should authorize the relay
+        let operator = Self::get_operator(e);
+
+        operator.require_auth();
+
+        // CHECK: Intent should not be expired
+        assert!(e.ledger().timestamp() <= intent.expiry, "INTENT_EXPIRED");
+
+        // CHECK: Nonce should be the next nonce of the user
+        let nonce = Self::get_intent_nonce(e, intent.user.clone());
+
+        assert!(intent.nonce == nonce, "INVALID_NONCE");
+
+        // CHECK: Intent should be signed by the registered key of the user
+        let public_key: BytesN<32> = e
+            .storage()
+            .instance()
+            .get(&DataKey::SigningKeys(intent.user.clone()))
+            .expect("SIGNING_KEY_NOT_FOUND");
+
+        let payload = Self::get_bet_intent_payload(e, intent.clone());
+
+        e.crypto().ed25519_verify(&public_key, &payload, &signature);
+
+        // Consume the Nonce
+        e.storage()
+            .instance()
+            .set(&DataKey::IntentNonces(intent.user.clone()), &(nonce + 1));
+
+        let source = match intent.funding {
+            IntentFunding::Balance => StakeSource::Balance(intent.user.clone()),
+            IntentFunding::Allowance => {
+                StakeSource::Allowance(e.current_contract_address(), intent.user.clone())
+            }
+        };
+
+        Self::place_bet(
+            e,
+            intent.series_id,
+            intent.epoch,
+            &intent.user,
+            &intent.token,
+            intent.amount,
+            intent.position.clone(),
+            source,
+        );
+
+        // Emit an Event for Bet Intent Executed
+        emit_bet_intent_executed_event(e, &intent.user, nonce, &intent);
+    }
+
+    /// Function to claim winnings or refunds and transfer them to the user
+    /// # Parameters
+    /// - `user`: The address of the user claiming
+    /// - `series_id`: The id of the series
+    /// - `epochs`: The epochs of the rounds to claim
+    /// # Events
+    /// - `CLAIMED`: Emitted for every claimed round
+    pub fn claim(e: &Env, user: Address, series_id: u32, epochs: Vec<u128>) {
+        // User should authorize the claim
+        user.require_auth();
+
+        let payouts = Self::collect_payouts(e, series_id, &user, &epochs);
+
+        let current_contract_address = e.current_contract_address();
+
+        for (token, amount) in payouts.iter() {
+            let token_client = token::Client::new(e, &token);
+
+            // Safely transfer tokens from contract to user
+            Self::safe_transfer_tokens(e, &token_client, &current_contract_address, &user, amount);
+        }
+    }
+
+    /// Function to claim winnings or refunds into the internal balance
+    /// # Parameters
+    /// - `user`: The address of the user claiming
+    /// - `series_id`: The id of the series
+    /// - `epochs`: The epochs of the rounds to claim
+    /// # Events
+    /// - `CLAIMED`: Emitted for every claimed round
+    /// - `BALANCE_CREDITED`: Emitted for every token credited to the balance
+    pub fn claim_to_balance(e: &Env, user: Address, series_id: u32, epochs: Vec<u128>) {
+        // User should authorize the claim
+        user.require_auth();
+
+        let payouts = Self::collect_payouts(e, series_id, &user, &epochs);
+
+        for (token, amount) in payouts.iter() {
+            Self::credit_balance(e, &user, &token, amount, Symbol::new(e, "CLAIM"));
+        }
+    }
+
+    /// Function to claim winnings or refunds and bet them on the current round of the same series
+    /// The bet is placed in the betting token, the rest of the payouts is credited to the internal balance
+    /// # Parameters
+    /// - `user`: The address of the user claiming
+    /// - `series_id`: The id of the series
+    /// - `claim_epochs`: The epochs of the rounds to claim
+    /// - `position`: The side of the new bet
+    /// - `amount`: The amount to bet, or `None` to bet all of the betting token payouts
+    /// # Events
+    /// - `CLAIMED`: Emitted for every claimed round
+    /// - `BET_PLACED`: Emitted when the bet is placed
+    /// - `BALANCE_CREDITED`: Emitted for every token credited to the balance
+    pub fn claim_and_bet(
+        e: &Env,
+        user: Address,
+        series_id: u32,
+        claim_epochs: Vec<u128>,
+        position: Position,
+        amount: Option<i128>,
+    ) {
+        // User should authorize the claim and the bet
+        user.require_auth();
+
+        let mut payouts = Self::collect_payouts(e, series_id, &user, &claim_epochs);
+
+        let token_address = Self::get_token_address(e);
+
+        let winnings = payouts.get(token_address.clone()).unwrap_or(0);
+
+        let amount = amount.unwrap_or(winnings);
+
+        assert!(amount > 0, "INVALID_AMOUNT");
+
+        // CHECK: Bet should be covered by the payouts
+        assert!(amount <= winnings, "BET_AMOUNT_EXCEEDS_WINNINGS");
+
+        let current_epoch: u128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentEpoch(series_id))
+            .expect("CURRENT_EPOCH_NOT_FOUND");
+
+        // Winnings are already held by the contract, so no tokens move
+        Self::place_bet(
+            e,
+            series_id,
+            current_epoch,
+            &user,
+            &token_address,
+            amount,
+            position,
+            StakeSource::Held,
+        );
+
+        payouts.set(token_address, winnings - amount);
+
+        for (token, amount) in payouts.iter() {
+            if amount > 0 {
+                Self::credit_balance(e, &user, &token, amount, Symbol::new(e, "CLAIM"));
+            }
+        }
+    }
+
+    /// Function to settle the ended rounds of a user into its stats and the season leaderboards
+    /// Stats are settled whenever the user bets or claims, this covers users who do neither
+    /// Callable by anyone
+    /// # Parameters
+    /// - `user`: The address of the user
+    pub fn sync_user_stats(e: &Env, user: Address) {
+        Self::settle_user_stats(e, &user);
+    }
+
+    /// Function to deposit tokens into the internal balance
+    /// # Parameters
+    /// - `user`: The address of the user depositing
+    /// - `token`: The address of an accepted token
+    /// - `amount`: The amount of tokens to deposit
+    /// # Events
+    /// - `BALANCE_CREDITED`: Emitted when the balance is credited
+    pub fn deposit(e: &Env, user: Address, token: Address, amount: i128) {
+        // User should authorize the deposit
+        user.require_auth();
+
+        assert!(amount > 0, "INVALID_AMOUNT");
+
+        // CHECK: User should be granted access
+        Self::require_access(e, &user);
+
+        // CHECK: Token should be accepted for betting
+        assert!(Self::is_accepted_token(e, &token), "TOKEN_NOT_ALLOWED");
+
+        let token_client = token::Client::new(e, &token);
+
+        // Safely transfer tokens from user to contract
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &user,
+            &e.current_contract_address(),
+            amount,
+        );
+
+        Self::credit_balance(e, &user, &token, amount, Symbol::new(e, "DEPOSIT"));
+    }
+
+    /// Function to withdraw tokens from the internal balance
+    /// # Parameters
+    /// - `user`: The address of the user withdrawing
+    /// - `token`: The address of the token
+    /// - `amount`: The amount of tokens to withdraw
+    /// # Events
+    /// - `BALANCE_DEBITED`: Emitted when the balance is debited
+    pub fn withdraw(e: &Env, user: Address, token: Address, amount: i128) {
+        // User should authorize the withdrawal
+        user.require_auth();
+
+        assert!(amount > 0, "INVALID_AMOUNT");
+
+        Self::debit_balance(e, &user, &token, amount, Symbol::new(e, "WITHDRAW"));
+
+        let token_client = token::Client::new(e, &token);
+
+        // Safely transfer tokens from contract to user
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &e.current_contract_address(),
+            &user,
+            amount,
+        );
+    }
+
+    /// Function to create a standing order betting the same amount on the same side for several rounds
+    /// The stake of every round is escrowed upfront in the betting token
+    /// # Parameters
+    /// - `user`: The address of the user creating the order
+    /// - `series_id`: The id of the series to bet on
+    /// - `position`: The side to bet on every round
+    /// - `amount`: The amount to bet every round
+    /// - `rounds`: The number of rounds to bet on
+    /// # Events
+    /// - `STANDING_ORDER_CREATED`: Emitted when the order is created
+    pub fn create_standing_order(
+        e: &Env,
+        user: Address,
+        series_id: u32,
+        position: Position,
+        amount: i128,
+        rounds: u32,
+    ) {
+        // User should authorize the order
+        user.require_auth();
+
+        assert!(rounds > 0, "INVALID_ROUNDS");
+
+        assert!(series_id < Self::get_series_count(e), "SERIES_NOT_FOUND");
+
+        // CHECK: User should be granted access
+        Self::require_access(e, &user);
+
+        // CHECK: Position should match the market type of the series
+        assert!(
+            Self::is_valid_position(e, series_id, &position),
+            "INVALID_POSITION"
+        );
+
+        let token_address = Self::get_token_address(e);
+
+        // CHECK: Amount should be greater than minimum bet amount
+        let min_bet_amount = Self::get_token_min_bet_amount(e, token_address.clone());
+
+        assert!(amount >= min_bet_amount, "BET_AMOUNT_TOO_LOW");
+
+        // CHECK: Escrow should cover the minimum escrow of an order
+        assert!(
+            amount * rounds as i128 >= Self::get_standing_order_min_escrow(e),
+            "STANDING_ORDER_ESCROW_TOO_LOW"
+        );
+
+        // CHECK: User should not have an active order
+        assert!(
+            Self::get_standing_order(e, user.clone()).is_none(),
+            "STANDING_ORDER_EXISTS"
+        );
+
+        let mut users = Self::get_standing_order_users(e, series_id);
+
+        assert!(
+            users.len() < MAX_STANDING_ORDERS,
+            "TOO_MANY_STANDING_ORDERS"
+        );
+
+        let token_client = token::Client::new(e, &token_address);
+
+        // Safely escrow the stakes of every round
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &user,
+            &e.current_contract_address(),
+            amount * rounds as i128,
+        );
+
+        let order = StandingOrder {
+            series_id,
+            position,
+            amount,
+            remaining_rounds: rounds,
+            last_epoch: 0,
+        };
+
+        e.storage()
+            .instance()
+            .set(&DataKey::StandingOrders(user.clone()), &order);
+
+        users.push_back(user.clone());
+
+        e.storage()
+            .instance()
+            .set(&DataKey::StandingOrderUsers(series_id), &users);
+
+        // Emit an Event for Standing Order Created
+        emit_standing_order_created_event(e, &user, &order);
+    }
+
+    /// Function to cancel a standing order and refund the unspent escrow
+    /// # Parameters
+    /// - `user`: The address of the user cancelling the order
+    /// # Events
+    /// - `STANDING_ORDER_CANCELLED`: Emitted when the order is cancelled
+    pub fn cancel_standing_order(e: &Env, user: Address) {
+        // User should authorize the cancellation
+        user.require_auth();
+
+        let order = Self::get_standing_order(e, user.clone()).expect("STANDING_ORDER_NOT_FOUND");
+
+        Self::remove_standing_order(e, &user, order.series_id);
+
+        let refund_amount = order.amount * order.remaining_rounds as i128;
+
+        let token_client = token::Client::new(e, &Self::get_token_address(e));
+
+        // Safely transfer the unspent escrow from contract to user
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &e.current_contract_address(),
+            &user,
+            refund_amount,
+        );
+
+        // Emit an Event for Standing Order Cancelled
+        emit_standing_order_cancelled_event(e, &user, refund_amount);
+    }
+
+    /// Keeper function to place the bets of the standing orders on the current round of a series
+    /// Callable by anyone while the current round is bettable
+    /// Orders whose user already bet on the round are skipped for that round
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// # Returns
+    /// - `u32`: The number of bets placed
+    /// # Events
+    /// - `BET_PLACED`: Emitted for every bet placed
+    pub fn execute_standing_orders(e: &Env, series_id: u32) -> u32 {
+        let current_epoch: u128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentEpoch(series_id))
+            .expect("CURRENT_EPOCH_NOT_FOUND");
+
+        // CHECK: Round should be bettable
+        assert!(
+            Self::is_bettable(e, series_id, current_epoch),
+            "ROUND_NOT_BETTABLE"
+        );
+
+        let token_address = Self::get_token_address(e);
+
+        let min_bet_amount = Self::get_token_min_bet_amount(e, token_address.clone());
+
+        let mut placed: u32 = 0;
+
+        for user in Self::get_standing_order_users(e, series_id).iter() {
+            let mut order =
+                Self::get_standing_order(e, user.clone()).expect("STANDING_ORDER_NOT_FOUND");
+
+            if order.last_epoch == current_epoch {
+                continue;
+            }
+
+            order.last_epoch = current_epoch;
+
+            let is_restricted = Self::get_bet_restriction(
+                e,
+                series_id,
+                current_epoch,
+                &user,
+                &token_address,
+                order.amount,
+            )
+            .is_some();
+
+            // Orders that cannot be placed this round are skipped so they never block the others
+            if Self::has_bet(e, series_id, current_epoch, &user)
+                || order.amount < min_bet_amount
+                || !Self::is_valid_position(e, series_id, &order.position)
+                || is_restricted
+            {
+                e.storage()
+                    .instance()
+                    .set(&DataKey::StandingOrders(user.clone()), &order);
+
+                continue;
+            }
+
+            // Stake was escrowed when the order was created
+            Self::place_bet(
+                e,
+                series_id,
+                current_epoch,
+                &user,
+                &token_address,
+                order.amount,
+                order.position.clone(),
+                StakeSource::Held,
+            );
+
+            placed += 1;
+
+            order.remaining_rounds -= 1;
+
+            if order.remaining_rounds == 0 {
+                Self::remove_standing_order(e, &user, series_id);
+            } else {
+                e.storage()
+                    .instance()
+                    .set(&DataKey::StandingOrders(user.clone()), &order);
+            }
+        }
+
+        placed
+    }
+
+    /// Function to propose a peer-to-peer bet on a round, escrowing the stake of the proposer
+    /// The bet is accepted by taking the opposite side, and is settled by the outcome of the round
+    /// without entering its pools
+    /// # Parameters
+    /// - `proposer`: The address of the user proposing the bet
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `position`: The side of the proposer, the taker gets the opposite side
+    /// - `stake`: The amount staked by the proposer
+    /// - `counter_stake`: The amount the taker should stake, equal to the stake if not set
+    /// - `counterparty`: The only user allowed to accept the bet, anyone if not set
+    /// # Returns
+    /// - `u32`: The id of the bet
+    /// # Events
+    /// - `P2P_BET_PROPOSED`: Emitted when the bet is proposed
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_p2p_bet(
+        e: &Env,
+        proposer: Address,
+        series_id: u32,
+        epoch: u128,
+        position: Position,
+        stake: i128,
+        counter_stake: Option<i128>,
+        counterparty: Option<Address>,
+    ) -> u32 {
+        // Proposer should authorize the bet
+        proposer.require_auth();
+
+        // CHECK: Round should be bettable
+        assert!(Self::is_bettable(e, series_id, epoch), "ROUND_NOT_BETTABLE");
+
+        // CHECK: Position should be valid in the series and have an opposite side
+        assert!(
+            Self::is_valid_position(e, series_id, &position),
+            "INVALID_POSITION"
+        );
+        assert!(!matches!(position, Position::Bucket(_)), "INVALID_POSITION");
+
+        // CHECK: Both stakes should be greater than minimum bet amount
+        let token_address = Self::get_token_address(e);
+
+        let min_bet_amount = Self::get_token_min_bet_amount(e, token_address.clone());
+
+        let counter_stake = counter_stake.unwrap_or(stake);
+
+        assert!(
+            stake >= min_bet_amount && counter_stake >= min_bet_amount,
+            "BET_AMOUNT_TOO_LOW"
+        );
+
+        // CHECK: Proposer cannot be the counterparty
+        assert!(
+            counterparty != Some(proposer.clone()),
+            "INVALID_COUNTERPARTY"
+        );
+
+        // CHECK: Stake should be allowed by the caps and the limits of the proposer
+        if let Some(error) =
+            Self::get_stake_restriction(e, series_id, epoch, &proposer, &token_address, stake)
+        {
+            panic_with_error!(e, error);
+        }
+
+        let token_client = token::Client::new(e, &token_address);
+
+        // Safely escrow the stake of the proposer
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &proposer,
+            &e.current_contract_address(),
+            stake,
+        );
+
+        // Count the stake in the daily wager of the proposer
+        Self::record_wager(e, &proposer, &token_address, stake);
+
+        let bet_id = Self::get_p2p_bet_count(e);
+
+        let bet = P2PBet {
+            series_id,
+            epoch,
+            proposer,
+            position,
+            stake,
+            counter_stake,
+            counterparty,
+            taker: None,
+            status: P2PBetStatus::Open,
+        };
+
+        e.storage().instance().set(&DataKey::P2PBets(bet_id), &bet);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::P2PBetCount, &(bet_id + 1));
+
+        // Emit an Event for P2P Bet Proposed
+        emit_p2p_bet_proposed_event(e, bet_id, &bet);
+
+        bet_id
+    }
+
+    /// Function to accept a peer-to-peer bet on the opposite side, escrowing the counter stake
+    /// # Parameters
+    /// - `taker`: The address of the user accepting the bet
+    /// - `bet_id`: The id of the bet
+    /// # Events
+    /// - `P2P_BET_ACCEPTED`: Emitted when the bet is accepted
+    pub fn accept_p2p_bet(e: &Env, taker: Address, bet_id: u32) {
+        // Taker should authorize the bet
+        taker.require_auth();
+
+        let mut bet = Self::get_p2p_bet(e, bet_id);
+
+        // CHECK: Bet should be open
+        assert!(bet.status == P2PBetStatus::Open, "P2P_BET_NOT_OPEN");
+
+        // CHECK: Taker should be the counterparty when one is set
+        assert!(taker != bet.proposer, "INVALID_COUNTERPARTY");
+
+        if let Some(counterparty) = bet.counterparty.clone() {
+            assert!(taker == counterparty, "INVALID_COUNTERPARTY");
+        }
+
+        // CHECK: Round should be bettable
+        assert!(
+            Self::is_bettable(e, bet.series_id, bet.epoch),
+            "ROUND_NOT_BETTABLE"
+        );
+
+        let token_address = Self::get_token_address(e);
+
+        // CHECK: Stake should be allowed by the caps and the limits of the taker
+        if let Some(error) = Self::get_stake_restriction(
+            e,
+            bet.series_id,
+            bet.epoch,
+            &taker,
+            &token_address,
+            bet.counter_stake,
+        ) {
+            panic_with_error!(e, error);
+        }
+
+        let token_client = token::Client::new(e, &token_address);
+
+        // Safely escrow the stake of the taker
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &taker,
+            &e.current_contract_address(),
+            bet.counter_stake,
+        );
+
+        // Count the stake in the daily wager of the taker
+        Self::record_wager(e, &taker, &token_address, bet.counter_stake);
+
+        bet.taker = Some(taker.clone());
+        bet.status = P2PBetStatus::Matched;
+
+        e.storage().instance().set(&DataKey::P2PBets(bet_id), &bet);
+
+        // Emit an Event for P2P Bet Accepted
+        emit_p2p_bet_accepted_event(e, bet_id, &taker);
+    }
+
+    /// Function to cancel a peer-to-peer bet that was not accepted and refund the stake
+    /// # Parameters
+    /// - `bet_id`: The id of the bet
+    /// # Events
+    /// - `P2P_BET_CANCELLED`: Emitted when the bet is cancelled
+    pub fn cancel_p2p_bet(e: &Env, bet_id: u32) {
+        let mut bet = Self::get_p2p_bet(e, bet_id);
+
+        // Proposer should authorize the cancellation
+        bet.proposer.require_auth();
+
+        // CHECK: Bet should be open
+        assert!(bet.status == P2PBetStatus::Open, "P2P_BET_NOT_OPEN");
+
+        bet.status = P2PBetStatus::Cancelled;
+
+        e.storage().instance().set(&DataKey::P2PBets(bet_id), &bet);
+
+        let token_client = token::Client::new(e, &Self::get_token_address(e));
+
+        // Safely transfer the stake from contract to proposer
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &e.current_contract_address(),
+            &bet.proposer,
+            bet.stake,
+        );
+
+        // Emit an Event for P2P Bet Cancelled
+        emit_p2p_bet_cancelled_event(e, bet_id);
+    }
+
+    /// Function to settle a matched peer-to-peer bet with the outcome of its round
+    /// The winner takes both stakes minus the treasury fee, both stakes are refunded
+    /// if the round ends without a winning side or is never ended
+    /// Callable by anyone
+    /// # Parameters
+    /// - `bet_id`: The id of the bet
+    /// # Events
+    /// - `P2P_BET_SETTLED`: Emitted when the bet is settled
+    pub fn settle_p2p_bet(e: &Env, bet_id: u32) {
+        let mut bet = Self::get_p2p_bet(e, bet_id);
+
+        // CHECK: Bet should be matched
+        assert!(bet.status == P2PBetStatus::Matched, "P2P_BET_NOT_MATCHED");
+
+        let taker = bet.taker.clone().expect("P2P_BET_NOT_MATCHED");
+
+        let round = Self::get_round(e, bet.series_id, bet.epoch);
+
+        let opposite_position = Self::get_opposite_position(e, bet.series_id, &bet.position);
+
+        let winner = if Self::is_claimable(e, bet.series_id, &round, &bet.position) {
+            Some(bet.proposer.clone())
+        } else if Self::is_claimable(e, bet.series_id, &round, &opposite_position) {
+            Some(taker.clone())
+        } else {
+            // CHECK: Round should be over for a refund
+            assert!(
+                Self::is_round_ended(&round) || Self::is_refundable(e, bet.series_id, &round),
+                "ROUND_NOT_ENDED"
+            );
+
+            None
+        };
+
+        bet.status = P2PBetStatus::Settled;
+
+        e.storage().instance().set(&DataKey::P2PBets(bet_id), &bet);
+
+        let token_client = token::Client::new(e, &Self::get_token_address(e));
+
+        let payout = match &winner {
+            Some(winner) => {
+                let pot = bet.stake + bet.counter_stake;
+
+                // The pot pays the fee the round was settled with
+                let treasury_amt = (pot * round.treasury_fee as i128) / 10_000;
+
+                Self::add_treasury_fees(e, treasury_amt);
+
+                // Safely transfer the pot from contract to winner
+                Self::safe_transfer_tokens(
+                    e,
+                    &token_client,
+                    &e.current_contract_address(),
+                    winner,
+                    pot - treasury_amt,
+                );
+
+                pot - treasury_amt
+            }
+            None => {
+                // Safely refund both stakes
+                Self::safe_transfer_tokens(
+                    e,
+                    &token_client,
+                    &e.current_contract_address(),
+                    &bet.proposer,
+                    bet.stake,
+                );
+
+                Self::safe_transfer_tokens(
+                    e,
+                    &token_client,
+                    &e.current_contract_address(),
+                    &taker,
+                    bet.counter_stake,
+                );
+
+                0
+            }
+        };
+
+        // Emit an Event for P2P Bet Settled
+        emit_p2p_bet_settled_event(e, bet_id, &winner, payout);
+    }
+
+    /// Function for a user to lock themselves out of betting until a timestamp
+    /// An exclusion can be extended but not shortened
+    /// # Parameters
+    /// - `user`: The address of the user
+    /// - `until_timestamp`: The time betting is allowed again
+    /// # Events
+    /// - `SELF_EXCLUDED`: Emitted when the exclusion is set
+    pub fn self_exclude(e: &Env, user: Address, until_timestamp: u64) {
+        // User should authorize the exclusion
+        user.require_auth();
+
+        // CHECK: Exclusion should end in the future and not before the current one
+        assert!(
+            until_timestamp > e.ledger().timestamp()
+                && until_timestamp >= Self::get_self_exclusion(e, user.clone()),
+            "INVALID_EXCLUSION_TIMESTAMP"
+        );
+
+        e.storage()
+            .instance()
+            .set(&DataKey::SelfExclusions(user.clone()), &until_timestamp);
+
+        // Emit an Event for Self Excluded
+        emit_self_excluded_event(e, &user, until_timestamp);
+    }
+
+    /// Function for a user to set the maximum amount they can wager per day, valued in the betting token
+    /// A tighter limit applies at once, a looser one only after a cooldown of 7 days
+    /// # Parameters
+    /// - `user`: The address of the user
+    /// - `max_wager_per_day`: The maximum amount wagered per day, 0 for no limit
+    /// # Events
+    /// - `USER_LIMIT_UPDATED`: Emitted when the limit is updated
+    pub fn set_user_limit(e: &Env, user: Address, max_wager_per_day: i128) {
+        // User should authorize the limit
+        user.require_auth();
+
+        assert!(max_wager_per_day >= 0, "INVALID_AMOUNT");
+
+        let current_limit = Self::get_user_wager_limit(e, user.clone());
+
+        // A limit of 0 is no limit, the loosest one
+        let is_tighter =
+            max_wager_per_day != 0 && (current_limit == 0 || max_wager_per_day <= current_limit);
+
+        let limit = if is_tighter {
+            UserLimit {
+                max_wager_per_day,
+                pending_max_wager_per_day: None,
+                pending_timestamp: 0,
+            }
+        } else {
+            UserLimit {
+                max_wager_per_day: current_limit,
+                pending_max_wager_per_day: Some(max_wager_per_day),
+                pending_timestamp: e.ledger().timestamp() + USER_LIMIT_COOLDOWN_SECONDS,
+            }
+        };
+
+        e.storage()
+            .instance()
+            .set(&DataKey::UserLimits(user.clone()), &limit);
+
+        // Emit an Event for User Limit Updated
+        emit_user_limit_updated_event(e, &user, &limit);
+    }
+
+    /// Flash loan function to borrow tokens temporarily    
+    /// Loans are backed by the house vault liquidity and the fee accrues to vault LPs
+    /// # Parameters
+    /// - `initiator`: The address requesting the loan
+    /// - `amount`: The amount of tokens to borrow
+    /// - `receiver`: The address of the receiver of the tokens
+    pub fn flash_loan(e: &Env, initiator: Address, amount: i128, receiver: Address) {
+        // Initiator should authorize the loan
+        initiator.require_auth();
+
+        // CHECK: Initiator and receiver should be granted access
+        Self::require_access(e, &initiator);
+        Self::require_access(e, &receiver);
+
+        // CHECK: Loan should be covered by the vault liquidity
+        let vault_total_assets: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::VaultTotalAssets)
+            .expect("VAULT_TOTAL_ASSETS_NOT_FOUND");
+
+        assert!(amount <= vault_total_assets, "INSUFFICIENT_VAULT_LIQUIDITY");
+
+        // Get The Flash Loan Fee
+        let flash_loan_fee: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::FlashLoanFee)
+            .expect("FLASH_LOAN_FEE_NOT_FOUND");
+
+        // Calculate The Fee Amount
+        let fee_amount: i128 = (amount * flash_loan_fee as i128) / 10_000;
+
+        // Get Token Address
+        let token_address: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("TOKEN_ADDRESS_NOT_FOUND");
+
+        // Create Token Client
+        let token_client = token::Client::new(e, &token_address);
+
+        let current_contract_address = e.current_contract_address();
+
+        // Get The token balance before the flash loan
+        let balance_before: i128 = token_client.balance(&current_contract_address);
+
+        // Safely transfer tokens from contract to receiver
+        Self::safe_transfer_tokens(
+            e,
+            &token_client,
+            &current_contract_address,
+            &receiver,
+            amount,
+        );
+
+        // Receiver should implement the FlashLoanReceiver trait
+        FlashLoanClient::new(e, &receiver).execute_flash_loan(
+            &current_contract_address,
+            &token_address,
+            &amount,
+            &fee_amount,
+        );
+
+        // Get The token balance after the flash loan
+        let balance_after: i128 = token_client.balance(&current_contract_address);
+
+        // Ensure that the receiver has repaid the loan plus fee
+        assert!(
+            balance_after >= balance_before + fee_amount,
+            "FLASH_LOAN_NOT_REPAID"
+        );
+
+        // Flash Fees accrue to the vault share price
+        Self::accrue_vault_fees(e, Symbol::new(e, "FLASH_LOAN"), fee_amount);
+
+        // Emit an Event for Flash Loan
+        emit_flash_loan_event(e, &initiator, &receiver, amount, fee_amount);
+    }
+
+    /// Function to deposit betting tokens into the house vault
+    /// # Parameters
+    /// - `lp`: The address of the liquidity provider
+    /// - `amount`: The amount of tokens to deposit
+    /// # Returns
+    /// - `i128`: The amount of vault shares minted
+    /// # Events
+    /// - `VAULT_DEPOSIT`: Emitted when tokens are deposited into the vault
+    pub fn vault_deposit(e: &Env, lp: Address, amount: i128) -> i128 {
+        // LP should authorize the deposit
+        lp.require_auth();
+
+        assert!(amount > 0, "INVALID_AMOUNT");
+
+        // CHECK: LP should be granted access
+        Self::require_access(e, &lp);
+
+        let mut position = Self::get_vault_position(e, lp.clone());
+
+        let current_timestamp: u64 = e.ledger().timestamp();
+
+        // CHECK: Cooldown since the last withdrawal should have elapsed
+        let cooldown_seconds = Self::get_vault_cooldown_seconds(e);
+
+        assert!(
+            position.last_withdraw_timestamp == 0
+                || current_timestamp >= position.last_withdraw_timestamp + cooldown_seconds,
+            "VAULT_COOLDOWN_NOT_ELAPSED"
+        );
+
+        let total_assets = Self::get_vault_total_assets(e);
+        let total_shares = Self::get_vault_total_shares(e);
+
+        // CHECK: Vault should not be drained while shares are outstanding, new deposits
+        // would be split with worthless shares
+        assert!(total_shares == 0 || total_assets > 0, "VAULT_INSOLVENT");
+
+        // Shares are minted 1:1 for the first deposit, then pro-rata to the share price
+        let shares = if total_shares == 0 {
+            amount
+        } else {
+            (amount * total_shares) / total_assets
+        };
+
+        assert!(shares > 0, "INVALID_AMOUNT");
+
+        let token_address = Self::get_token_address(e);
+        let token_client = token::Client::new(e, &token_address);
+
+        // Safely transfer tokens from LP to contract
+        Self::safe_transfer_tokens(e, &token_client, &lp, &e.current_contract_address(), amount);
+
+        // Update Vault Totals
+        e.storage()
+            .instance()
+            .set(&DataKey::VaultTotalAssets, &(total_assets + amount));
+        e.storage()
+            .instance()
+            .set(&DataKey::VaultTotalShares, &(total_shares + shares));
+
+        // Update LP Position
+        position.shares += shares;
+        position.last_deposit_timestamp = current_timestamp;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::VaultPositions(lp.clone()), &position);
+
+        // Emit an Event for Vault Deposit
+        emit_vault_deposit_event(e, &lp, amount, shares);
+
+        shares
+    }
+
+    /// Function to redeem vault shares for betting tokens
+    /// # Parameters
+    /// - `lp`: The address of the liquidity provider
+    /// - `shares`: The amount of vault shares to redeem
+    /// # Returns
+    /// - `i128`: The amount of tokens withdrawn
+    /// # Events
+    /// - `VAULT_WITHDRAW`: Emitted when tokens are withdrawn from the vault
+    pub fn vault_withdraw(e: &Env, lp: Address, shares: i128) -> i128 {
+        // LP should authorize the withdrawal
+        lp.require_auth();
+
+        assert!(shares > 0, "INVALID_AMOUNT");
+
+        let mut position = Self::get_vault_position(e, lp.clone());
+
+        assert!(position.shares >= shares, "INSUFFICIENT_VAULT_SHARES");
+
+        let current_timestamp: u64 = e.ledger().timestamp();
+
+        // CHECK: Cooldown since the last deposit should have elapsed
+        let cooldown_seconds = Self::get_vault_cooldown_seconds(e);
+
+        assert!(
+            current_timestamp >= position.last_deposit_timestamp + cooldown_seconds,
+            "VAULT_COOLDOWN_NOT_ELAPSED"
+        );
+
+        let total_assets = Self::get_vault_total_assets(e);
+        let total_shares = Self::get_vault_total_shares(e);
+
+        let amount = (shares * total_assets) / total_shares;
+
+        // CHECK: Vault should keep covering the open exposure of the house
+        assert!(
+            total_assets - amount >= Self::get_house_exposure(e),
+            "INSUFFICIENT_VAULT_LIQUIDITY"
+        );
+
+        // Update Vault Totals
+        e.storage()
+            .instance()
+            .set(&DataKey::VaultTotalAssets, &(total_assets - amount));
+        e.storage()
+            .instance()
+            .set(&DataKey::VaultTotalShares, &(total_shares - shares));
+
+        // Update LP Position
+        position.shares -= shares;
+        position.last_withdraw_timestamp = current_timestamp;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::VaultPositions(lp.clone()), &position);
+
+        let token_address = Self::get_token_address(e);
+        let token_client = token::Client::new(e, &token_address);
+
+        // Safely transfer tokens from contract to LP
+        Self::safe_transfer_tokens(e, &token_client, &e.current_contract_address(), &lp, amount);
+
+        // Emit an Event for Vault Withdraw
+        emit_vault_withdraw_event(e, &lp, amount, shares);
+
+        amount
+    }
+
+    //////////////////////////////// ADMIN ////////////////////////////////
+
+    /// Function to create a new series of rounds with its own timeframe
+    /// Series share the token, treasury and oracle configuration
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `interval_seconds`: Duration of each round of the series in seconds
+    /// - `buffer_seconds`: Buffer to execute the rounds of the series in seconds
+    /// # Returns
+    /// - `u32`: The id of the new series
+    /// # Events
+    /// - `SERIES_CREATED`: Emitted when a series is created
+    #[only_owner]
+    pub fn create_series(e: &Env, interval_seconds: u64, buffer_seconds: u64) -> u32 {
+        assert!(interval_seconds > 0, "INVALID_INTERVAL_SECONDS");
+
+        Self::init_series(e, interval_seconds, buffer_seconds)
+    }
+
+    /// Function to create a new series whose rounds are bet on price buckets instead of up/down
+    /// Boundaries split the price change from lock to close into buckets, a change equal
+    /// to a boundary falls in the bucket above it
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `interval_seconds`: Duration of each round of the series in seconds
+    /// - `buffer_seconds`: Buffer to execute the rounds of the series in seconds
+    /// - `boundaries`: Ascending price changes relative to the lock price (scaled by 100, e.g., -100 = -1%)
+    /// # Returns
+    /// - `u32`: The id of the new series
+    /// # Events
+    /// - `SERIES_CREATED`: Emitted when a series is created
+    #[only_owner]
+    pub fn create_bucket_series(
+        e: &Env,
+        interval_seconds: u64,
+        buffer_seconds: u64,
+        boundaries: Vec<i32>,
+    ) -> u32 {
+        assert!(interval_seconds > 0, "INVALID_INTERVAL_SECONDS");
+
+        // CHECK: Boundaries should define between 2 and 10 buckets
+        assert!(
+            !boundaries.is_empty() && boundaries.len() <= MAX_BUCKET_BOUNDARIES,
+            "INVALID_BUCKET_BOUNDARIES"
+        );
+
+        // CHECK: Boundaries should be strictly ascending and above a -100% change
+        let mut prev_boundary = -10_000;
+
+        for boundary in boundaries.iter() {
+            assert!(boundary > prev_boundary, "INVALID_BUCKET_BOUNDARIES");
+
+            prev_boundary = boundary;
+        }
+
+        let series_id = Self::init_series(e, interval_seconds, buffer_seconds);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::SeriesBuckets(series_id), &boundaries);
+
+        series_id
+    }
+
+    /// Function to create a new series of strike rounds
+    /// Strike rounds are created one by one with their own strike price and expiry, and
+    /// several of them can be open at the same time
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `buffer_seconds`: Buffer to settle a strike round after its expiry in seconds
+    /// # Returns
+    /// - `u32`: The id of the new series
+    /// # Events
+    /// - `SERIES_CREATED`: Emitted when a series is created
+    #[only_owner]
+    pub fn create_strike_series(e: &Env, buffer_seconds: u64) -> u32 {
+        let series_id = Self::init_series(e, 0, buffer_seconds);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::StrikeSeries(series_id), &true);
+
+        series_id
+    }
+
+    /// Function to create a new series whose rounds are bet on which of two oracle assets
+    /// has the higher percentage change between lock and close
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `interval_seconds`: Duration of each round of the series in seconds
+    /// - `buffer_seconds`: Buffer to execute the rounds of the series in seconds
+    /// - `asset`: The first asset, pooled on the bull side of the rounds
+    /// - `rival_asset`: The second asset, pooled on the bear side of the rounds
+    /// # Returns
+    /// - `u32`: The id of the new series
+    /// # Events
+    /// - `SERIES_CREATED`: Emitted when a series is created
+    #[only_owner]
+    pub fn create_head_to_head_series(
+        e: &Env,
+        interval_seconds: u64,
+        buffer_seconds: u64,
+        asset: Symbol,
+        rival_asset: Symbol,
+    ) -> u32 {
+        assert!(interval_seconds > 0, "INVALID_INTERVAL_SECONDS");
+
+        // CHECK: Assets should be distinct
+        assert!(asset != rival_asset, "INVALID_SERIES_ASSETS");
+
+        // CHECK: Both assets should be priced by the oracle
+        Self::get_asset_oracle_price(e, asset.clone());
+        Self::get_asset_oracle_price(e, rival_asset.clone());
+
+        let series_id = Self::init_series(e, interval_seconds, buffer_seconds);
+
+        e.storage().instance().set(
+            &DataKey::SeriesAssets(series_id),
+            &Vec::from_array(e, [asset, rival_asset]),
+        );
+
+        series_id
+    }
+
+    /// Function to create a new series whose rounds are bet on the size of the price move
+    /// A round is a big move when the price changes from lock to close by more than the threshold
+    /// in either direction, and a small move otherwise
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `interval_seconds`: Duration of each round of the series in seconds
+    /// - `buffer_seconds`: Buffer to execute the rounds of the series in seconds
+    /// - `move_threshold`: The price change separating small and big moves (scaled by 100, e.g., 100 = 1%)
+    /// # Returns
+    /// - `u32`: The id of the new series
+    /// # Events
+    /// - `SERIES_CREATED`: Emitted when a series is created
+    #[only_owner]
+    pub fn create_volatility_series(
+        e: &Env,
+        interval_seconds: u64,
+        buffer_seconds: u64,
+        move_threshold: u32,
+    ) -> u32 {
+        assert!(interval_seconds > 0, "INVALID_INTERVAL_SECONDS");
+        assert!(move_threshold > 0, "INVALID_MOVE_THRESHOLD");
+
+        let series_id = Self::init_series(e, interval_seconds, buffer_seconds);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::VolatilitySeries(series_id), &move_threshold);
+
+        series_id
+    }
+
+    /// Function to create a new series of yes/no event rounds settled by resolvers
+    /// Event rounds are created one by one, each with its own question and resolver
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `buffer_seconds`: Time after the close of a round to resolve and settle it, after which bets are refunded
+    /// - `dispute_seconds`: Time after a resolution during which the owner can override it
+    /// # Returns
+    /// - `u32`: The id of the new series
+    /// # Events
+    /// - `SERIES_CREATED`: Emitted when a series is created
+    #[only_owner]
+    pub fn create_event_series(e: &Env, buffer_seconds: u64, dispute_seconds: u64) -> u32 {
+        // CHECK: Rounds should be able to settle after the dispute window
+        assert!(dispute_seconds < buffer_seconds, "INVALID_DISPUTE_SECONDS");
+
+        let series_id = Self::init_series(e, 0, buffer_seconds);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::EventSeries(series_id), &dispute_seconds);
+
+        series_id
+    }
+
+    /// Function to set the cooldown between vault deposits and withdrawals
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `cooldown_seconds`: The cooldown in seconds
+    #[only_owner]
+    pub fn set_vault_cooldown_seconds(e: &Env, cooldown_seconds: u64) {
+        e.storage()
+            .instance()
+            .set(&DataKey::VaultCooldownSeconds, &cooldown_seconds);
+    }
+
+    /// Function to set the share of the treasury fee routed to the vault
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `fee_share`: Share of the treasury fee (scaled by 100, e.g., 5000 = 50%)
+    #[only_owner]
+    pub fn set_vault_treasury_fee_share(e: &Env, fee_share: u32) {
+        assert!(
+            fee_share <= MAX_VAULT_TREASURY_FEE_SHARE,
+            "VAULT_TREASURY_FEE_SHARE_TOO_HIGH"
+        );
+
+        e.storage()
+            .instance()
+            .set(&DataKey::VaultTreasuryFeeShare, &fee_share);
+    }
+
+    /// Function to allowlist an additional betting token
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `token`: The address of the SAC or fungible token
+    /// - `min_bet_amount`: Minimum amount required to place a bet with this token
+    /// - `rate`: The value of the token in the betting token (scaled by 10_000_000), used to
+    ///   hold its bets to the caps
+    /// # Events
+    /// - `TOKEN_ADDED`: Emitted when a token is allowlisted
+    #[only_owner]
+    pub fn add_token(e: &Env, token: Address, min_bet_amount: i128, rate: i128) {
+        assert!(token != Self::get_token_address(e), "TOKEN_ALREADY_ALLOWED");
+        assert!(min_bet_amount > 0, "INVALID_AMOUNT");
+        assert!(rate > 0, "INVALID_TOKEN_RATE");
+
+        let mut allowed_tokens = Self::get_allowed_tokens(e);
+
+        assert!(!allowed_tokens.contains(&token), "TOKEN_ALREADY_ALLOWED");
+
+        allowed_tokens.push_back(token.clone());
+
+        e.storage()
+            .instance()
+            .set(&DataKey::AllowedTokens, &allowed_tokens);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::TokenMinBetAmount(token.clone()), &min_bet_amount);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::TokenRates(token.clone()), &rate);
+
+        // Emit an Event for Token Added
+        emit_token_added_event(e, &token, min_bet_amount, rate);
+    }
+
+    /// Function to update the rate of an allowlisted token as its price moves
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `token`: The address of the token
+    /// - `rate`: The value of the token in the betting token (scaled by 10_000_000)
+    /// # Events
+    /// - `TOKEN_RATE_UPDATED`: Emitted when the rate is updated
+    #[only_owner]
+    pub fn set_token_rate(e: &Env, token: Address, rate: i128) {
+        // CHECK: Token should be allowlisted
+        assert!(
+            Self::get_allowed_tokens(e).contains(&token),
+            "TOKEN_NOT_ALLOWED"
+        );
+
+        assert!(rate > 0, "INVALID_TOKEN_RATE");
+
+        e.storage()
+            .instance()
+            .set(&DataKey::TokenRates(token.clone()), &rate);
+
+        // Emit an Event for Token Rate Updated
+        emit_token_rate_updated_event(e, &token, rate);
+    }
+
+    /// Function to remove a token from the allowlist
+    /// Existing bets placed with the token are unaffected
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `token`: The address of the token
+    /// # Events
+    /// - `TOKEN_REMOVED`: Emitted when a token is removed from the allowlist
+    #[only_owner]
+    pub fn remove_token(e: &Env, token: Address) {
+        let mut allowed_tokens = Self::get_allowed_tokens(e);
+
+        let index = allowed_tokens
+            .first_index_of(&token)
+            .expect("TOKEN_NOT_ALLOWED");
+
+        allowed_tokens.remove(index);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::AllowedTokens, &allowed_tokens);
+
+        e.storage()
+            .instance()
+            .remove(&DataKey::TokenMinBetAmount(token.clone()));
+
+        // The rate is kept to value the bets already placed with the token
+
+        // Emit an Event for Token Removed
+        emit_token_removed_event(e, &token);
+    }
+
+    /// Function to set the access mode gating bets, flash loans and deposits
+    /// Claims and refunds of placed bets are never gated
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `mode`: Open to everyone, closed to blocked addresses, or open to allowed addresses only
+    /// # Events
+    /// - `ACCESS_MODE_UPDATED`: Emitted when the access mode is updated
+    #[only_owner]
+    pub fn set_access_mode(e: &Env, mode: AccessMode) {
+        e.storage().instance().set(&DataKey::AccessMode, &mode);
+
+        // Emit an Event for Access Mode Updated
+        emit_access_mode_updated_event(e, mode);
+    }
+
+    /// Function to set the compliance manager maintaining the access lists
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `manager`: The address of the compliance manager
+    /// # Events
+    /// - `COMPLIANCE_MANAGER_UPDATED`: Emitted when the compliance manager is updated
+    #[only_owner]
+    pub fn set_compliance_manager(e: &Env, manager: Address) {
+        e.storage()
+            .instance()
+            .set(&DataKey::ComplianceManager, &manager);
+
+        // Emit an Event for Compliance Manager Updated
+        emit_compliance_manager_updated_event(e, &manager);
+    }
+
+    /// Function to add or remove an address from the blocklist
+    /// Blocked addresses are refused in the blocklist and allowlist modes
+    /// Only callable by the compliance manager
+    /// # Parameters
+    /// - `user`: The address to update
+    /// - `blocked`: Whether the address is blocked
+    /// # Events
+    /// - `BLOCKLIST_UPDATED`: Emitted when the blocklist is updated
+    pub fn set_blocked(e: &Env, user: Address, blocked: bool) {
+        // Compliance manager should authorize the update
+        Self::get_compliance_manager(e).require_auth();
+
+        if blocked {
+            e.storage()
+                .instance()
+                .set(&DataKey::Blocklist(user.clone()), &true);
+        } else {
+            e.storage()
+                .instance()
+                .remove(&DataKey::Blocklist(user.clone()));
+        }
+
+        // Emit an Event for Blocklist Updated
+        emit_blocklist_updated_event(e, &user, blocked);
+    }
+
+    /// Function to add or remove an address from the allowlist
+    /// Only callable by the compliance manager
+    /// # Parameters
+    /// - `user`: The address to update
+    /// - `allowed`: Whether the address is allowed
+    /// # Events
+    /// - `ALLOWLIST_UPDATED`: Emitted when the allowlist is updated
+    pub fn set_allowed(e: &Env, user: Address, allowed: bool) {
+        // Compliance manager should authorize the update
+        Self::get_compliance_manager(e).require_auth();
+
+        if allowed {
+            e.storage()
+                .instance()
+                .set(&DataKey::Allowlist(user.clone()), &true);
+        } else {
+            e.storage()
+                .instance()
+                .remove(&DataKey::Allowlist(user.clone()));
+        }
+
+        // Emit an Event for Allowlist Updated
+        emit_allowlist_updated_event(e, &user, allowed);
+    }
+
+    /// Function to set the operator relaying signed bet intents
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `operator`: The address of the operator
+    #[only_owner]
+    pub fn set_operator(e: &Env, operator: Address) {
+        e.storage().instance().set(&DataKey::Operator, &operator);
+    }
+
+    /// Function to set the share of the treasury fee paid to referrers
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `fee_share`: Share of the treasury fee (scaled by 100, e.g., 2000 = 20%)
+    #[only_owner]
+    pub fn set_referral_fee_share(e: &Env, fee_share: u32) {
+        assert!(
+            fee_share <= MAX_REFERRAL_FEE_SHARE,
+            "REFERRAL_FEE_SHARE_TOO_HIGH"
+        );
+
+        e.storage()
+            .instance()
+            .set(&DataKey::ReferralFeeShare, &fee_share);
+    }
+
+    /// Function to create a season over a range of future epochs of a series
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `series_id`: The id of the series the season follows
+    /// - `start_epoch`: The first epoch of the season
+    /// - `end_epoch`: The last epoch of the season
+    /// - `metric`: The metric ranking the season leaderboard
+    /// - `payout_table`: Share of the prize pool per rank (scaled by 100, e.g., 5000 = 50%)
+    /// # Returns
+    /// - `u32`: The id of the new season
+    /// # Events
+    /// - `SEASON_CREATED`: Emitted when a season is created
+    #[only_owner]
+    pub fn create_season(
+        e: &Env,
+        series_id: u32,
+        start_epoch: u128,
+        end_epoch: u128,
+        metric: RankingMetric,
+        payout_table: Vec<u32>,
+    ) -> u32 {
+        assert!(start_epoch <= end_epoch, "INVALID_SEASON_EPOCHS");
+
+        // CHECK: Season should only cover rounds that have not started yet
+        assert!(
+            start_epoch > Self::get_current_epoch(e, series_id),
+            "INVALID_SEASON_EPOCHS"
+        );
+
+        // CHECK: Payout table should fit the leaderboard and the prize pool
+        assert!(
+            !payout_table.is_empty() && payout_table.len() <= LEADERBOARD_SIZE,
+            "INVALID_PAYOUT_TABLE"
+        );
+
+        let mut payout_total: u32 = 0;
+
+        for payout in payout_table.iter() {
+            payout_total += payout;
+        }
+
+        assert!(payout_total <= 10_000, "INVALID_PAYOUT_TABLE");
+
+        let season_count = Self::get_season_count(e);
+
+        // CHECK: Seasons of a series should not overlap
+        let mut prev_season_id = season_count;
+
+        while prev_season_id > 0 {
+            let prev_season = Self::get_season(e, prev_season_id);
+
+            if prev_season.series_id == series_id {
+                assert!(start_epoch > prev_season.end_epoch, "SEASONS_OVERLAP");
+
+                break;
+            }
+
+            prev_season_id -= 1;
+        }
+
+        let season = Season {
+            id: season_count + 1,
+            series_id,
+            start_epoch,
+            end_epoch,
+            metric,
+            prize_pool: 0,
+            payout_table,
+            finalized: false,
+            winners: Vec::new(e),
+            finalized_timestamp: 0,
+            claimed_amount: 0,
+            swept: false,
+        };
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Seasons(season.id), &season);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::SeasonCount, &season.id);
+
+        // Emit an Event for Season Created
+        emit_season_created_event(e, &season);
+
+        season.id
+    }
+
+    /// Function to move part of the treasury into the prize pool of a season
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `season_id`: The id of the season
+    /// - `amount`: The amount of tokens to move
+    /// # Events
+    /// - `SEASON_FUNDED`: Emitted when the prize pool is funded
+    #[only_owner]
+    pub fn fund_season_from_treasury(e: &Env, season_id: u32, amount: i128) {
+        assert!(amount > 0, "INVALID_AMOUNT");
+
+        let treasury_amount = Self::get_treasury_amount(e);
+
+        assert!(treasury_amount >= amount, "INSUFFICIENT_TREASURY");
+
+        let mut season = Self::get_season(e, season_id);
+
+        assert!(!season.finalized, "SEASON_ALREADY_FINALIZED");
+
+        season.prize_pool += amount;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Seasons(season_id), &season);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::TreasuryAmount, &(treasury_amount - amount));
+
+        // Emit an Event for Season Funded
+        emit_season_funded_event(e, season_id, &e.current_contract_address(), amount);
+    }
+
+    /// Function to freeze the leaderboard of an ended season and open prize claims
+    /// Allowed once every round of the season has ended and the grace period after the last one
+    /// to close has passed, so that stats of every entrant can be settled with `sync_user_stats`
+    /// Only users with a positive score fill the payout slots
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `season_id`: The id of the season
+    /// # Events
+    /// - `SEASON_FINALIZED`: Emitted when the season is finalized
+    #[only_owner]
+    pub fn finalize_season(e: &Env, season_id: u32) {
+        let mut season = Self::get_season(e, season_id);
+
+        assert!(!season.finalized, "SEASON_ALREADY_FINALIZED");
+
+        // CHECK: Every round of the season should have ended
+        let close_timestamp = Self::get_season_close_timestamp(e, &season);
+
+        // CHECK: Grace period after the last round to close should have passed
+        assert!(
+            e.ledger().timestamp() >= close_timestamp + SEASON_GRACE_SECONDS,
+            "SEASON_GRACE_PERIOD"
+        );
+
+        let mut winners: Vec<Address> = Vec::new(e);
+
+        for entry in Self::get_leaderboard(e, season_id).iter() {
+            if winners.len() == season.payout_table.len() || entry.score <= 0 {
+                break;
+            }
+
+            winners.push_back(entry.user);
+        }
+
+        season.finalized = true;
+        season.winners = winners;
+        season.finalized_timestamp = e.ledger().timestamp();
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Seasons(season_id), &season);
+
+        // Emit an Event for Season Finalized
+        emit_season_finalized_event(e, season_id, &season.winners);
+    }
+
+    /// Function to move the unallocated and unclaimed prizes of a season back to the treasury
+    /// Only callable by the owner, once the claim period after finalization has passed
+    /// # Parameters
+    /// - `season_id`: The id of the season
+    /// # Events
+    /// - `SEASON_SWEPT`: Emitted when the season is swept
+    #[only_owner]
+    pub fn sweep_season(e: &Env, season_id: u32) {
+        let mut season = Self::get_season(e, season_id);
+
+        assert!(season.finalized, "SEASON_NOT_FINALIZED");
+
+        assert!(!season.swept, "SEASON_SWEPT");
+
+        // CHECK: Claim period should have passed
+        assert!(
+            e.ledger().timestamp() >= season.finalized_timestamp + SEASON_PRIZE_CLAIM_SECONDS,
+            "SEASON_CLAIM_PERIOD"
+        );
+
+        let amount = season.prize_pool - season.claimed_amount;
+
+        season.swept = true;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Seasons(season_id), &season);
+
+        let treasury_amount = Self::get_treasury_amount(e);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::TreasuryAmount, &(treasury_amount + amount));
+
+        // Emit an Event for Season Swept
+        emit_season_swept_event(e, season_id, amount);
+    }
+
+    /// Function to set the share of the treasury fee funding the jackpot
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `fee_share`: Share of the treasury fee (scaled by 100, e.g., 1000 = 10%)
+    #[only_owner]
+    pub fn set_jackpot_fee_share(e: &Env, fee_share: u32) {
+        assert!(
+            fee_share <= MAX_JACKPOT_FEE_SHARE,
+            "JACKPOT_FEE_SHARE_TOO_HIGH"
+        );
+
+        e.storage()
+            .instance()
+            .set(&DataKey::JackpotFeeShare, &fee_share);
+    }
+
+    /// Function to set the number of rounds between jackpot draws
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `interval`: The number of rounds per jackpot period
+    #[only_owner]
+    pub fn set_jackpot_interval(e: &Env, interval: u128) {
+        assert!(interval > 0, "INVALID_JACKPOT_INTERVAL");
+
+        e.storage()
+            .instance()
+            .set(&DataKey::JackpotInterval, &interval);
+    }
+
+    /// Function to set the minimum escrow of a new standing order in the betting token
+    /// Raises the cost of filling the standing order slots of a series
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `min_escrow`: The minimum of the amount times the rounds of an order, 0 for none
+    #[only_owner]
+    pub fn set_standing_order_min_escrow(e: &Env, min_escrow: i128) {
+        assert!(min_escrow >= 0, "INVALID_AMOUNT");
+
+        e.storage()
+            .instance()
+            .set(&DataKey::StandingOrderMinEscrow, &min_escrow);
+    }
+
+    /// Function to set the fee curve replacing the flat treasury fee
+    /// The fee of a round goes from the minimum fee on a balanced pool to the maximum fee
+    /// on a one-sided pool
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `fee_curve`: The minimum and maximum fees (scaled by 100), None to use the flat treasury fee
+    #[only_owner]
+    pub fn set_fee_curve(e: &Env, fee_curve: Option<FeeCurve>) {
+        match fee_curve {
+            Some(fee_curve) => {
+                assert!(
+                    fee_curve.min_fee <= fee_curve.max_fee && fee_curve.max_fee <= MAX_TREASURY_FEE,
+                    "INVALID_FEE_CURVE"
+                );
+
+                e.storage().instance().set(&DataKey::FeeCurve, &fee_curve);
+            }
+            None => e.storage().instance().remove(&DataKey::FeeCurve),
+        }
+    }
+
+    /// Function to set the fixed odds quoted by the house on each side of a series
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `bull_odds`: The odds of the bull side (scaled by 10_000, e.g., 19_000 = 1.9x), 0 to disable
+    /// - `bear_odds`: The odds of the bear side (scaled by 10_000, e.g., 19_000 = 1.9x), 0 to disable
+    #[only_owner]
+    pub fn set_house_odds(e: &Env, series_id: u32, bull_odds: u32, bear_odds: u32) {
+        assert!(series_id < Self::get_series_count(e), "SERIES_NOT_FOUND");
+
+        // CHECK: Odds should pay more than the stake
+        assert!(
+            (bull_odds == 0 || bull_odds > 10_000) && (bear_odds == 0 || bear_odds > 10_000),
+            "INVALID_ODDS"
+        );
+
+        e.storage().instance().set(
+            &DataKey::HouseOdds(series_id),
+            &HouseOdds {
+                bull_odds,
+                bear_odds,
+            },
+        );
+    }
+
+    /// Function to set the risk budget of the house for fixed-odds bets
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `max_side_exposure`: The maximum payout owed to one side of a round
+    /// - `max_round_exposure`: The maximum loss of the house on a round
+    #[only_owner]
+    pub fn set_house_limits(e: &Env, max_side_exposure: i128, max_round_exposure: i128) {
+        assert!(
+            max_side_exposure >= 0 && max_round_exposure >= 0,
+            "INVALID_HOUSE_LIMITS"
+        );
+
+        e.storage()
+            .instance()
+            .set(&DataKey::HouseMaxSideExposure, &max_side_exposure);
+        e.storage()
+            .instance()
+            .set(&DataKey::HouseMaxRoundExposure, &max_round_exposure);
+    }
+
+    /// Function to set the maximum amount of a single bet, bets in other tokens are valued in
+    /// the betting token
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `max_bet_amount`: The maximum bet amount, 0 for no cap
+    #[only_owner]
+    pub fn set_max_bet_amount(e: &Env, max_bet_amount: i128) {
+        assert!(max_bet_amount >= 0, "INVALID_AMOUNT");
+
+        e.storage()
+            .instance()
+            .set(&DataKey::MaxBetAmount, &max_bet_amount);
+    }
+
+    /// Function to set the maximum total of a round across its token pools, valued in the betting token
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `max_round_amount`: The maximum total amount bet on a round, 0 for no cap
+    #[only_owner]
+    pub fn set_max_round_amount(e: &Env, max_round_amount: i128) {
+        assert!(max_round_amount >= 0, "INVALID_AMOUNT");
+
+        e.storage()
+            .instance()
+            .set(&DataKey::MaxRoundAmount, &max_round_amount);
+    }
+
+    /// Function to set the rolling cap of every user, valued in the betting token
+    /// A user can bet at most `amount` over the last `rounds` rounds of a series
+    /// Only callable by the owner
+    /// # Parameters
+    /// - `amount`: The maximum amount bet over the window, 0 for no cap
+    /// - `rounds`: The number of rounds in the window, at most 100
+    #[only_owner]
+    pub fn set_user_cap(e: &Env, amount: i128, rounds: u32) {
+        assert!(amount >= 0, "INVALID_AMOUNT");
+
+        assert!(
+            rounds > 0 && rounds <= MAX_USER_CAP_ROUNDS,
+            "INVALID_USER_CAP_ROUNDS"
+        );
+
+        e.storage()
+            .instance()
+            .set(&DataKey::UserCap, &UserCap { amount, rounds });
+    }
+
+    //////////////////////////////// GETTERS ////////////////////////////////
+
+    /// Internal function to get XLM price from the oracle
+    /// # Returns
+    /// - `i128`: XLM price in stroops
+    pub fn get_xlm_oracle_price(e: &Env) -> i128 {
+        Self::get_asset_oracle_price(e, Symbol::new(e, "XLM"))
+    }
+
+    pub fn get_market_asset(e: &Env) -> Symbol {
+        e.storage()
+            .instance()
+            .get(&DataKey::MarketAsset)
+            .expect("MARKET_ASSET_NOT_FOUND")
+    }
+
+    pub fn get_series_count(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SeriesCount)
+            .expect("SERIES_COUNT_NOT_FOUND")
+    }
+
+    /// Readonly function to get the bucket boundaries of a series
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// # Returns
+    /// - `Vec<i32>`: The boundaries, empty for an up/down series
+    pub fn get_series_buckets(e: &Env, series_id: u32) -> Vec<i32> {
+        e.storage()
+            .instance()
+            .get(&DataKey::SeriesBuckets(series_id))
+            .unwrap_or(Vec::new(e))
+    }
+
+    /// Readonly function to get the assets of a head-to-head series
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// # Returns
+    /// - `Vec<Symbol>`: The first and second asset, empty for a single asset series
+    pub fn get_series_assets(e: &Env, series_id: u32) -> Vec<Symbol> {
+        e.storage()
+            .instance()
+            .get(&DataKey::SeriesAssets(series_id))
+            .unwrap_or(Vec::new(e))
+    }
+
+    /// Readonly function to get the move threshold of a volatility series
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// # Returns
+    /// - `u32`: The threshold (scaled by 100), 0 for a directional series
+    pub fn get_move_threshold(e: &Env, series_id: u32) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::VolatilitySeries(series_id))
+            .unwrap_or(0)
+    }
+
+    pub fn is_event_series(e: &Env, series_id: u32) -> bool {
+        e.storage().instance().has(&DataKey::EventSeries(series_id))
+    }
+
+    pub fn get_event_dispute_seconds(e: &Env, series_id: u32) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::EventSeries(series_id))
+            .expect("EVENT_SERIES_NOT_FOUND")
+    }
+
+    pub fn get_event_info(e: &Env, series_id: u32, epoch: u128) -> EventInfo {
+        e.storage()
+            .instance()
+            .get(&DataKey::EventInfos(series_id, epoch))
+            .expect("EVENT_INFO_NOT_FOUND")
+    }
+
+    pub fn is_strike_series(e: &Env, series_id: u32) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::StrikeSeries(series_id))
+            .unwrap_or(false)
+    }
+
+    pub fn get_is_genesis_started(e: &Env, series_id: u32) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::IsGenesisStarted(series_id))
+            .expect("IS_GENESIS_STARTED_NOT_FOUND")
+    }
+
+    pub fn get_is_genesis_locked(e: &Env, series_id: u32) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::IsGenesisLocked(series_id))
+            .expect("IS_GENESIS_LOCKED_NOT_FOUND")
+    }
+
+    pub fn get_current_epoch(e: &Env, series_id: u32) -> u128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::CurrentEpoch(series_id))
+            .expect("CURRENT_EPOCH_NOT_FOUND")
+    }
+
+    pub fn get_token_address(e: &Env) -> Address {
+        e.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("TOKEN_ADDRESS_NOT_FOUND")
+    }
+
+    pub fn get_min_bet_amount(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MinBetAmount)
+            .expect("MIN_BET_AMOUNT_NOT_FOUND")
+    }
+
+    pub fn get_treasury_fee(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::TreasuryFee)
+            .expect("TREASURY_FEE_NOT_FOUND")
+    }
+
+    pub fn get_treasury_amount(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::TreasuryAmount)
+            .expect("TREASURY_AMOUNT_NOT_FOUND")
+    }
+
+    pub fn get_round(e: &Env, series_id: u32, epoch: u128) -> Round {
+        e.storage()
+            .instance()
+            .get(&DataKey::Rounds(series_id, epoch))
+            .expect("ROUND_NOT_FOUND")
+    }
+
+    pub fn get_bet_info(e: &Env, series_id: u32, epoch: u128, user: Address) -> BetInfo {
+        let bet_info_key = DataKey::BetInfos(series_id, epoch, user);
+        e.storage()
+            .instance()
+            .get(&bet_info_key)
+            .expect("BET_INFO_NOT_FOUND")
+    }
+
+    pub fn get_user_rounds(e: &Env, series_id: u32, user: Address) -> Vec<u128> {
+        e.storage()
+            .instance()
+            .get(&DataKey::UserRounds(series_id, user))
+            .unwrap_or(Vec::new(&e))
+    }
+
+    pub fn get_oracle_address(e: &Env) -> Address {
+        e.storage()
+            .instance()
+            .get(&DataKey::OracleAddress)
+            .expect("ORACLE_ADDRESS_NOT_FOUND")
+    }
+
+    pub fn get_interval_seconds(e: &Env, series_id: u32) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::IntervalSeconds(series_id))
+            .expect("INTERVAL_SECONDS_NOT_FOUND")
+    }
+
+    pub fn get_buffer_seconds(e: &Env, series_id: u32) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::BufferSeconds(series_id))
+            .expect("BUFFER_SECONDS_NOT_FOUND")
+    }
+
+    /// Readonly function to get the tokens accepted besides the betting token
+    pub fn get_allowed_tokens(e: &Env) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&DataKey::AllowedTokens)
+            .expect("ALLOWED_TOKENS_NOT_FOUND")
+    }
+
+    /// Readonly function to get the minimum bet amount of an accepted token
+    /// # Parameters
+    /// - `token`: The address of the token
+    pub fn get_token_min_bet_amount(e: &Env, token: Address) -> i128 {
+        if token == Self::get_token_address(e) {
+            return Self::get_min_bet_amount(e);
+        }
+
+        e.storage()
+            .instance()
+            .get(&DataKey::TokenMinBetAmount(token))
+            .expect("TOKEN_NOT_ALLOWED")
+    }
+
+    /// Readonly function to get the value of a token in the betting token
+    /// # Parameters
+    /// - `token`: The address of the token
+    /// # Returns
+    /// - `i128`: The rate of the token (scaled by 10_000_000)
+    pub fn get_token_rate(e: &Env, token: Address) -> i128 {
+        if token == Self::get_token_address(e) {
+            return TOKEN_RATE_SCALE;
+        }
+
+        e.storage()
+            .instance()
+            .get(&DataKey::TokenRates(token))
+            .expect("TOKEN_RATE_NOT_FOUND")
+    }
+
+    /// Readonly function to get the treasury amount collected in a token
+    /// # Parameters
+    /// - `token`: The address of the token
+    pub fn get_token_treasury_amount(e: &Env, token: Address) -> i128 {
+        if token == Self::get_token_address(e) {
+            return Self::get_treasury_amount(e);
+        }
+
+        e.storage()
+            .instance()
+            .get(&DataKey::TokenTreasuryAmount(token))
+            .unwrap_or(0)
+    }
+
+    /// Readonly function to get the internal balance of a user
+    /// # Parameters
+    /// - `user`: The address of the user
+    /// - `token`: The address of the token
+    pub fn get_balance(e: &Env, user: Address, token: Address) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::Balances(user, token))
+            .unwrap_or(0)
+    }
+
+    /// Readonly function to check if a user can claim winnings for a round
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// - `user`: The address of the user
+    pub fn claimable(e: &Env, series_id: u32, epoch: u128, user: Address) -> bool {
+        let bet_info: Option<BetInfo> = e
+            .storage()
+            .instance()
+            .get(&DataKey::BetInfos(series_id, epoch, user));
+
+        match bet_info {
+            Some(bet_info) => {
+                !bet_info.claimed
+                    && Self::is_claimable(
+                        e,
+                        series_id,
+                        &Self::get_round(e, series_id, epoch),
+                        &bet_info.position,
+                    )
+            }
+            None => false,
+        }
+    }
+
+    /// Readonly function to check if a user can get a refund for a round
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// - `user`: The address of the user
+    pub fn refundable(e: &Env, series_id: u32, epoch: u128, user: Address) -> bool {
+        let bet_info: Option<BetInfo> = e
+            .storage()
+            .instance()
+            .get(&DataKey::BetInfos(series_id, epoch, user));
+
+        match bet_info {
+            Some(bet_info) => {
+                !bet_info.claimed
+                    && Self::is_refundable(e, series_id, &Self::get_round(e, series_id, epoch))
+            }
+            None => false,
+        }
+    }
+
+    pub fn get_referrer(e: &Env, user: Address) -> Option<Address> {
+        e.storage().instance().get(&DataKey::Referrers(user))
+    }
+
+    pub fn get_referral_fee_share(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::ReferralFeeShare)
+            .expect("REFERRAL_FEE_SHARE_NOT_FOUND")
+    }
+
+    /// Readonly function to get the payers allowed to gift bets to a beneficiary
+    /// # Parameters
+    /// - `beneficiary`: The address receiving the gifted bets
+    /// # Returns
+    /// - `Vec<Address>`: The allowed payers, empty when any payer is accepted
+    pub fn get_gift_payers(e: &Env, beneficiary: Address) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&DataKey::GiftPayers(beneficiary))
+            .unwrap_or(Vec::new(e))
+    }
+
+    /// Readonly function to get the referral stats of a referrer
+    /// Earned and claimable amounts are tracked per token
+    /// # Parameters
+    /// - `referrer`: The address of the referrer
+    pub fn get_referral_stats(e: &Env, referrer: Address) -> ReferralStats {
+        e.storage()
+            .instance()
+            .get(&DataKey::ReferralStats(referrer))
+            .unwrap_or(ReferralStats {
+                referred_count: 0,
+                total_earned: Map::new(e),
+                claimable: Map::new(e),
+            })
+    }
+
+    pub fn get_season_count(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SeasonCount)
+            .expect("SEASON_COUNT_NOT_FOUND")
+    }
+
+    pub fn get_season(e: &Env, season_id: u32) -> Season {
+        e.storage()
+            .instance()
+            .get(&DataKey::Seasons(season_id))
+            .expect("SEASON_NOT_FOUND")
+    }
+
+    /// Readonly function to get the season an epoch of a series counts toward
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// # Returns
+    /// - `u32`: The id of the season, or 0 when the epoch is outside every season
+    pub fn get_season_id(e: &Env, series_id: u32, epoch: u128) -> u32 {
+        // Seasons of a series never overlap, so only its latest season starting at or before the epoch can match
+        let mut season_id = Self::get_season_count(e);
+
+        while season_id > 0 {
+            let season = Self::get_season(e, season_id);
+
+            if season.series_id == series_id && season.start_epoch <= epoch {
+                return if epoch <= season.end_epoch {
+                    season_id
+                } else {
+                    0
+                };
+            }
+
+            season_id -= 1;
+        }
+
+        0
+    }
+
+    /// Readonly function to get the lifetime stats of a user
+    /// Wins, losses and net profit only include rounds settled into the stats, see `sync_user_stats`
+    /// # Parameters
+    /// - `user`: The address of the user
+    pub fn get_user_stats(e: &Env, user: Address) -> UserStats {
+        e.storage()
+            .instance()
+            .get(&DataKey::UserStats(user))
+            .unwrap_or(UserStats {
+                total_wagered: 0,
+                total_claimed: 0,
+                rounds_played: 0,
+                rounds_won: 0,
+                current_win_streak: 0,
+                best_win_streak: 0,
+                net_profit: 0,
+            })
+    }
+
+    /// Readonly function to get the stats of a user within a season
+    /// Win streaks are only tracked in lifetime stats
+    /// # Parameters
+    /// - `season_id`: The id of the season
+    /// - `user`: The address of the user
+    pub fn get_season_user_stats(e: &Env, season_id: u32, user: Address) -> UserStats {
+        e.storage()
+            .instance()
+            .get(&DataKey::SeasonUserStats(season_id, user))
+            .unwrap_or(UserStats {
+                total_wagered: 0,
+                total_claimed: 0,
+                rounds_played: 0,
+                rounds_won: 0,
+                current_win_streak: 0,
+                best_win_streak: 0,
+                net_profit: 0,
+            })
+    }
+
+    /// Readonly function to get the top users of a season, best first
+    /// The leaderboard keeps the top 10 entries and is only updated when a user's own score changes,
+    /// so a user pushed out of it does not come back when the users above lose ground, only on the
+    /// next settled round of its own. Off-chain rankings should read `get_season_user_stats`
+    /// # Parameters
+    /// - `season_id`: The id of the season
+    pub fn get_leaderboard(e: &Env, season_id: u32) -> Vec<LeaderboardEntry> {
+        e.storage()
+            .instance()
+            .get(&DataKey::Leaderboard(season_id))
+            .unwrap_or(Vec::new(e))
+    }
+
+    pub fn get_jackpot_amount(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::JackpotAmount)
+            .expect("JACKPOT_AMOUNT_NOT_FOUND")
+    }
+
+    pub fn get_jackpot_fee_share(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::JackpotFeeShare)
+            .expect("JACKPOT_FEE_SHARE_NOT_FOUND")
+    }
+
+    pub fn get_jackpot_interval(e: &Env) -> u128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::JackpotInterval)
+            .expect("JACKPOT_INTERVAL_NOT_FOUND")
+    }
+
+    /// Readonly function to get the stake of every bettor in a jackpot period
+    /// Only stakes of settled rounds enter the draw of their period
+    /// # Parameters
+    /// - `period`: The jackpot period, `(epoch - 1) / interval`
+    pub fn get_jackpot_entries(e: &Env, period: u128) -> Map<Address, i128> {
+        e.storage()
+            .instance()
+            .get(&DataKey::JackpotEntries(period))
+            .unwrap_or(Map::new(e))
+    }
+
+    /// Readonly function to get the stake of every bettor in a round of the default series
+    /// that is not settled yet
+    /// # Parameters
+    /// - `epoch`: The epoch of the round
+    pub fn get_jackpot_round_entries(e: &Env, epoch: u128) -> Map<Address, i128> {
+        e.storage()
+            .instance()
+            .get(&DataKey::JackpotRoundEntries(epoch))
+            .unwrap_or(Map::new(e))
+    }
+
+    pub fn get_self_exclusion(e: &Env, user: Address) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SelfExclusions(user))
+            .unwrap_or(0)
+    }
+
+    pub fn get_user_limit(e: &Env, user: Address) -> Option<UserLimit> {
+        e.storage().instance().get(&DataKey::UserLimits(user))
+    }
+
+    /// Readonly function to get the daily wager limit of a user currently in force
+    /// # Parameters
+    /// - `user`: The address of the user
+    /// # Returns
+    /// - `i128`: The maximum amount wagered per day, 0 for no limit
+    pub fn get_user_wager_limit(e: &Env, user: Address) -> i128 {
+        match Self::get_user_limit(e, user) {
+            Some(limit) => match limit.pending_max_wager_per_day {
+                Some(pending_limit) if e.ledger().timestamp() >= limit.pending_timestamp => {
+                    pending_limit
+                }
+                _ => limit.max_wager_per_day,
+            },
+            None => 0,
+        }
+    }
+
+    /// Readonly function to get the amount a user wagered today, valued in the betting token
+    /// # Parameters
+    /// - `user`: The address of the user
+    pub fn get_user_daily_wager(e: &Env, user: Address) -> i128 {
+        let day = e.ledger().timestamp() / SECONDS_PER_DAY;
+
+        // Only the wager of the last day the user bet on is kept
+        let daily_wager: Option<(u64, i128)> =
+            e.storage().instance().get(&DataKey::UserDailyWagers(user));
+
+        match daily_wager {
+            Some((wager_day, amount)) if wager_day == day => amount,
+            _ => 0,
+        }
+    }
+
+    pub fn get_fee_curve(e: &Env) -> Option<FeeCurve> {
+        e.storage().instance().get(&DataKey::FeeCurve)
+    }
+
+    pub fn get_max_bet_amount(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MaxBetAmount)
+            .expect("MAX_BET_AMOUNT_NOT_FOUND")
+    }
+
+    pub fn get_max_round_amount(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MaxRoundAmount)
+            .expect("MAX_ROUND_AMOUNT_NOT_FOUND")
+    }
+
+    pub fn get_user_cap(e: &Env) -> UserCap {
+        e.storage()
+            .instance()
+            .get(&DataKey::UserCap)
+            .expect("USER_CAP_NOT_FOUND")
+    }
+
+    /// Readonly function to get the amount a user bet over the rolling cap window, valued in the betting token
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The last epoch of the window
+    /// - `user`: The address of the user
+    pub fn get_user_rolling_amount(e: &Env, series_id: u32, epoch: u128, user: Address) -> i128 {
+        let rounds = Self::get_user_cap(e).rounds as u128;
+
+        let mut rolling_amount = 0;
+
+        for window_epoch in epoch.saturating_sub(rounds) + 1..=epoch {
+            let bet_info: Option<BetInfo> = e.storage().instance().get(&DataKey::BetInfos(
+                series_id,
+                window_epoch,
+                user.clone(),
+            ));
+
+            if let Some(bet_info) = bet_info {
+                rolling_amount += Self::get_token_value(e, &bet_info.token, bet_info.amount);
+            }
+        }
+
+        rolling_amount
+    }
+
+    pub fn get_house_odds(e: &Env, series_id: u32) -> HouseOdds {
+        e.storage()
+            .instance()
+            .get(&DataKey::HouseOdds(series_id))
+            .unwrap_or(HouseOdds {
+                bull_odds: 0,
+                bear_odds: 0,
+            })
+    }
+
+    pub fn get_house_max_side_exposure(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::HouseMaxSideExposure)
+            .expect("HOUSE_MAX_SIDE_EXPOSURE_NOT_FOUND")
+    }
+
+    pub fn get_house_max_round_exposure(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::HouseMaxRoundExposure)
+            .expect("HOUSE_MAX_ROUND_EXPOSURE_NOT_FOUND")
+    }
+
+    /// Readonly function to get the vault assets reserved for the worst case of open fixed-odds rounds
+    pub fn get_house_exposure(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::HouseExposure)
+            .expect("HOUSE_EXPOSURE_NOT_FOUND")
+    }
+
+    pub fn get_fixed_odds_bet(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: Address,
+    ) -> Option<FixedOddsBet> {
+        e.storage()
+            .instance()
+            .get(&DataKey::FixedOddsBets(series_id, epoch, user))
+    }
+
+    pub fn get_standing_order(e: &Env, user: Address) -> Option<StandingOrder> {
+        e.storage().instance().get(&DataKey::StandingOrders(user))
+    }
+
+    pub fn get_standing_order_users(e: &Env, series_id: u32) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&DataKey::StandingOrderUsers(series_id))
+            .unwrap_or(Vec::new(e))
+    }
+
+    pub fn get_standing_order_min_escrow(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::StandingOrderMinEscrow)
+            .expect("STANDING_ORDER_MIN_ESCROW_NOT_FOUND")
+    }
+
+    pub fn get_p2p_bet_count(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::P2PBetCount)
+            .expect("P2P_BET_COUNT_NOT_FOUND")
+    }
+
+    pub fn get_p2p_bet(e: &Env, bet_id: u32) -> P2PBet {
+        e.storage()
+            .instance()
+            .get(&DataKey::P2PBets(bet_id))
+            .expect("P2P_BET_NOT_FOUND")
+    }
+
+    pub fn get_operator(e: &Env) -> Address {
+        e.storage()
+            .instance()
+            .get(&DataKey::Operator)
+            .expect("OPERATOR_NOT_FOUND")
+    }
+
+    pub fn get_access_mode(e: &Env) -> AccessMode {
+        e.storage()
+            .instance()
+            .get(&DataKey::AccessMode)
+            .expect("ACCESS_MODE_NOT_FOUND")
+    }
+
+    pub fn get_compliance_manager(e: &Env) -> Address {
+        e.storage()
+            .instance()
+            .get(&DataKey::ComplianceManager)
+            .expect("COMPLIANCE_MANAGER_NOT_FOUND")
+    }
+
+    pub fn is_blocked(e: &Env, user: Address) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::Blocklist(user))
+            .unwrap_or(false)
+    }
+
+    pub fn is_allowed(e: &Env, user: Address) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::Allowlist(user))
+            .unwrap_or(false)
+    }
+
+    /// Readonly function to check if an address can bet, take flash loans and deposit
+    /// # Parameters
+    /// - `user`: The address to check
+    pub fn has_access(e: &Env, user: Address) -> bool {
+        match Self::get_access_mode(e) {
+            AccessMode::Open => true,
+            AccessMode::Blocklist => !Self::is_blocked(e, user),
+            AccessMode::Allowlist => {
+                Self::is_allowed(e, user.clone()) && !Self::is_blocked(e, user)
+            }
+        }
+    }
+
+    pub fn get_signing_key(e: &Env, user: Address) -> Option<BytesN<32>> {
+        e.storage().instance().get(&DataKey::SigningKeys(user))
+    }
+
+    /// Readonly function to get the nonce expected in the next intent of a user
+    /// # Parameters
+    /// - `user`: The address of the user
+    pub fn get_intent_nonce(e: &Env, user: Address) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::IntentNonces(user))
+            .unwrap_or(0)
+    }
+
+    /// Readonly function to get the bytes a user signs for a bet intent
+    /// The payload binds the intent to this contract to prevent cross-contract replay
+    /// # Parameters
+    /// - `intent`: The bet intent to sign
+    pub fn get_bet_intent_payload(e: &Env, intent: BetIntent) -> Bytes {
+        (e.current_contract_address(), intent).to_xdr(e)
+    }
+
+    /// Readonly function to get the pool of a token in a round
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// - `token`: The address of the token
+    pub fn get_round_token_pool(e: &Env, series_id: u32, epoch: u128, token: Address) -> TokenPool {
+        let round = Self::get_round(e, series_id, epoch);
+
+        Self::get_pool(e, &round, &token)
+    }
+
+    pub fn get_flash_treasury_amount(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::FlashTreasuryAmount)
+            .expect("FLASH_TREASURY_AMOUNT_NOT_FOUND")
+    }
+
+    pub fn get_vault_total_assets(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::VaultTotalAssets)
+            .expect("VAULT_TOTAL_ASSETS_NOT_FOUND")
+    }
+
+    pub fn get_vault_total_shares(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::VaultTotalShares)
+            .expect("VAULT_TOTAL_SHARES_NOT_FOUND")
+    }
+
+    pub fn get_vault_position(e: &Env, lp: Address) -> VaultPosition {
+        e.storage()
+            .instance()
+            .get(&DataKey::VaultPositions(lp))
+            .unwrap_or(VaultPosition {
+                shares: 0,
+                last_deposit_timestamp: 0,
+                last_withdraw_timestamp: 0,
+            })
+    }
+
+    pub fn get_vault_cooldown_seconds(e: &Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::VaultCooldownSeconds)
+            .expect("VAULT_COOLDOWN_SECONDS_NOT_FOUND")
+    }
+
+    pub fn get_vault_treasury_fee_share(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::VaultTreasuryFeeShare)
+            .expect("VAULT_TREASURY_FEE_SHARE_NOT_FOUND")
+    }
+
+    /// Readonly function to check if a round is bettable
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to check
+    /// # Returns
+    /// - `bool`: True if the round is bettable, false otherwise
+    pub fn is_bettable(e: &Env, series_id: u32, epoch: u128) -> bool {
+        let round: Round = e
+            .storage()
+            .instance()
+            .get(&DataKey::Rounds(series_id, epoch))
+            .expect("ROUND_NOT_FOUND");
+
+        let current_timestamp: u64 = e.ledger().timestamp();
+
+        round.start_timestamp != 0
+            && round.lock_timestamp != 0
+            && current_timestamp > round.start_timestamp
+            && current_timestamp < round.lock_timestamp
+    }
+
+    //////////////////////////////// INTERNALS ////////////////////////////////
+
+    /// Internal function to set up the epoch counter, genesis flags and timing of a new series
+    /// # Parameters
+    /// - `interval_seconds`: Duration of each round of the series in seconds
+    /// - `buffer_seconds`: Buffer to execute the rounds of the series in seconds
+    /// # Returns
+    /// - `u32`: The id of the new series
+    /// # Events
+    /// - `SERIES_CREATED`: Emitted when a series is created
+    fn init_series(e: &Env, interval_seconds: u64, buffer_seconds: u64) -> u32 {
+        let series_id = Self::get_series_count(e);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::IntervalSeconds(series_id), &interval_seconds);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::BufferSeconds(series_id), &buffer_seconds);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::CurrentEpoch(series_id), &0u128);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::IsGenesisStarted(series_id), &false);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::IsGenesisLocked(series_id), &false);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::SeriesCount, &(series_id + 1));
+
+        // Emit an Event for Series Created
+        emit_series_created_event(e, series_id, interval_seconds, buffer_seconds);
+
+        series_id
+    }
+
+    /// Internal function to start a new round
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to be started
+    fn start_round(e: &Env, series_id: u32, epoch: u128) {
+        let start_timestamp = e.ledger().timestamp();
+
+        // Get Interval Seconds
+        let interval_seconds: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::IntervalSeconds(series_id))
+            .expect("INTERVAL_SECONDS_NOT_FOUND");
+
+        // lock_timestamp = start_timestamp + interval_seconds
+        let lock_timestamp = start_timestamp + interval_seconds;
+
+        // close_timestamp = start_timestamp + 2 * interval_seconds
+        let close_timestamp = lock_timestamp + interval_seconds;
+
+        // Bucket series have one more bucket than boundaries
+        let boundaries = Self::get_series_buckets(e, series_id);
+
+        let bucket_count = if boundaries.is_empty() {
+            0
+        } else {
+            boundaries.len() + 1
+        };
+
+        // Create New Round
+        let round = Round {
+            epoch,
+            start_timestamp,
+            lock_timestamp,
+            close_timestamp,
+            lock_price: 0,
+            close_price: 0,
+            total_amount: 0,
+            bull_amount: 0,
+            bear_amount: 0,
+            reward_base_cal_amount: 0,
+            reward_amount: 0,
+            token_pools: Map::new(e),
+            bucket_amounts: Self::get_empty_bucket_amounts(e, bucket_count),
+            winning_bucket: None,
+            rival_lock_price: 0,
+            rival_close_price: 0,
+            move_threshold: Self::get_move_threshold(e, series_id),
+            event_outcome: None,
+            house_amount: 0,
+            house_bull_liability: 0,
+            house_bear_liability: 0,
+            treasury_fee: 0,
+        };
+
+        // Store Round in Storage
+        e.storage()
+            .instance()
+            .set(&DataKey::Rounds(series_id, epoch), &round);
+
+        // Emit an Event for Round Started
+        emit_round_started_event(
+            e,
+            series_id,
+            epoch,
+            start_timestamp,
+            lock_timestamp,
+            close_timestamp,
+        );
+    }
+
+    /// Internal function to safely lock a round
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to be locked
+    /// - `current_price`: The current price fetched from the oracle
+    fn safe_lock_round(e: &Env, series_id: u32, epoch: u128, current_price: i128) {
+        let mut round: Round = e
+            .storage()
+            .instance()
+            .get(&DataKey::Rounds(series_id, epoch))
+            .expect("ROUND_NOT_FOUND");
+
+        // CHECK: Round should have started
+        assert!(round.start_timestamp != 0, "CANNOT_LOCK_NON_STARTED_ROUND");
+
+        let current_timestamp: u64 = e.ledger().timestamp();
+
+        // CHECK: Current time should be after or equal to lock timestamp
+        assert!(
+            current_timestamp >= round.lock_timestamp,
+            "CANNOT_LOCK_BEFORE_LOCK_TIMESTAMP"
+        );
+
+        let buffer_seconds: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::BufferSeconds(series_id))
+            .expect("BUFFER_SECONDS_NOT_FOUND");
+
+        // CHECK: Current time should be within buffer seconds of lock timestamp
+        assert!(
+            current_timestamp <= round.lock_timestamp + buffer_seconds,
+            "CANNOT_LOCK_OUTSIDE_BUFFER"
+        );
+
+        let interval_seconds: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::IntervalSeconds(series_id))
+            .expect("INTERVAL_SECONDS_NOT_FOUND");
+
+        // Update Round Details on Lock
+        round.lock_price = current_price;
+        round.rival_lock_price = Self::get_rival_price(e, series_id);
+        round.close_timestamp = current_timestamp + interval_seconds;
+
+        // Store Updated Round in Storage
+        e.storage()
+            .instance()
+            .set(&DataKey::Rounds(series_id, epoch), &round);
+
+        // Emit an Event for Round Locked
+        emit_round_locked_event(e, series_id, epoch, current_timestamp, current_price);
+    }
+
+    /// Internal function to safely end a round
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to be ended
+    /// - `current_price`: The current price fetched from the oracle
+    fn safe_end_round(e: &Env, series_id: u32, epoch: u128, current_price: i128) {
+        let mut round: Round = e
+            .storage()
+            .instance()
+            .get(&DataKey::Rounds(series_id, epoch))
+            .expect("ROUND_NOT_FOUND");
+
+        // CHECK: Round should be locked
+        assert!(round.lock_timestamp != 0, "CANNOT_END_NON_LOCKED_ROUND");
+
+        // CHECK: Round should not have ended yet
+        assert!(round.close_price == 0, "ROUND_ALREADY_ENDED");
+
+        let current_timestamp: u64 = e.ledger().timestamp();
+
+        // CHECK: Current time should be after or equal to close timestamp
+        assert!(
+            current_timestamp >= round.close_timestamp,
+            "CANNOT_END_BEFORE_CLOSE_TIMESTAMP"
+        );
+
+        let buffer_seconds: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::BufferSeconds(series_id))
+            .expect("BUFFER_SECONDS_NOT_FOUND");
+
+        // CHECK: Current time should be within buffer seconds of close timestamp
+        assert!(
+            current_timestamp <= round.close_timestamp + buffer_seconds,
+            "CANNOT_END_OUTSIDE_BUFFER"
+        );
+
+        round.close_price = current_price;
+        round.rival_close_price = Self::get_rival_price(e, series_id);
+
+        // Store Updated Round in Storage
+        e.storage()
+            .instance()
+            .set(&DataKey::Rounds(series_id, epoch), &round);
+
+        // Emit an Event for Round Ended
+        emit_round_ended_event(e, series_id, epoch, current_timestamp, current_price);
+    }
+
+    /// Internal function to safely start a new round
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to be started
+    /// # Events
+    /// - `ROUND_STARTED`: Emitted when a new round is started
+    fn safe_start_round(e: &Env, series_id: u32, epoch: u128) {
+        let is_genesis_started: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::IsGenesisStarted(series_id))
+            .expect("IS_GENESIS_STARTED_NOT_FOUND");
+
+        assert!(is_genesis_started, "GENESIS_NOT_STARTED");
+
+        // Get n -2 round
+        let prev_prev_epoch = epoch - 2;
+
+        let prev_prev_round: Round = e
+            .storage()
+            .instance()
+            .get(&DataKey::Rounds(series_id, prev_prev_epoch))
+            .expect("ROUND_NOT_FOUND");
+
+        // CHECK: n - 2 round should be closed
+        assert!(
+            prev_prev_round.close_timestamp != 0,
+            "CANNOT_START_BEFORE_PREV_PREV_ROUND_CLOSED"
+        );
+
+        Self::start_round(e, series_id, epoch);
+    }
+
+    /// Internal function to calculate rewards for a round
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to calculate rewards for
+    /// - # Events
+    /// - `REWARDS_CALCULATED`: Emitted when rewards are calculated
+    fn calculate_rewards(e: &Env, series_id: u32, epoch: u128) {
+        let mut round: Round = e
+            .storage()
+            .instance()
+            .get(&DataKey::Rounds(series_id, epoch))
+            .expect("ROUND_NOT_FOUND");
+
+        // CHECK: Rewards should not have been calculated yet
+        assert!(
+            round.reward_amount == 0 && round.reward_base_cal_amount == 0,
+            "REWARDS_ALREADY_CALCULATED"
+        );
+
+        // Record the fee of the round for its claims
+        round.treasury_fee = Self::get_round_treasury_fee(e, &round);
+
+        let treasury_fee = round.treasury_fee;
+
+        // Find the winning bucket of a bucket series
+        if !round.bucket_amounts.is_empty() {
+            round.winning_bucket = Some(Self::get_winning_bucket(e, series_id, &round));
+        }
+
+        // Calculate Rewards for the betting token pool
+        let token_address = Self::get_token_address(e);
+
+        let mut pool = Self::get_pool(e, &round, &token_address);
+        let treasury_amt = Self::calculate_pool_rewards(&round, &mut pool, treasury_fee);
+
+        Self::set_pool(e, &mut round, &token_address, pool);
+
+        // Calculate Rewards for every other token pool
+        for (token, mut pool) in round.token_pools.clone().iter() {
+            let token_treasury_amt = Self::calculate_pool_rewards(&round, &mut pool, treasury_fee)
+                - Self::distribute_referral_fees(e, series_id, epoch, &token, treasury_fee);
+
+            let token_treasury_amount = Self::get_token_treasury_amount(e, token.clone());
+
+            e.storage().instance().set(
+                &DataKey::TokenTreasuryAmount(token.clone()),
+                &(token_treasury_amount + token_treasury_amt),
+            );
+
+            emit_token_rewards_calculated_event(
+                e,
+                series_id,
+                epoch,
+                &token,
+                pool.reward_amount,
+                token_treasury_amt,
+            );
+
+            round.token_pools.set(token, pool);
+        }
+
+        // Store Updated Round in Storage
+        e.storage()
+            .instance()
+            .set(&DataKey::Rounds(series_id, epoch), &round);
+
+        // Pay the referrers of the round out of the treasury fee
+        let treasury_amt = treasury_amt
+            - Self::distribute_referral_fees(e, series_id, epoch, &token_address, treasury_fee);
+
+        // Fund the jackpot with its share of the treasury fee of the default series
+        let jackpot_amt = if series_id == DEFAULT_SERIES_ID {
+            (treasury_amt * Self::get_jackpot_fee_share(e) as i128) / 10_000
+        } else {
+            0
+        };
+
+        let jackpot_amount = Self::get_jackpot_amount(e);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::JackpotAmount, &(jackpot_amount + jackpot_amt));
+
+        let treasury_amt = treasury_amt - jackpot_amt;
+
+        Self::add_treasury_fees(e, treasury_amt);
+
+        // Settle the fixed-odds bets of the round against the vault
+        Self::settle_house_book(e, series_id, &round);
+
+        // Emit an Event for Rewards Calculated
+        emit_rewards_calculated_event(e, series_id, epoch, round.reward_amount, treasury_amt);
+
+        // Enter the stakes of the round into the jackpot and draw every ended period
+        if series_id == DEFAULT_SERIES_ID {
+            Self::settle_jackpot_entries(e, epoch);
+        }
+    }
+
+    /// Internal function to add treasury fees of the betting token to the treasury
+    /// The vault share of the fees is routed to the LPs
+    /// # Parameters
+    /// - `treasury_amt`: The treasury fees collected
+    fn add_treasury_fees(e: &Env, treasury_amt: i128) {
+        // Route the vault share of the treasury fee to the LPs
+        let vault_amt = Self::get_vault_fee_cut(e, treasury_amt);
+
+        Self::accrue_vault_fees(e, Symbol::new(e, "TREASURY_FEE"), vault_amt);
+
+        // Update Treasury Amount in Storage
+        let mut treasury_amount: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TreasuryAmount)
+            .expect("TREASURY_AMOUNT_NOT_FOUND");
+
+        treasury_amount += treasury_amt - vault_amt;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::TreasuryAmount, &treasury_amount);
+    }
+
+    /// Internal function to settle the fixed-odds bets of an ended round against the vault
+    /// The vault keeps the stakes and pays the winning side, and its reserved exposure is released
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `round`: The ended round
+    /// # Events
+    /// - `HOUSE_BOOK_SETTLED`: Emitted with the profit or loss of the house
+    fn settle_house_book(e: &Env, series_id: u32, round: &Round) {
+        if round.house_amount == 0 {
+            return;
+        }
+
+        let winning_liability = match Self::get_winning_side(round) {
+            Some(Position::Bull) => round.house_bull_liability,
+            Some(_) => round.house_bear_liability,
+            None => 0,
+        };
+
+        let house_pnl = round.house_amount - winning_liability;
+
+        // Release the exposure reserved for the round
+        let house_exposure = Self::get_house_exposure(e) - Self::get_house_round_exposure(round);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::HouseExposure, &house_exposure);
+
+        if house_pnl > 0 {
+            Self::accrue_vault_fees(e, Symbol::new(e, "HOUSE_PNL"), house_pnl);
+        } else {
+            let total_assets = Self::get_vault_total_assets(e);
+
+            e.storage()
+                .instance()
+                .set(&DataKey::VaultTotalAssets, &(total_assets + house_pnl));
+        }
+
+        emit_house_book_settled_event(e, series_id, round.epoch, house_pnl);
+    }
+
+    /// Internal function to release the exposure reserved for the fixed-odds bets of a refunded round
+    /// The book of the round is cleared, so the exposure is only released once
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `round`: The refunded round
+    /// # Events
+    /// - `HOUSE_BOOK_RELEASED`: Emitted with the exposure released
+    fn release_house_book(e: &Env, series_id: u32, round: &mut Round) {
+        if round.house_amount == 0 {
+            return;
+        }
+
+        let round_exposure = Self::get_house_round_exposure(round);
+
+        let house_exposure = Self::get_house_exposure(e) - round_exposure;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::HouseExposure, &house_exposure);
+
+        round.house_amount = 0;
+        round.house_bull_liability = 0;
+        round.house_bear_liability = 0;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Rounds(series_id, round.epoch), round);
+
+        emit_house_book_released_event(e, series_id, round.epoch, round_exposure);
+    }
+
+    /// Internal function to compute the worst case loss of the house on a round
+    /// # Parameters
+    /// - `round`: The round to check
+    fn get_house_round_exposure(round: &Round) -> i128 {
+        let max_liability = round.house_bull_liability.max(round.house_bear_liability);
+
+        (max_liability - round.house_amount).max(0)
+    }
+
+    /// Internal function to delete the standing order of a user
+    /// # Parameters
+    /// - `user`: The address of the user
+    /// - `series_id`: The id of the series of the order
+    fn remove_standing_order(e: &Env, user: &Address, series_id: u32) {
+        e.storage()
+            .instance()
+            .remove(&DataKey::StandingOrders(user.clone()));
+
+        let mut users = Self::get_standing_order_users(e, series_id);
+
+        if let Some(index) = users.first_index_of(user.clone()) {
+            users.remove(index);
+        }
+
+        e.storage()
+            .instance()
+            .set(&DataKey::StandingOrderUsers(series_id), &users);
+    }
+
+    /// Internal function to add the stake of a bet to the jackpot entries of its round
+    /// Only bets of the default series in the betting token enter the draw, and only while the jackpot is funded
+    /// Stakes enter the draw of their period when the round is settled, so refunded bets never do
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// - `user`: The address of the user
+    /// - `token`: The address of the token used for the bet
+    /// - `amount`: The amount of the bet
+    fn record_jackpot_entry(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: &Address,
+        token: &Address,
+        amount: i128,
+    ) {
+        if series_id != DEFAULT_SERIES_ID
+            || Self::get_jackpot_fee_share(e) == 0
+            || *token != Self::get_token_address(e)
+        {
+            return;
+        }
+
+        let mut entries = Self::get_jackpot_round_entries(e, epoch);
+
+        let stake = entries.get(user.clone()).unwrap_or(0);
+
+        entries.set(user.clone(), stake + amount);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::JackpotRoundEntries(epoch), &entries);
+    }
+
+    /// Internal function to move the stakes of a settled round into the draw of its period
+    /// A period is drawn by the settlement of its last round or, if that round is refunded,
+    /// by the first settlement of a later period
+    /// # Parameters
+    /// - `epoch`: The epoch of the settled round of the default series
+    fn settle_jackpot_entries(e: &Env, epoch: u128) {
+        let jackpot_interval = Self::get_jackpot_interval(e);
+        let period = (epoch - 1) / jackpot_interval;
+
+        let round_entries = Self::get_jackpot_round_entries(e, epoch);
+
+        e.storage()
+            .instance()
+            .remove(&DataKey::JackpotRoundEntries(epoch));
+
+        let mut periods: Vec<u128> = e
+            .storage()
+            .instance()
+            .get(&DataKey::JackpotPeriods)
+            .unwrap_or(Vec::new(e));
+
+        if !round_entries.is_empty() {
+            let mut entries = Self::get_jackpot_entries(e, period);
+
+            for (user, amount) in round_entries.iter() {
+                let stake = entries.get(user.clone()).unwrap_or(0);
+
+                entries.set(user, stake + amount);
+            }
+
+            e.storage()
+                .instance()
+                .set(&DataKey::JackpotEntries(period), &entries);
+
+            if !periods.contains(period) {
+                periods.push_back(period);
+            }
+        }
+
+        let mut open_periods: Vec<u128> = Vec::new(e);
+
+        for open_period in periods.iter() {
+            if open_period < period
+                || (open_period == period && epoch.is_multiple_of(jackpot_interval))
+            {
+                Self::draw_jackpot(e, open_period);
+            } else {
+                open_periods.push_back(open_period);
+            }
+        }
+
+        e.storage()
+            .instance()
+            .set(&DataKey::JackpotPeriods, &open_periods);
+    }
+
+    /// Internal function to pay the jackpot to a bettor of the period, weighted by stake
+    /// The jackpot is credited to the internal balance and rolls over if nobody entered
+    /// # Parameters
+    /// - `period`: The jackpot period to draw
+    /// # Events
+    /// - `JACKPOT_WON`: Emitted when the jackpot is won
+    fn draw_jackpot(e: &Env, period: u128) {
+        let entries = Self::get_jackpot_entries(e, period);
+
+        e.storage()
+            .instance()
+            .remove(&DataKey::JackpotEntries(period));
+
+        let jackpot_amount = Self::get_jackpot_amount(e);
+
+        if entries.is_empty() || jackpot_amount == 0 {
+            return;
+        }
+
+        let mut total_stake: i128 = 0;
+
+        for (_, stake) in entries.iter() {
+            total_stake += stake;
+        }
+
+        // Pick a stake unit uniformly, its owner wins
+        // Stakes can exceed u64, so the ticket is drawn from 128 random bits
+        let random = ((e.prng().gen::<u64>() as u128) << 64) | e.prng().gen::<u64>() as u128;
+
+        let mut ticket = (random % total_stake as u128) as i128;
+
+        for (user, stake) in entries.iter() {
+            if ticket < stake {
+                e.storage().instance().set(&DataKey::JackpotAmount, &0i128);
+
+                let token_address = Self::get_token_address(e);
+
+                Self::credit_balance(
+                    e,
+                    &user,
+                    &token_address,
+                    jackpot_amount,
+                    Symbol::new(e, "JACKPOT"),
+                );
+
+                // Emit an Event for Jackpot Won
+                emit_jackpot_won_event(e, period, &user, jackpot_amount);
+
+                return;
+            }
+
+            ticket -= stake;
+        }
+    }
+
+    /// Internal function to bind a referrer to a user and count the referral
+    /// # Parameters
+    /// - `user`: The address of the referred user
+    /// - `referrer`: The address of the referrer
+    /// # Events
+    /// - `REFERRER_REGISTERED`: Emitted when a referrer is registered
+    fn bind_referrer(e: &Env, user: &Address, referrer: &Address) {
+        assert!(user != referrer, "INVALID_ADDRESS");
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Referrers(user.clone()), referrer);
+
+        let mut stats = Self::get_referral_stats(e, referrer.clone());
+
+        stats.referred_count += 1;
+
+        e.storage()
+            .instance()
+            .set(&DataKey::ReferralStats(referrer.clone()), &stats);
+
+        // Emit an Event for Referrer Registered
+        emit_referrer_registered_event(e, user, referrer);
+    }
+
+    /// Internal function to record the stake a referrer earns fees on
+    /// Fees are only computed and credited to referrers once the round is settled
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// - `user`: The address of the user placing the bet
+    /// - `token`: The address of the token used for the bet
+    /// - `amount`: The amount of the bet
+    fn record_referral_stake(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: &Address,
+        token: &Address,
+        amount: i128,
+    ) {
+        let Some(referrer) = Self::get_referrer(e, user.clone()) else {
+            return;
+        };
+
+        let key = DataKey::RoundReferralStakes(series_id, epoch, token.clone());
+
+        let mut referral_stakes: Map<Address, i128> =
+            e.storage().instance().get(&key).unwrap_or(Map::new(e));
+
+        let stake = referral_stakes.get(referrer.clone()).unwrap_or(0);
+
+        referral_stakes.set(referrer, stake + amount);
+
+        e.storage().instance().set(&key, &referral_stakes);
+    }
+
+    /// Internal function to credit referrers with their share of the treasury fee of a round
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// - `token`: The address of the token
+    /// - `treasury_fee`: The treasury fee of the round (scaled by 100)
+    /// # Returns
+    /// - `i128`: The total amount credited to referrers
+    fn distribute_referral_fees(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        token: &Address,
+        treasury_fee: u32,
+    ) -> i128 {
+        let key = DataKey::RoundReferralStakes(series_id, epoch, token.clone());
+
+        let referral_stakes: Map<Address, i128> =
+            e.storage().instance().get(&key).unwrap_or(Map::new(e));
+
+        let fee_share = Self::get_referral_fee_share(e);
+
+        let mut total_referral_amt: i128 = 0;
+
+        for (referrer, stake) in referral_stakes.iter() {
+            let amount = (stake * treasury_fee as i128 * fee_share as i128) / 100_000_000;
+
+            if amount == 0 {
+                continue;
+            }
+
+            let mut stats = Self::get_referral_stats(e, referrer.clone());
+
+            let earned = stats.total_earned.get(token.clone()).unwrap_or(0);
+            let claimable = stats.claimable.get(token.clone()).unwrap_or(0);
+
+            stats.total_earned.set(token.clone(), earned + amount);
+            stats.claimable.set(token.clone(), claimable + amount);
+
+            e.storage()
+                .instance()
+                .set(&DataKey::ReferralStats(referrer), &stats);
+
+            total_referral_amt += amount;
+        }
+
+        e.storage().instance().remove(&key);
+
+        total_referral_amt
+    }
+
+    /// Internal function to split a token pool between winners and the treasury
+    /// # Parameters
+    /// - `round`: The round holding the lock and close prices
+    /// - `pool`: The token pool to update
+    /// - `treasury_fee`: The treasury fee (scaled by 100)
+    /// # Returns
+    /// - `i128`: The amount collected by the treasury
+    fn calculate_pool_rewards(round: &Round, pool: &mut TokenPool, treasury_fee: u32) -> i128 {
+        // Determine Winning Side
+        let winning_amount = if let Some(winning_bucket) = round.winning_bucket {
+            // Winning Bucket Wins
+            Some(pool.bucket_amounts.get(winning_bucket).unwrap_or(0))
+        } else {
+            match Self::get_winning_side(round) {
+                Some(Position::Bull) => Some(pool.bull_amount),
+                Some(_) => Some(pool.bear_amount),
+                None => None,
+            }
+        };
+
+        let treasury_amt: i128;
+
+        if let Some(winning_amount) = winning_amount.filter(|amount| *amount > 0) {
+            pool.reward_base_cal_amount = winning_amount;
+            treasury_amt = (pool.total_amount * treasury_fee as i128) / 10_000;
+            pool.reward_amount = pool.total_amount - treasury_amt;
+        } else {
+            // No one wins, or no one bet on the winning side, all bets go to treasury
+            pool.reward_base_cal_amount = 0;
+            treasury_amt = pool.total_amount;
+            pool.reward_amount = 0;
+        }
+
+        treasury_amt
+    }
+
+    /// Internal function to get the treasury fee of a round from the fee curve
+    /// Falls back to the flat treasury fee when no fee curve is set
+    /// The imbalance is measured across every side of the round, two sides or the buckets
+    /// # Parameters
+    /// - `round`: The round to price
+    /// # Returns
+    /// - `u32`: The treasury fee (scaled by 100)
+    fn get_round_treasury_fee(e: &Env, round: &Round) -> u32 {
+        let Some(fee_curve) = Self::get_fee_curve(e) else {
+            return Self::get_treasury_fee(e);
+        };
+
+        if round.total_amount == 0 {
+            return fee_curve.min_fee;
+        }
+
+        // Largest side of the betting token pool
+        let largest_amount = round
+            .bucket_amounts
+            .iter()
+            .fold(round.bull_amount.max(round.bear_amount), i128::max);
+
+        // Number of sides the stakes are split between
+        let side_count = round.bucket_amounts.len().max(2) as i128;
+
+        // Share of the largest side above an even split: 0 when balanced, 10_000 when one-sided
+        let imbalance = ((side_count * largest_amount - round.total_amount) * 10_000
+            / ((side_count - 1) * round.total_amount))
+            .max(0);
+
+        fee_curve.min_fee
+            + ((fee_curve.max_fee - fee_curve.min_fee) as i128 * imbalance / 10_000) as u32
+    }
+
+    /// Internal function to compute the part of a treasury fee owed to the vault
+    /// Nothing is routed to the vault while it has no LPs
+    /// # Parameters
+    /// - `treasury_amt`: The treasury fee collected for a round
+    fn get_vault_fee_cut(e: &Env, treasury_amt: i128) -> i128 {
+        if Self::get_vault_total_shares(e) == 0 {
+            return 0;
+        }
+
+        let fee_share = Self::get_vault_treasury_fee_share(e);
+
+        (treasury_amt * fee_share as i128) / 10_000
+    }
+
+    /// Internal function to add fees to the vault assets, raising the share price
+    /// Falls back to the flash treasury while the vault has no LPs
+    /// # Parameters
+    /// - `source`: The origin of the fees
+    /// - `amount`: The amount of fees
+    /// # Events
+    /// - `VAULT_FEES_ACCRUED`: Emitted when fees are added to the vault
+    fn accrue_vault_fees(e: &Env, source: Symbol, amount: i128) {
+        if amount == 0 {
+            return;
+        }
 
-        // Ensure that the receiver has repaid the loan plus fee
-        assert!(
-            balance_after >= balance_before + fee_amount,
-            "FLASH_LOAN_NOT_REPAID"
-        );
+        if Self::get_vault_total_shares(e) == 0 {
+            let flash_treasury_amount = Self::get_flash_treasury_amount(e);
 
-        // Update Flash Treasury Amount in Storage
-        let mut flash_treasury_amount: i128 = e
-            .storage()
-            .instance()
-            .get(&DataKey::FlashTreasuryAmount)
-            .expect("FLASH_TREASURY_AMOUNT_NOT_FOUND");
+            e.storage().instance().set(
+                &DataKey::FlashTreasuryAmount,
+                &(flash_treasury_amount + amount),
+            );
 
-        flash_treasury_amount += fee_amount;
+            return;
+        }
+
+        let total_assets = Self::get_vault_total_assets(e);
 
         e.storage()
             .instance()
-            .set(&DataKey::FlashTreasuryAmount, &flash_treasury_amount);
+            .set(&DataKey::VaultTotalAssets, &(total_assets + amount));
 
-        // Emit an Event for Flash Loan
-        emit_flash_loan_event(e, &receiver, amount, fee_amount);
+        emit_vault_fees_accrued_event(e, source, amount);
     }
 
-    //////////////////////////////// GETTERS ////////////////////////////////
+    /// Internal function to get the token price from an oracle
+    /// Head-to-head series are priced on their first asset, other series on the asset of the market
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    fn get_token_price(e: &Env, series_id: u32) -> i128 {
+        match Self::get_series_assets(e, series_id).first() {
+            Some(asset) => Self::get_asset_oracle_price(e, asset),
+            None => Self::get_asset_oracle_price(e, Self::get_market_asset(e)),
+        }
+    }
 
-    /// Internal function to get XLM price from the oracle
+    /// Internal function to get the price of the second asset of a head-to-head series
+    /// # Parameters
+    /// - `series_id`: The id of the series
     /// # Returns
-    /// - `i128`: XLM price in stroops
-    pub fn get_xlm_oracle_price(e: &Env) -> i128 {
+    /// - `i128`: The price of the second asset, 0 for a single asset series
+    fn get_rival_price(e: &Env, series_id: u32) -> i128 {
+        match Self::get_series_assets(e, series_id).get(1) {
+            Some(rival_asset) => Self::get_asset_oracle_price(e, rival_asset),
+            None => 0,
+        }
+    }
+
+    /// Internal function to get the latest price of an asset from the oracle
+    /// # Parameters
+    /// - `asset`: The symbol of the asset
+    fn get_asset_oracle_price(e: &Env, asset: Symbol) -> i128 {
         let oracle_address: Address = e
             .storage()
             .instance()
@@ -653,378 +5966,1080 @@ impl PredictionMarket {
 
         let oracle_client = reflector_oracle::Client::new(e, &oracle_address);
 
-        let xlm_asset = Asset::Other(Symbol::new(&e, "XLM"));
-
-        let recent_price = oracle_client.lastprice(&xlm_asset);
+        let recent_price = oracle_client.lastprice(&Asset::Other(asset));
 
         recent_price.expect("INVALID_ORACLE_PRICE").price
     }
 
-    pub fn get_is_genesis_started(e: &Env) -> bool {
-        e.storage()
+    /// Internal function to get the price of an asset recorded by the oracle at a given time
+    /// # Parameters
+    /// - `asset`: The symbol of the asset
+    /// - `timestamp`: The time of the price in seconds
+    fn get_asset_oracle_price_at(e: &Env, asset: Symbol, timestamp: u64) -> i128 {
+        let oracle_address: Address = e
+            .storage()
             .instance()
-            .get(&DataKey::IsGenesisStarted)
-            .expect("IS_GENESIS_STARTED_NOT_FOUND")
+            .get(&DataKey::OracleAddress)
+            .expect("ORACLE_ADDRESS_NOT_FOUND");
+
+        let oracle_client = reflector_oracle::Client::new(e, &oracle_address);
+
+        // Oracle records its prices in milliseconds
+        let historic_price = oracle_client.price(&Asset::Other(asset), &timestamp);
+
+        historic_price.expect("INVALID_ORACLE_PRICE").price
     }
 
-    pub fn get_is_genesis_locked(e: &Env) -> bool {
-        e.storage()
+    /// Internal function to record a bet and collect its stake
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round to bet on
+    /// - `user`: The address of the user placing the bet
+    /// - `token`: The address of the token used for the bet
+    /// - `amount`: The amount of tokens to bet
+    /// - `position`: The side of the bet
+    /// - `source`: Where the stake is collected from
+    /// # Events
+    /// - `BET_PLACED`: Emitted when a bet is placed
+    #[allow(clippy::too_many_arguments)]
+    fn place_bet(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: &Address,
+        token: &Address,
+        amount: i128,
+        position: Position,
+        source: StakeSource,
+    ) {
+        let current_epoch: u128 = e
+            .storage()
             .instance()
-            .get(&DataKey::IsGenesisLocked)
-            .expect("IS_GENESIS_LOCKED_NOT_FOUND")
-    }
+            .get(&DataKey::CurrentEpoch(series_id))
+            .expect("CURRENT_EPOCH_NOT_FOUND");
 
-    pub fn get_current_epoch(e: &Env) -> u128 {
-        e.storage()
+        // CHECK: Epoch should be the current epoch, strike and event rounds can be open side by side
+        assert!(
+            epoch == current_epoch
+                || Self::is_strike_series(e, series_id)
+                || Self::is_event_series(e, series_id),
+            "INVALID_ROUND"
+        );
+
+        // CHECK: Round should be bettable
+        assert!(Self::is_bettable(e, series_id, epoch), "ROUND_NOT_BETTABLE");
+
+        // CHECK: Amount should be greater than minimum bet amount of the token
+        let min_bet_amount = Self::get_token_min_bet_amount(e, token.clone());
+
+        assert!(amount >= min_bet_amount, "BET_AMOUNT_TOO_LOW");
+
+        // CHECK: User should not have already placed a bet in this round
+        assert!(
+            !Self::has_bet(e, series_id, epoch, user),
+            "ALREADY_BET_FOR_ROUND"
+        );
+
+        // CHECK: Bet should be allowed by the caps and the limits of the user
+        if let Some(error) = Self::get_bet_restriction(e, series_id, epoch, user, token, amount) {
+            panic_with_error!(e, error);
+        }
+
+        // CHECK: Payer of a sponsored bet should be granted access
+        if let StakeSource::Wallet(payer)
+        | StakeSource::Balance(payer)
+        | StakeSource::Allowance(_, payer) = &source
+        {
+            Self::require_access(e, payer);
+        }
+
+        // Collect the stake
+        match source {
+            StakeSource::Wallet(payer) => {
+                // Create Token Client
+                let token_client = token::Client::new(e, token);
+
+                // Safely transfer tokens from payer to contract
+                Self::safe_transfer_tokens(
+                    e,
+                    &token_client,
+                    &payer,
+                    &e.current_contract_address(),
+                    amount,
+                );
+            }
+            StakeSource::Balance(payer) => {
+                Self::debit_balance(e, &payer, token, amount, Symbol::new(e, "BET"));
+            }
+            StakeSource::Allowance(spender, owner) => {
+                // Create Token Client
+                let token_client = token::Client::new(e, token);
+
+                // Safely pull tokens from owner to contract under the allowance
+                Self::safe_transfer_from_tokens(
+                    e,
+                    &token_client,
+                    &spender,
+                    &owner,
+                    &e.current_contract_address(),
+                    amount,
+                );
+            }
+            StakeSource::Held => {}
+        }
+
+        // Update Round Info
+        let mut round: Round = e
+            .storage()
             .instance()
-            .get(&DataKey::CurrentEpoch)
-            .expect("CURRENT_EPOCH_NOT_FOUND")
-    }
+            .get(&DataKey::Rounds(series_id, epoch))
+            .expect("ROUND_NOT_FOUND");
 
-    pub fn get_token_address(e: &Env) -> Address {
+        // CHECK: Position should match the market type of the series
+        assert!(
+            Self::is_valid_position(e, series_id, &position),
+            "INVALID_POSITION"
+        );
+
+        let mut pool = Self::get_pool(e, &round, token);
+
+        pool.total_amount += amount;
+
+        if let Position::Bucket(bucket) = position {
+            let bucket_amount = pool.bucket_amounts.get(bucket).unwrap_or(0);
+
+            pool.bucket_amounts.set(bucket, bucket_amount + amount);
+        } else if Self::get_pool_side(e, series_id, &position) == Position::Bull {
+            pool.bull_amount += amount;
+        } else {
+            pool.bear_amount += amount;
+        }
+
+        Self::set_pool(e, &mut round, token, pool);
+
+        // Store Updated Round in Storage
         e.storage()
             .instance()
-            .get(&DataKey::Token)
-            .expect("TOKEN_ADDRESS_NOT_FOUND")
-    }
+            .set(&DataKey::Rounds(series_id, epoch), &round);
 
-    pub fn get_min_bet_amount(e: &Env) -> i128 {
-        e.storage()
+        // Record Bet Info
+        let bet_info = BetInfo {
+            position: position.clone(),
+            amount,
+            claimed: false,
+            token: token.clone(),
+        };
+
+        // Store Bet Info in Storage
+        let bet_info_key = DataKey::BetInfos(series_id, epoch, user.clone());
+
+        e.storage().instance().set(&bet_info_key, &bet_info);
+
+        // Get User Rounds (returns an empty vec if none exist)
+        let mut user_rounds: Vec<u128> = e
+            .storage()
             .instance()
-            .get(&DataKey::MinBetAmount)
-            .expect("MIN_BET_AMOUNT_NOT_FOUND")
-    }
+            .get(&DataKey::UserRounds(series_id, user.clone()))
+            .unwrap_or(Vec::new(e));
 
-    pub fn get_treasury_fee(e: &Env) -> u32 {
+        // Add Round to User Rounds
+        user_rounds.push_back(epoch);
+
+        // Store Updated User Rounds in Storage
         e.storage()
             .instance()
-            .get(&DataKey::TreasuryFee)
-            .expect("TREASURY_FEE_NOT_FOUND")
+            .set(&DataKey::UserRounds(series_id, user.clone()), &user_rounds);
+
+        // Record the stake the referrer of the user earns fees on
+        Self::record_referral_stake(e, series_id, epoch, user, token, amount);
+
+        // Update User Stats
+        Self::record_bet_stats(e, series_id, epoch, user, token, amount);
+
+        // Count the bet in the daily wager of the user
+        Self::record_wager(e, user, token, amount);
+
+        // Enter the jackpot draw of the period
+        Self::record_jackpot_entry(e, series_id, epoch, user, token, amount);
+
+        // Emit an Event for Bet Placed
+        emit_bet_placed_event(e, series_id, epoch, user.clone(), amount, position);
     }
 
-    pub fn get_treasury_amount(e: &Env) -> i128 {
-        e.storage()
-            .instance()
-            .get(&DataKey::TreasuryAmount)
-            .expect("TREASURY_AMOUNT_NOT_FOUND")
+    /// Internal function to settle claims and refunds of a user
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `user`: The address of the user
+    /// - `epochs`: The epochs of the rounds to settle
+    /// # Returns
+    /// - `Map<Address, i128>`: The amount owed to the user per token
+    /// # Events
+    /// - `CLAIMED`: Emitted for every settled round
+    fn collect_payouts(
+        e: &Env,
+        series_id: u32,
+        user: &Address,
+        epochs: &Vec<u128>,
+    ) -> Map<Address, i128> {
+        let mut payouts: Map<Address, i128> = Map::new(e);
+
+        // Settle wins and losses of ended rounds before paying out
+        Self::settle_user_stats(e, user);
+
+        for epoch in epochs.iter() {
+            let round = Self::get_round(e, series_id, epoch);
+
+            let bet_info_key = DataKey::BetInfos(series_id, epoch, user.clone());
+
+            let mut bet_info: BetInfo = e
+                .storage()
+                .instance()
+                .get(&bet_info_key)
+                .expect("BET_INFO_NOT_FOUND");
+
+            // CHECK: Bet should not have been claimed yet
+            assert!(!bet_info.claimed, "ALREADY_CLAIMED");
+
+            let won = Self::is_claimable(e, series_id, &round, &bet_info.position);
+
+            let amount = if won {
+                Self::get_bet_reward(e, &round, &bet_info)
+            } else if Self::is_refundable(e, series_id, &round) {
+                // Refunded rounds never enter the jackpot draw
+                if series_id == DEFAULT_SERIES_ID {
+                    e.storage()
+                        .instance()
+                        .remove(&DataKey::JackpotRoundEntries(epoch));
+                }
+
+                bet_info.amount
+            } else {
+                panic!("NOT_ELIGIBLE_FOR_CLAIM");
+            };
+
+            // Update User Stats
+            Self::record_claim_stats(e, series_id, epoch, user, &bet_info.token, amount);
+
+            // Mark Bet as Claimed
+            bet_info.claimed = true;
+
+            e.storage().instance().set(&bet_info_key, &bet_info);
+
+            let owed = payouts.get(bet_info.token.clone()).unwrap_or(0);
+
+            payouts.set(bet_info.token.clone(), owed + amount);
+
+            // Emit an Event for Claimed
+            emit_claimed_event(e, series_id, epoch, user, &bet_info.token, amount);
+        }
+
+        payouts
     }
 
-    pub fn get_round(e: &Env, epoch: u128) -> Round {
+    /// Internal function to update user stats when a bet is placed
+    /// Amounts only track the betting token, bets in other tokens count as rounds played
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// - `user`: The address of the user
+    /// - `token`: The address of the token used for the bet
+    /// - `amount`: The amount of the bet
+    fn record_bet_stats(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: &Address,
+        token: &Address,
+        amount: i128,
+    ) {
+        let wagered = if *token == Self::get_token_address(e) {
+            amount
+        } else {
+            0
+        };
+
+        // Settle wins and losses of ended rounds before counting the new one
+        Self::settle_user_stats(e, user);
+
+        let mut stats = Self::get_user_stats(e, user.clone());
+
+        stats.rounds_played += 1;
+        stats.total_wagered += wagered;
+
         e.storage()
             .instance()
-            .get(&DataKey::Rounds(epoch))
-            .expect("ROUND_NOT_FOUND")
+            .set(&DataKey::UserStats(user.clone()), &stats);
+
+        // Queue the round until it ends and can be settled into the stats
+        let mut unsettled_rounds = Self::get_unsettled_stats_rounds(e, user);
+
+        unsettled_rounds.push_back((series_id, epoch));
+
+        e.storage().instance().set(
+            &DataKey::UnsettledStatsRounds(user.clone()),
+            &unsettled_rounds,
+        );
+
+        let season_id = Self::get_season_id(e, series_id, epoch);
+
+        let mut season_stats = Self::get_season_user_stats(e, season_id, user.clone());
+
+        season_stats.rounds_played += 1;
+        season_stats.total_wagered += wagered;
+
+        Self::set_season_user_stats(e, season_id, user, &season_stats);
     }
 
-    pub fn get_bet_info(e: &Env, epoch: u128, user: Address) -> BetInfo {
-        let bet_info_key = DataKey::BetInfos(epoch, user);
+    /// Internal function to update user stats when a bet is claimed or refunded
+    /// Wins, losses and net profit are settled when the round ends, see `settle_user_stats`
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// - `user`: The address of the user
+    /// - `token`: The address of the token paid out
+    /// - `amount`: The amount paid out
+    fn record_claim_stats(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: &Address,
+        token: &Address,
+        amount: i128,
+    ) {
+        if *token != Self::get_token_address(e) {
+            return;
+        }
+
+        let mut stats = Self::get_user_stats(e, user.clone());
+
+        stats.total_claimed += amount;
+
         e.storage()
             .instance()
-            .get(&bet_info_key)
-            .expect("BET_INFO_NOT_FOUND")
+            .set(&DataKey::UserStats(user.clone()), &stats);
+
+        let season_id = Self::get_season_id(e, series_id, epoch);
+
+        let mut season_stats = Self::get_season_user_stats(e, season_id, user.clone());
+
+        season_stats.total_claimed += amount;
+
+        e.storage().instance().set(
+            &DataKey::SeasonUserStats(season_id, user.clone()),
+            &season_stats,
+        );
     }
 
-    pub fn get_user_rounds(e: &Env, user: Address) -> Vec<u128> {
+    /// Internal function to settle the ended rounds of a user into its stats
+    /// Rounds are counted as won or lost when they end, whether or not they are claimed,
+    /// and refunded rounds neither break nor extend the win streak
+    /// Net profit only tracks the betting token
+    /// # Parameters
+    /// - `user`: The address of the user
+    fn settle_user_stats(e: &Env, user: &Address) {
+        let unsettled_rounds = Self::get_unsettled_stats_rounds(e, user);
+
+        if unsettled_rounds.is_empty() {
+            return;
+        }
+
+        let token_address = Self::get_token_address(e);
+
+        let mut stats = Self::get_user_stats(e, user.clone());
+        let mut still_unsettled: Vec<(u32, u128)> = Vec::new(e);
+
+        for (series_id, epoch) in unsettled_rounds.iter() {
+            let round = Self::get_round(e, series_id, epoch);
+
+            let is_refunded = Self::is_refundable(e, series_id, &round);
+
+            if !Self::is_round_ended(&round) && !is_refunded {
+                still_unsettled.push_back((series_id, epoch));
+                continue;
+            }
+
+            let bet_info = Self::get_bet_info(e, series_id, epoch, user.clone());
+
+            let won = Self::is_claimable(e, series_id, &round, &bet_info.position);
+
+            let payout = if won {
+                Self::get_bet_reward(e, &round, &bet_info)
+            } else if is_refunded {
+                bet_info.amount
+            } else {
+                0
+            };
+
+            let profit = if bet_info.token == token_address {
+                payout - bet_info.amount
+            } else {
+                0
+            };
+
+            stats.net_profit += profit;
+
+            if won {
+                stats.rounds_won += 1;
+                stats.current_win_streak += 1;
+
+                if stats.current_win_streak > stats.best_win_streak {
+                    stats.best_win_streak = stats.current_win_streak;
+                }
+            } else if !is_refunded {
+                stats.current_win_streak = 0;
+            }
+
+            let season_id = Self::get_season_id(e, series_id, epoch);
+
+            let mut season_stats = Self::get_season_user_stats(e, season_id, user.clone());
+
+            season_stats.net_profit += profit;
+
+            if won {
+                season_stats.rounds_won += 1;
+            }
+
+            Self::set_season_user_stats(e, season_id, user, &season_stats);
+        }
+
         e.storage()
             .instance()
-            .get(&DataKey::UserRounds(user))
-            .unwrap_or(Vec::new(&e))
+            .set(&DataKey::UserStats(user.clone()), &stats);
+
+        if still_unsettled.is_empty() {
+            e.storage()
+                .instance()
+                .remove(&DataKey::UnsettledStatsRounds(user.clone()));
+        } else {
+            e.storage().instance().set(
+                &DataKey::UnsettledStatsRounds(user.clone()),
+                &still_unsettled,
+            );
+        }
     }
 
-    pub fn get_oracle_address(e: &Env) -> Address {
+    /// Internal function to get the rounds of a user that are not settled into its stats yet
+    /// # Parameters
+    /// - `user`: The address of the user
+    fn get_unsettled_stats_rounds(e: &Env, user: &Address) -> Vec<(u32, u128)> {
         e.storage()
             .instance()
-            .get(&DataKey::OracleAddress)
-            .expect("ORACLE_ADDRESS_NOT_FOUND")
+            .get(&DataKey::UnsettledStatsRounds(user.clone()))
+            .unwrap_or(Vec::new(e))
+    }
+
+    /// Internal function to get the reward of a winning bet
+    /// # Parameters
+    /// - `round`: The ended round of the bet
+    /// - `bet_info`: The winning bet
+    fn get_bet_reward(e: &Env, round: &Round, bet_info: &BetInfo) -> i128 {
+        let pool = Self::get_pool(e, round, &bet_info.token);
+
+        (bet_info.amount * pool.reward_amount) / pool.reward_base_cal_amount
     }
 
-    pub fn get_interval_seconds(e: &Env) -> u64 {
+    /// Internal function to store season stats and update the season leaderboard
+    /// # Parameters
+    /// - `season_id`: The id of the season
+    /// - `user`: The address of the user
+    /// - `stats`: The updated season stats of the user
+    fn set_season_user_stats(e: &Env, season_id: u32, user: &Address, stats: &UserStats) {
         e.storage()
             .instance()
-            .get(&DataKey::IntervalSeconds)
-            .expect("INTERVAL_SECONDS_NOT_FOUND")
+            .set(&DataKey::SeasonUserStats(season_id, user.clone()), stats);
+
+        // Leaderboard is ranked by settled net profit in the betting token unless the season says otherwise
+        let metric = if season_id == 0 {
+            RankingMetric::NetProfit
+        } else {
+            Self::get_season(e, season_id).metric
+        };
+
+        let score = match metric {
+            RankingMetric::NetProfit => stats.net_profit,
+            RankingMetric::WinCount => stats.rounds_won as i128,
+        };
+
+        Self::update_leaderboard(e, season_id, user, score);
+    }
+
+    /// Internal function to get the time the last round of an ended season closed
+    /// Rounds of a regular series end in order, while strike and event rounds can close in any
+    /// order, so each of them is checked
+    /// # Parameters
+    /// - `season`: The season to check
+    /// # Returns
+    /// - `u64`: The latest close timestamp of the rounds of the season
+    fn get_season_close_timestamp(e: &Env, season: &Season) -> u64 {
+        let series_id = season.series_id;
+        let current_epoch = Self::get_current_epoch(e, series_id);
+
+        if !Self::is_strike_series(e, series_id) && !Self::is_event_series(e, series_id) {
+            assert!(current_epoch > season.end_epoch + 1, "SEASON_NOT_ENDED");
+
+            return Self::get_round(e, series_id, season.end_epoch).close_timestamp;
+        }
+
+        assert!(current_epoch >= season.end_epoch, "SEASON_NOT_ENDED");
+
+        let mut close_timestamp = 0;
+
+        for epoch in season.start_epoch..=season.end_epoch {
+            let round = Self::get_round(e, series_id, epoch);
+
+            assert!(
+                Self::is_round_ended(&round) || Self::is_refundable(e, series_id, &round),
+                "SEASON_NOT_ENDED"
+            );
+
+            close_timestamp = close_timestamp.max(round.close_timestamp);
+        }
+
+        close_timestamp
     }
 
-    pub fn get_buffer_seconds(e: &Env) -> u64 {
+    /// Internal function to insert or move a user on a bounded, descending leaderboard
+    /// Users only re-enter the leaderboard when their own score changes, evicted users are not
+    /// tracked so a dropping entry cannot be replaced by them
+    /// # Parameters
+    /// - `season_id`: The id of the season
+    /// - `user`: The address of the user
+    /// - `score`: The new score of the user
+    fn update_leaderboard(e: &Env, season_id: u32, user: &Address, score: i128) {
+        let mut leaderboard = Self::get_leaderboard(e, season_id);
+
+        // Remove the previous entry of the user
+        for (index, entry) in leaderboard.iter().enumerate() {
+            if entry.user == *user {
+                leaderboard.remove(index as u32);
+                break;
+            }
+        }
+
+        // Find the rank of the user
+        let mut rank = leaderboard.len();
+
+        for (index, entry) in leaderboard.iter().enumerate() {
+            if score > entry.score {
+                rank = index as u32;
+                break;
+            }
+        }
+
+        if rank < LEADERBOARD_SIZE {
+            leaderboard.insert(
+                rank,
+                LeaderboardEntry {
+                    user: user.clone(),
+                    score,
+                },
+            );
+        }
+
+        while leaderboard.len() > LEADERBOARD_SIZE {
+            leaderboard.pop_back();
+        }
+
         e.storage()
             .instance()
-            .get(&DataKey::BufferSeconds)
-            .expect("BUFFER_SECONDS_NOT_FOUND")
+            .set(&DataKey::Leaderboard(season_id), &leaderboard);
     }
 
-    /// Readonly function to check if a round is bettable
+    /// Internal function to check a bet against the limits of the user and against the bet, round
+    /// and user caps, bets in other tokens are valued in the betting token
     /// # Parameters
-    /// - `epoch`: The epoch of the round to check
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// - `user`: The address of the user placing the bet
+    /// - `token`: The address of the token used for the bet
+    /// - `amount`: The amount of tokens to bet
     /// # Returns
-    /// - `bool`: True if the round is bettable, false otherwise
-    pub fn is_bettable(e: &Env, epoch: u128) -> bool {
-        let round: Round = e
-            .storage()
-            .instance()
-            .get(&DataKey::Rounds(epoch))
-            .expect("ROUND_NOT_FOUND");
+    /// - `Option<Error>`: The error the bet is refused with, None if it is allowed
+    fn get_bet_restriction(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> Option<Error> {
+        if let Some(error) = Self::get_stake_restriction(e, series_id, epoch, user, token, amount) {
+            return Some(error);
+        }
 
-        let current_timestamp: u64 = e.ledger().timestamp();
+        let max_round_amount = Self::get_max_round_amount(e);
 
-        round.start_timestamp != 0
-            && round.lock_timestamp != 0
-            && current_timestamp > round.start_timestamp
-            && current_timestamp < round.lock_timestamp
-    }
+        if max_round_amount != 0
+            && Self::get_round_value(e, &Self::get_round(e, series_id, epoch))
+                + Self::get_token_value(e, token, amount)
+                > max_round_amount
+        {
+            return Some(Error::RoundCapExceeded);
+        }
 
-    //////////////////////////////// INTERNALS ////////////////////////////////
+        None
+    }
 
-    /// Internal function to start a new round
+    /// Internal function to check a stake against the limits of the user and against the bet and
+    /// user caps, without the round cap as peer-to-peer stakes do not enter the pools of the round
     /// # Parameters
-    /// - `epoch`: The epoch of the round to be started
-    fn start_round(e: &Env, epoch: u128) {
-        let start_timestamp = e.ledger().timestamp();
+    /// - `series_id`: The id of the series
+    /// - `epoch`: The epoch of the round
+    /// - `user`: The address of the user staking
+    /// - `token`: The address of the token staked
+    /// - `amount`: The amount of tokens staked
+    /// # Returns
+    /// - `Option<Error>`: The error the stake is refused with, None if it is allowed
+    fn get_stake_restriction(
+        e: &Env,
+        series_id: u32,
+        epoch: u128,
+        user: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> Option<Error> {
+        if let Some(error) = Self::get_wager_restriction(e, user, token, amount) {
+            return Some(error);
+        }
 
-        // Get Interval Seconds
-        let interval_seconds: u64 = e
-            .storage()
-            .instance()
-            .get(&DataKey::IntervalSeconds)
-            .expect("INTERVAL_SECONDS_NOT_FOUND");
+        let value = Self::get_token_value(e, token, amount);
 
-        // lock_timestamp = start_timestamp + interval_seconds
-        let lock_timestamp = start_timestamp + interval_seconds;
+        let max_bet_amount = Self::get_max_bet_amount(e);
 
-        // close_timestamp = start_timestamp + 2 * interval_seconds
-        let close_timestamp = lock_timestamp + interval_seconds;
+        if max_bet_amount != 0 && value > max_bet_amount {
+            return Some(Error::BetAmountTooHigh);
+        }
 
-        // Create New Round
-        let round = Round {
-            epoch,
-            start_timestamp,
-            lock_timestamp,
-            close_timestamp,
-            lock_price: 0,
-            close_price: 0,
-            total_amount: 0,
-            bull_amount: 0,
-            bear_amount: 0,
-            reward_base_cal_amount: 0,
-            reward_amount: 0,
-        };
+        let user_cap = Self::get_user_cap(e);
 
-        // Store Round in Storage
-        e.storage().instance().set(&DataKey::Rounds(epoch), &round);
+        if user_cap.amount != 0
+            && Self::get_user_rolling_amount(e, series_id, epoch, user.clone()) + value
+                > user_cap.amount
+        {
+            return Some(Error::UserCapExceeded);
+        }
 
-        // Emit an Event for Round Started
-        emit_round_started_event(e, epoch, start_timestamp, lock_timestamp, close_timestamp);
+        None
     }
 
-    /// Internal function to safely lock a round
+    /// Internal function to value an amount of an accepted token in the betting token
     /// # Parameters
-    /// - `epoch`: The epoch of the round to be locked
-    /// - `current_price`: The current price fetched from the oracle
-    fn safe_lock_round(e: &Env, epoch: u128, current_price: i128) {
-        let mut round: Round = e
-            .storage()
-            .instance()
-            .get(&DataKey::Rounds(epoch))
-            .expect("ROUND_NOT_FOUND");
+    /// - `token`: The address of the token
+    /// - `amount`: The amount of tokens
+    fn get_token_value(e: &Env, token: &Address, amount: i128) -> i128 {
+        (amount * Self::get_token_rate(e, token.clone())) / TOKEN_RATE_SCALE
+    }
 
-        // CHECK: Round should have started
-        assert!(round.start_timestamp != 0, "CANNOT_LOCK_NON_STARTED_ROUND");
+    /// Internal function to value the pools of a round in the betting token
+    /// # Parameters
+    /// - `round`: The round to value
+    fn get_round_value(e: &Env, round: &Round) -> i128 {
+        round
+            .token_pools
+            .iter()
+            .fold(round.total_amount, |value, (token, pool)| {
+                value + Self::get_token_value(e, &token, pool.total_amount)
+            })
+    }
 
-        let current_timestamp: u64 = e.ledger().timestamp();
+    /// Internal function to check a wager against the access lists, the self-exclusion and the
+    /// daily wager limit of a user
+    /// # Parameters
+    /// - `user`: The address of the user wagering
+    /// - `token`: The address of the token wagered
+    /// - `amount`: The amount of tokens wagered
+    /// # Returns
+    /// - `Option<Error>`: The error the wager is refused with, None if it is allowed
+    fn get_wager_restriction(
+        e: &Env,
+        user: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> Option<Error> {
+        if !Self::has_access(e, user.clone()) {
+            return Some(Error::AccessDenied);
+        }
 
-        // CHECK: Current time should be after or equal to lock timestamp
-        assert!(
-            current_timestamp >= round.lock_timestamp,
-            "CANNOT_LOCK_BEFORE_LOCK_TIMESTAMP"
-        );
+        if e.ledger().timestamp() < Self::get_self_exclusion(e, user.clone()) {
+            return Some(Error::SelfExcluded);
+        }
 
-        let buffer_seconds: u64 = e
-            .storage()
-            .instance()
-            .get(&DataKey::BufferSeconds)
-            .expect("BUFFER_SECONDS_NOT_FOUND");
+        let wager_limit = Self::get_user_wager_limit(e, user.clone());
+
+        // Daily wager limits are counted in the betting token
+        let value = Self::get_token_value(e, token, amount);
+
+        if wager_limit != 0 && Self::get_user_daily_wager(e, user.clone()) + value > wager_limit {
+            return Some(Error::WagerLimitExceeded);
+        }
+
+        None
+    }
+
+    /// Internal function to refuse a gift from a payer the beneficiary did not allow
+    /// # Parameters
+    /// - `payer`: The address funding the bet
+    /// - `beneficiary`: The address owning the bet
+    fn require_gift_payer(e: &Env, payer: &Address, beneficiary: &Address) {
+        let gift_payers = Self::get_gift_payers(e, beneficiary.clone());
 
-        // CHECK: Current time should be within buffer seconds of lock timestamp
         assert!(
-            current_timestamp <= round.lock_timestamp + buffer_seconds,
-            "CANNOT_LOCK_OUTSIDE_BUFFER"
+            gift_payers.is_empty() || gift_payers.contains(payer),
+            "PAYER_NOT_ALLOWED"
         );
+    }
 
-        let interval_seconds: u64 = e
-            .storage()
-            .instance()
-            .get(&DataKey::IntervalSeconds)
-            .expect("INTERVAL_SECONDS_NOT_FOUND");
+    /// Internal function to refuse an address that is not granted access
+    /// # Parameters
+    /// - `user`: The address to check
+    fn require_access(e: &Env, user: &Address) {
+        if !Self::has_access(e, user.clone()) {
+            panic_with_error!(e, Error::AccessDenied);
+        }
+    }
 
-        // Update Round Details on Lock
-        round.lock_price = current_price;
-        round.close_timestamp = current_timestamp + interval_seconds;
+    /// Internal function to add a wager, valued in the betting token, to the daily total of a user
+    /// # Parameters
+    /// - `user`: The address of the user wagering
+    /// - `token`: The address of the token wagered
+    /// - `amount`: The amount of tokens wagered
+    fn record_wager(e: &Env, user: &Address, token: &Address, amount: i128) {
+        let day = e.ledger().timestamp() / SECONDS_PER_DAY;
 
-        // Store Updated Round in Storage
-        e.storage().instance().set(&DataKey::Rounds(epoch), &round);
+        let daily_wager =
+            Self::get_user_daily_wager(e, user.clone()) + Self::get_token_value(e, token, amount);
 
-        // Emit an Event for Round Locked
-        emit_round_locked_event(e, epoch, current_timestamp, current_price);
+        // A new day overwrites the total of the previous one
+        e.storage()
+            .instance()
+            .set(&DataKey::UserDailyWagers(user.clone()), &(day, daily_wager));
     }
 
-    /// Internal function to safely end a round
+    /// Internal function to check if a position can be bet on the rounds of a series
     /// # Parameters
-    /// - `epoch`: The epoch of the round to be ended
-    /// - `current_price`: The current price fetched from the oracle
-    fn safe_end_round(e: &Env, epoch: u128, current_price: i128) {
-        let mut round: Round = e
-            .storage()
-            .instance()
-            .get(&DataKey::Rounds(epoch))
-            .expect("ROUND_NOT_FOUND");
+    /// - `series_id`: The id of the series
+    /// - `position`: The side of the bet
+    fn is_valid_position(e: &Env, series_id: u32, position: &Position) -> bool {
+        let boundaries = Self::get_series_buckets(e, series_id);
+
+        let series_assets = Self::get_series_assets(e, series_id);
+
+        let move_threshold = Self::get_move_threshold(e, series_id);
+
+        let is_event_series = Self::is_event_series(e, series_id);
+
+        match position {
+            // A series with n boundaries has n + 1 buckets
+            Position::Bucket(bucket) => !boundaries.is_empty() && *bucket <= boundaries.len(),
+            Position::Asset(asset) => series_assets.contains(asset),
+            Position::BigMove | Position::SmallMove => move_threshold != 0,
+            Position::Yes | Position::No => is_event_series,
+            Position::Bull | Position::Bear => {
+                boundaries.is_empty()
+                    && series_assets.is_empty()
+                    && move_threshold == 0
+                    && !is_event_series
+            }
+        }
+    }
 
-        // CHECK: Round should be locked
-        assert!(round.lock_timestamp != 0, "CANNOT_END_NON_LOCKED_ROUND");
+    /// Internal function to get the side opposite to a position
+    /// Panics for bucket positions, which have no single opposite side
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `position`: The side to flip
+    fn get_opposite_position(e: &Env, series_id: u32, position: &Position) -> Position {
+        match position {
+            Position::Bull => Position::Bear,
+            Position::Bear => Position::Bull,
+            Position::BigMove => Position::SmallMove,
+            Position::SmallMove => Position::BigMove,
+            Position::Yes => Position::No,
+            Position::No => Position::Yes,
+            Position::Asset(asset) => {
+                let series_assets = Self::get_series_assets(e, series_id);
+
+                let rival_asset = series_assets
+                    .iter()
+                    .find(|series_asset| series_asset != asset)
+                    .expect("INVALID_POSITION");
+
+                Position::Asset(rival_asset)
+            }
+            Position::Bucket(_) => panic!("INVALID_POSITION"),
+        }
+    }
 
-        let current_timestamp: u64 = e.ledger().timestamp();
+    /// Internal function to check if a round has ended with its close price or event outcome
+    /// # Parameters
+    /// - `round`: The round to check
+    fn is_round_ended(round: &Round) -> bool {
+        // Close price or event outcome is only set once the round has ended and rewards were calculated
+        round.close_price != 0 || round.event_outcome.is_some()
+    }
 
-        // CHECK: Current time should be after or equal to close timestamp
-        assert!(
-            current_timestamp >= round.close_timestamp,
-            "CANNOT_END_BEFORE_CLOSE_TIMESTAMP"
-        );
+    /// Internal function to check if a position won its round
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `round`: The round of the bet
+    /// - `position`: The side to check
+    fn is_claimable(e: &Env, series_id: u32, round: &Round, position: &Position) -> bool {
+        if !Self::is_round_ended(round) {
+            return false;
+        }
 
-        let buffer_seconds: u64 = e
-            .storage()
-            .instance()
-            .get(&DataKey::BufferSeconds)
-            .expect("BUFFER_SECONDS_NOT_FOUND");
+        match position {
+            Position::Bucket(bucket) => round.winning_bucket == Some(*bucket),
+            _ => Self::get_winning_side(round) == Some(Self::get_pool_side(e, series_id, position)),
+        }
+    }
 
-        // CHECK: Current time should be within buffer seconds of close timestamp
-        assert!(
-            current_timestamp <= round.close_timestamp + buffer_seconds,
-            "CANNOT_END_OUTSIDE_BUFFER"
-        );
+    /// Internal function to get the pool side a position is counted on
+    /// Positions of every market type other than buckets are pooled on the bull or bear side
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `position`: The side of the bet
+    /// # Returns
+    /// - `Position`: `Position::Bull` or `Position::Bear`
+    fn get_pool_side(e: &Env, series_id: u32, position: &Position) -> Position {
+        match position {
+            Position::Bull | Position::BigMove | Position::Yes => Position::Bull,
+            Position::Bear | Position::SmallMove | Position::No => Position::Bear,
+            // Head-to-head rounds pool the first asset on the bull side and the second on the bear side
+            Position::Asset(asset) => {
+                if Self::get_series_assets(e, series_id).first() == Some(asset.clone()) {
+                    Position::Bull
+                } else {
+                    Position::Bear
+                }
+            }
+            Position::Bucket(_) => panic!("INVALID_POSITION"),
+        }
+    }
 
-        round.close_price = current_price;
+    /// Internal function to find the winning pool side of an ended round without buckets
+    /// # Parameters
+    /// - `round`: The ended round
+    /// # Returns
+    /// - `Option<Position>`: `Position::Bull` or `Position::Bear`, None if no side wins
+    fn get_winning_side(round: &Round) -> Option<Position> {
+        let is_bull_winning = if round.rival_lock_price != 0 {
+            match Self::get_outperforming_asset(round) {
+                Some(index) => index == 0,
+                None => return None,
+            }
+        } else if let Some(event_outcome) = round.event_outcome {
+            event_outcome
+        } else if round.move_threshold != 0 {
+            Self::is_big_move(round)
+        } else if round.close_price != round.lock_price {
+            round.close_price > round.lock_price
+        } else {
+            return None;
+        };
 
-        // Store Updated Round in Storage
-        e.storage().instance().set(&DataKey::Rounds(epoch), &round);
+        if is_bull_winning {
+            Some(Position::Bull)
+        } else {
+            Some(Position::Bear)
+        }
+    }
 
-        // Emit an Event for Round Ended
-        emit_round_ended_event(e, epoch, current_timestamp, current_price);
+    /// Internal function to check if the price of a volatility round moved more than its threshold
+    /// # Parameters
+    /// - `round`: The ended round
+    fn is_big_move(round: &Round) -> bool {
+        // |close - lock| / lock > threshold / 10_000, without rounding
+        (round.close_price - round.lock_price).abs() * 10_000
+            > round.lock_price * round.move_threshold as i128
     }
 
-    /// Internal function to safely start a new round
+    /// Internal function to find which asset of a head-to-head round had the higher percentage change
     /// # Parameters
-    /// - `epoch`: The epoch of the round to be started
-    /// # Events
-    /// - `ROUND_STARTED`: Emitted when a new round is started
-    fn safe_start_round(e: &Env, epoch: u128) {
-        let is_genesis_started: bool = e
-            .storage()
-            .instance()
-            .get(&DataKey::IsGenesisStarted)
-            .expect("IS_GENESIS_STARTED_NOT_FOUND");
+    /// - `round`: The ended round
+    /// # Returns
+    /// - `Option<u32>`: 0 for the first asset, 1 for the second, None on a tie
+    fn get_outperforming_asset(round: &Round) -> Option<u32> {
+        // close / lock > rival_close / rival_lock, without rounding
+        let return_cmp = (round.close_price * round.rival_lock_price)
+            .cmp(&(round.rival_close_price * round.lock_price));
+
+        match return_cmp {
+            Ordering::Greater => Some(0),
+            Ordering::Less => Some(1),
+            Ordering::Equal => None,
+        }
+    }
 
-        assert!(is_genesis_started, "GENESIS_NOT_STARTED");
+    /// Internal function to find the bucket of a bucket series the price change of a round falls in
+    /// # Parameters
+    /// - `series_id`: The id of the series
+    /// - `round`: The ended round
+    fn get_winning_bucket(e: &Env, series_id: u32, round: &Round) -> u32 {
+        let mut winning_bucket: u32 = 0;
+
+        // close / lock - 1 >= boundary / 10_000, without rounding
+        for boundary in Self::get_series_buckets(e, series_id).iter() {
+            if round.close_price * 10_000 >= round.lock_price * (10_000 + boundary as i128) {
+                winning_bucket += 1;
+            }
+        }
 
-        // Get n -2 round
-        let prev_prev_epoch = epoch - 2;
+        winning_bucket
+    }
 
-        let prev_prev_round: Round = e
-            .storage()
-            .instance()
-            .get(&DataKey::Rounds(prev_prev_epoch))
-            .expect("ROUND_NOT_FOUND");
+    /// Internal function to create zeroed bucket amounts
+    /// # Parameters
+    /// - `bucket_count`: The number of buckets, 0 for an up/down series
+    fn get_empty_bucket_amounts(e: &Env, bucket_count: u32) -> Vec<i128> {
+        let mut bucket_amounts = Vec::new(e);
 
-        // CHECK: n - 2 round should be closed
-        assert!(
-            prev_prev_round.close_timestamp != 0,
-            "CANNOT_START_BEFORE_PREV_PREV_ROUND_CLOSED"
-        );
+        for _ in 0..bucket_count {
+            bucket_amounts.push_back(0);
+        }
 
-        Self::start_round(e, epoch);
+        bucket_amounts
     }
 
-    /// Internal function to calculate rewards for a round
+    /// Internal function to check if a round was never ended and its bets can be refunded
     /// # Parameters
-    /// - `epoch`: The epoch of the round to calculate rewards for
-    /// - # Events
-    /// - `REWARDS_CALCULATED`: Emitted when rewards are calculated
-    fn calculate_rewards(e: &Env, epoch: u128) {
-        let mut round: Round = e
-            .storage()
-            .instance()
-            .get(&DataKey::Rounds(epoch))
-            .expect("ROUND_NOT_FOUND");
+    /// - `series_id`: The id of the series
+    /// - `round`: The round to check
+    fn is_refundable(e: &Env, series_id: u32, round: &Round) -> bool {
+        let buffer_seconds = Self::get_buffer_seconds(e, series_id);
 
-        // CHECK: Rewards should not have been calculated yet
-        assert!(
-            round.reward_amount == 0 && round.reward_base_cal_amount == 0,
-            "REWARDS_ALREADY_CALCULATED"
-        );
+        !Self::is_round_ended(round)
+            && e.ledger().timestamp() > round.close_timestamp + buffer_seconds
+    }
 
-        let treasury_fee: u32 = e
-            .storage()
-            .instance()
-            .get(&DataKey::TreasuryFee)
-            .expect("TREASURY_FEE_NOT_FOUND");
+    /// Internal function to check if a token can be used for bets and deposits
+    /// # Parameters
+    /// - `token`: The address of the token
+    fn is_accepted_token(e: &Env, token: &Address) -> bool {
+        *token == Self::get_token_address(e) || Self::get_allowed_tokens(e).contains(token)
+    }
 
-        let treasury_amt: i128;
+    /// Internal function to credit the internal balance of a user
+    /// # Parameters
+    /// - `user`: The address of the user
+    /// - `token`: The address of the token
+    /// - `amount`: The amount to credit
+    /// - `reason`: The movement that credited the balance
+    /// # Events
+    /// - `BALANCE_CREDITED`: Emitted when the balance is credited
+    fn credit_balance(e: &Env, user: &Address, token: &Address, amount: i128, reason: Symbol) {
+        let balance = Self::get_balance(e, user.clone(), token.clone()) + amount;
 
-        // Determine Winning Side
-        if round.close_price > round.lock_price {
-            // Bull Wins
-            round.reward_base_cal_amount = round.bull_amount;
-            treasury_amt = (round.total_amount * treasury_fee as i128) / 10_000;
-            round.reward_amount = round.total_amount - treasury_amt;
-        } else if round.close_price < round.lock_price {
-            // Bear Wins
-            round.reward_base_cal_amount = round.bear_amount;
-            treasury_amt = (round.total_amount * treasury_fee as i128) / 10_000;
-            round.reward_amount = round.total_amount - treasury_amt;
-        } else {
-            // No one wins, all bets go to treasury
-            round.reward_base_cal_amount = 0;
-            treasury_amt = round.total_amount;
-            round.reward_amount = 0;
-        }
+        e.storage()
+            .instance()
+            .set(&DataKey::Balances(user.clone(), token.clone()), &balance);
 
-        // Store Updated Round in Storage
-        e.storage().instance().set(&DataKey::Rounds(epoch), &round);
+        emit_balance_credited_event(e, user, token, reason, amount, balance);
+    }
 
-        // Update Treasury Amount in Storage
-        let mut treasury_amount: i128 = e
-            .storage()
-            .instance()
-            .get(&DataKey::TreasuryAmount)
-            .expect("TREASURY_AMOUNT_NOT_FOUND");
+    /// Internal function to debit the internal balance of a user
+    /// # Parameters
+    /// - `user`: The address of the user
+    /// - `token`: The address of the token
+    /// - `amount`: The amount to debit
+    /// - `reason`: The movement that debited the balance
+    /// # Events
+    /// - `BALANCE_DEBITED`: Emitted when the balance is debited
+    fn debit_balance(e: &Env, user: &Address, token: &Address, amount: i128, reason: Symbol) {
+        let balance = Self::get_balance(e, user.clone(), token.clone());
+
+        assert!(balance >= amount, "INSUFFICIENT_BALANCE");
 
-        treasury_amount += treasury_amt;
+        let balance = balance - amount;
 
         e.storage()
             .instance()
-            .set(&DataKey::TreasuryAmount, &treasury_amount);
+            .set(&DataKey::Balances(user.clone(), token.clone()), &balance);
 
-        // Emit an Event for Rewards Calculated
-        emit_rewards_calculated_event(e, epoch, round.reward_amount, treasury_amt);
+        emit_balance_debited_event(e, user, token, reason, amount, balance);
     }
 
-    /// Internal function to get the token price from an oracle
-    fn get_token_price(e: &Env) -> i128 {
-        Self::get_xlm_oracle_price(e)
+    /// Internal function to read the pool of a token in a round
+    /// The betting token pool lives in the round fields, other tokens in `token_pools`
+    /// # Parameters
+    /// - `round`: The round to read from
+    /// - `token`: The address of the token
+    fn get_pool(e: &Env, round: &Round, token: &Address) -> TokenPool {
+        if *token == Self::get_token_address(e) {
+            return TokenPool {
+                total_amount: round.total_amount,
+                bull_amount: round.bull_amount,
+                bear_amount: round.bear_amount,
+                reward_base_cal_amount: round.reward_base_cal_amount,
+                reward_amount: round.reward_amount,
+                bucket_amounts: round.bucket_amounts.clone(),
+            };
+        }
+
+        round.token_pools.get(token.clone()).unwrap_or(TokenPool {
+            total_amount: 0,
+            bull_amount: 0,
+            bear_amount: 0,
+            reward_base_cal_amount: 0,
+            reward_amount: 0,
+            bucket_amounts: Self::get_empty_bucket_amounts(e, round.bucket_amounts.len()),
+        })
+    }
+
+    /// Internal function to write the pool of a token in a round
+    /// # Parameters
+    /// - `round`: The round to update
+    /// - `token`: The address of the token
+    /// - `pool`: The updated pool
+    fn set_pool(e: &Env, round: &mut Round, token: &Address, pool: TokenPool) {
+        if *token == Self::get_token_address(e) {
+            round.total_amount = pool.total_amount;
+            round.bull_amount = pool.bull_amount;
+            round.bear_amount = pool.bear_amount;
+            round.reward_base_cal_amount = pool.reward_base_cal_amount;
+            round.reward_amount = pool.reward_amount;
+            round.bucket_amounts = pool.bucket_amounts;
+            return;
+        }
+
+        round.token_pools.set(token.clone(), pool);
     }
 
     /// Internal function to check if a user has already placed a bet in a round
     /// # Parameters
+    /// - `series_id`: The id of the series
     /// - `epoch`: The epoch of the round
     /// - `user`: The address of the user
-    fn has_bet(e: &Env, epoch: u128, user: &Address) -> bool {
-        let bet_info_key = DataKey::BetInfos(epoch, user.clone());
+    fn has_bet(e: &Env, series_id: u32, epoch: u128, user: &Address) -> bool {
+        let bet_info_key = DataKey::BetInfos(series_id, epoch, user.clone());
         let existing_bet_info: Option<BetInfo> = e.storage().instance().get(&bet_info_key);
 
         existing_bet_info.is_some()
     }
 
+    /// Internal function to authorize a bet funded under an allowance
+    /// # Parameters
+    /// - `spender`: The address spending the allowance
+    /// - `user`: The address of the user owning the allowance
+    fn require_spender_auth(e: &Env, spender: &Address, user: &Address) {
+        // The contract can only spend its own allowance with the consent of the user
+        if *spender == e.current_contract_address() {
+            user.require_auth();
+        } else {
+            spender.require_auth();
+        }
+    }
+
     /// Internal function to safely transfer tokens from a user to another address
+    /// # Parameters
+    /// - `spender`: The address approved by `from` to spend its tokens
+    /// - `from`: The address of the user owning the tokens
+    /// - `to`: The address receiving the tokens
+    /// - `amount`: The amount of tokens to transfer
     fn safe_transfer_from_tokens(
-        e: &Env,
+        _e: &Env,
         token_client: &token::Client,
+        spender: &Address,
         from: &Address,
         to: &Address,
         amount: i128,
@@ -1033,13 +7048,11 @@ impl PredictionMarket {
 
         assert!(from_balance >= amount, "INSUFFICIENT_BALANCE");
 
-        let current_contract_address = e.current_contract_address();
-
-        let from_allowance = token_client.allowance(from, &current_contract_address);
+        let from_allowance = token_client.allowance(from, spender);
 
         assert!(from_allowance >= amount, "INSUFFICIENT_ALLOWANCE");
 
-        token_client.transfer_from(&current_contract_address, from, to, &amount);
+        token_client.transfer_from(spender, from, to, &amount);
     }
 
     fn safe_transfer_tokens(
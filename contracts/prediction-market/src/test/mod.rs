@@ -1,19 +1,26 @@
 #![cfg(test)]
 
+extern crate std;
+
+mod receiver;
 mod types;
 
+use ed25519_dalek::{Signer, SigningKey};
 use mock_token::contract::{MyToken, MyTokenClient};
 use soroban_sdk::{
     log,
     testutils::{Address as _, Ledger},
-    Address, Env, Symbol, Vec,
+    Address, BytesN, Env, String, Symbol, Vec,
 };
 
 use crate::contract::{
     reflector_oracle::{self, Asset, ConfigData},
-    Position, PredictionMarket, PredictionMarketClient,
+    AccessMode, BetIntent, Error, FeeCurve, IntentFunding, P2PBetStatus, Position,
+    PredictionMarket, PredictionMarketClient, RankingMetric, StandingOrder, UserCap,
 };
 
+use receiver::FlashLoanRepayer;
+
 const DEFAULT_TOKEN_ID: &str = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
 const DEFAULT_ORACLE_ID: &str = "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63";
 const DEFAULT_INTERVAL_SECONDS: u64 = 300; // 5 minutes
@@ -34,6 +41,7 @@ fn generate_asset(e: &Env) -> Vec<Asset> {
     let mut assets = Vec::new(&e);
 
     assets.push_back(Asset::Other(Symbol::new(e, &("XLM"))));
+    assets.push_back(Asset::Other(Symbol::new(e, &("BTC"))));
 
     assets
 }
@@ -104,6 +112,7 @@ fn init_test<'a>(
             DEFAULT_TREASURY_FEE,
             DEFAULT_FLASH_LOAN_FEE,
             &oracle_id,
+            Symbol::new(env, "XLM"),
         ),
     );
 
@@ -112,14 +121,98 @@ fn init_test<'a>(
     (admin, oracle_id, token_id, client, contract_id)
 }
 
+/// Runs the genesis rounds and moves the ledger into the betting window of the new round
+fn start_genesis(env: &Env, client: &PredictionMarketClient) -> u128 {
+    start_series_genesis(env, client, 0)
+}
+
+/// Starts and locks the genesis round of a series, leaving the ledger inside the next round
+fn start_series_genesis(env: &Env, client: &PredictionMarketClient, series_id: u32) -> u128 {
+    client.genesis_start_round(&series_id);
+
+    let round = client.get_round(&series_id, &1);
+    env.ledger().set_timestamp(round.lock_timestamp);
+
+    client.genesis_lock_round(&series_id);
+
+    let current_epoch = client.get_current_epoch(&series_id);
+    let round = client.get_round(&series_id, &current_epoch);
+    env.ledger().set_timestamp(round.start_timestamp + 1);
+
+    current_epoch
+}
+
+/// Publishes `price` to the oracle and executes the current round at its lock time
+fn execute_round_at_price(
+    env: &Env,
+    client: &PredictionMarketClient,
+    oracle_id: &Address,
+    price: i128,
+) {
+    execute_series_round_at_price(env, client, oracle_id, 0, price);
+}
+
+/// Publishes `price` to the oracle and executes the current round of a series at its lock time
+fn execute_series_round_at_price(
+    env: &Env,
+    client: &PredictionMarketClient,
+    oracle_id: &Address,
+    series_id: u32,
+    price: i128,
+) {
+    let current_epoch = client.get_current_epoch(&series_id);
+    let round = client.get_round(&series_id, &current_epoch);
+    env.ledger().set_timestamp(round.lock_timestamp);
+
+    let reflect_client = reflector_oracle::Client::new(env, oracle_id);
+    let updates = get_updates(env, &generate_asset(env), normalize_price(price));
+    reflect_client.set_price(&updates, &(round.lock_timestamp * 1000));
+
+    client.execute_round(&series_id);
+}
+
+/// Publishes one price per oracle asset and executes the current round of a series at its lock time
+fn execute_series_round_at_prices(
+    env: &Env,
+    client: &PredictionMarketClient,
+    oracle_id: &Address,
+    series_id: u32,
+    prices: [i128; 2],
+) {
+    let current_epoch = client.get_current_epoch(&series_id);
+    let round = client.get_round(&series_id, &current_epoch);
+    env.ledger().set_timestamp(round.lock_timestamp);
+
+    let reflect_client = reflector_oracle::Client::new(env, oracle_id);
+    let updates = Vec::from_array(env, prices.map(normalize_price));
+    reflect_client.set_price(&updates, &(round.lock_timestamp * 1000));
+
+    client.execute_round(&series_id);
+}
+
+/// Signs the payload of a bet intent with the given ed25519 key
+fn sign_intent(
+    env: &Env,
+    client: &PredictionMarketClient,
+    signing_key: &SigningKey,
+    intent: &BetIntent,
+) -> BytesN<64> {
+    let payload = client.get_bet_intent_payload(intent);
+
+    let mut message = std::vec![0u8; payload.len() as usize];
+    payload.copy_into_slice(&mut message);
+
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
 #[test]
 fn test_constructor() {
     let env = Env::default();
     let (admin, oracle_id, token_id, client, _) = init_test(&env);
 
     assert_eq!(client.get_owner().expect("OWNER_NOT_FOUND"), admin);
-    assert_eq!(client.get_interval_seconds(), DEFAULT_INTERVAL_SECONDS);
-    assert_eq!(client.get_buffer_seconds(), DEFAULT_BUFFER_SECONDS);
+    assert_eq!(client.get_interval_seconds(&0), DEFAULT_INTERVAL_SECONDS);
+    assert_eq!(client.get_buffer_seconds(&0), DEFAULT_BUFFER_SECONDS);
     assert_eq!(client.get_min_bet_amount(), DEFAULT_MIN_BET_AMOUNT);
     assert_eq!(client.get_treasury_fee(), DEFAULT_TREASURY_FEE);
     assert_eq!(client.get_token_address(), token_id);
@@ -149,8 +242,8 @@ fn test_genesis_start_round_twice() {
     let env = Env::default();
     let (_, _, _, client, _) = init_test(&env);
 
-    client.genesis_start_round();
-    client.genesis_start_round(); // Should panic
+    client.genesis_start_round(&0);
+    client.genesis_start_round(&0); // Should panic
 }
 
 #[test]
@@ -159,7 +252,7 @@ fn test_genesis_lock_round_before_start() {
     let env = Env::default();
     let (_, _, _, client, _) = init_test(&env);
 
-    client.genesis_lock_round(); // Should panic
+    client.genesis_lock_round(&0); // Should panic
 }
 
 #[test]
@@ -168,11 +261,11 @@ fn test_genesis_round_flow() {
 
     let (_, _, _, client, _) = init_test(&env);
 
-    client.genesis_start_round();
+    client.genesis_start_round(&0);
 
-    assert_eq!(client.get_is_genesis_started(), true);
+    assert_eq!(client.get_is_genesis_started(&0), true);
 
-    assert_eq!(client.get_current_epoch(), 1);
+    assert_eq!(client.get_current_epoch(&0), 1);
 
     // Advance ledger time to after lock time
     let current_time = env.ledger().timestamp();
@@ -182,11 +275,11 @@ fn test_genesis_round_flow() {
     env.ledger().set_timestamp(lock_time);
 
     // Lock the round
-    client.genesis_lock_round();
+    client.genesis_lock_round(&0);
 
-    assert_eq!(client.get_is_genesis_locked(), true);
-    assert_eq!(client.get_current_epoch(), 2);
-    assert_eq!(client.get_round(&1u128).lock_timestamp, lock_time);
+    assert_eq!(client.get_is_genesis_locked(&0), true);
+    assert_eq!(client.get_current_epoch(&0), 2);
+    assert_eq!(client.get_round(&0, &1u128).lock_timestamp, lock_time);
 }
 
 #[test]
@@ -205,17 +298,17 @@ fn test_bet_with_exact_minimum() {
     let (_, _, token_id, client, contract_id) = init_test(&env);
 
     // Setup genesis rounds
-    client.genesis_start_round();
-    let round = client.get_round(&1);
+    client.genesis_start_round(&0);
+    let round = client.get_round(&0, &1);
     env.ledger().set_timestamp(round.lock_timestamp);
-    client.genesis_lock_round();
+    client.genesis_lock_round(&0);
 
     // get current epoch
-    let current_epoch = client.get_current_epoch();
+    let current_epoch = client.get_current_epoch(&0);
     assert_eq!(current_epoch, 2);
 
     // Get round info
-    let round = client.get_round(&2);
+    let round = client.get_round(&0, &2);
 
     log!(&env, "Round: {:?}", round);
 
@@ -232,8 +325,2494 @@ fn test_bet_with_exact_minimum() {
     token_client.approve(&user, &contract_id, &100000000, &99999);
 
     // Place bet with exact minimum amount - should succeed
-    client.bet_bull(&current_epoch, &user, &DEFAULT_MIN_BET_AMOUNT);
+    client.bet_bull(&0, &current_epoch, &user, &DEFAULT_MIN_BET_AMOUNT);
+
+    let bet_info = client.get_bet_info(&0, &current_epoch, &user);
+    assert_eq!(bet_info.amount, DEFAULT_MIN_BET_AMOUNT);
+}
+
+#[test]
+fn test_vault_deposit_and_withdraw() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let lp = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&lp, &1_000_000_000);
+
+    let shares = client.vault_deposit(&lp, &1_000_000_000);
+
+    assert_eq!(shares, 1_000_000_000);
+    assert_eq!(client.get_vault_total_assets(), 1_000_000_000);
+    assert_eq!(client.get_vault_total_shares(), 1_000_000_000);
+    assert_eq!(client.get_vault_position(&lp).shares, 1_000_000_000);
+
+    // Advance ledger time past the cooldown
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + client.get_vault_cooldown_seconds());
+
+    let amount = client.vault_withdraw(&lp, &shares);
+
+    assert_eq!(amount, 1_000_000_000);
+    assert_eq!(client.get_vault_total_assets(), 0);
+    assert_eq!(client.get_vault_total_shares(), 0);
+    assert_eq!(token_client.balance(&lp), 1_000_000_000);
+}
+
+#[test]
+#[should_panic(expected = "VAULT_COOLDOWN_NOT_ELAPSED")]
+fn test_vault_withdraw_before_cooldown() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let lp = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&lp, &1_000_000_000);
+
+    let shares = client.vault_deposit(&lp, &1_000_000_000);
+
+    client.vault_withdraw(&lp, &shares); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "VAULT_INSOLVENT")]
+fn test_vault_deposit_after_vault_drained() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let lp = Address::generate(&env);
+    let new_lp = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&lp, &1_000_000_000);
+    token_client.mint(&new_lp, &1_000_000_000);
+    token_client.mint(&user, &1_000_000_000);
+
+    client.vault_deposit(&lp, &1_000_000_000);
+
+    client.set_house_limits(&2_000_000_000, &1_000_000_000);
+    client.set_house_odds(&0, &20_000, &20_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_fixed_odds(
+        &0,
+        &current_epoch,
+        &user,
+        &Position::Bull,
+        &1_000_000_000,
+        &20_000,
+    );
+
+    // The winning bet takes every asset of the vault
+    execute_round_at_price(&env, &client, &oracle_id, 1000);
+    execute_round_at_price(&env, &client, &oracle_id, 1100);
+
+    assert_eq!(client.get_vault_total_assets(), 0);
+
+    client.vault_deposit(&new_lp, &1_000_000_000); // Should panic
+}
+
+#[test]
+fn test_flash_loan_fee_accrues_to_vault() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let lp = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&lp, &1_000_000_000);
+    client.vault_deposit(&lp, &1_000_000_000);
+
+    // Receiver holds enough tokens to pay the fee
+    let receiver_id = env.register(FlashLoanRepayer, ());
+    token_client.mint(&receiver_id, &10_000_000);
+
+    let initiator = Address::generate(&env);
+
+    client.flash_loan(&initiator, &1_000_000_000, &receiver_id);
+
+    assert!(env.auths().iter().any(|(address, _)| *address == initiator));
+
+    let fee_amount = (1_000_000_000 * DEFAULT_FLASH_LOAN_FEE as i128) / 10_000;
+
+    assert_eq!(client.get_vault_total_assets(), 1_000_000_000 + fee_amount);
+    assert_eq!(client.get_flash_treasury_amount(), 0);
+
+    // LP redeems the fee along with the deposit
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + client.get_vault_cooldown_seconds());
+
+    let amount = client.vault_withdraw(&lp, &1_000_000_000);
+
+    assert_eq!(amount, 1_000_000_000 + fee_amount);
+}
+
+#[test]
+#[should_panic(expected = "INSUFFICIENT_VAULT_LIQUIDITY")]
+fn test_flash_loan_exceeds_vault_liquidity() {
+    let env = Env::default();
+    let (_, _, _, client, _) = init_test(&env);
+
+    let receiver_id = env.register(FlashLoanRepayer, ());
+
+    client.flash_loan(&Address::generate(&env), &1, &receiver_id); // Should panic
+}
+
+#[test]
+fn test_flash_loan_by_blocked_initiator() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let lp = Address::generate(&env);
+    let initiator = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&lp, &1_000_000_000);
+    client.vault_deposit(&lp, &1_000_000_000);
+
+    let receiver_id = env.register(FlashLoanRepayer, ());
+    token_client.mint(&receiver_id, &10_000_000);
+
+    client.set_access_mode(&AccessMode::Blocklist);
+    client.set_blocked(&initiator, &true);
+
+    // A blocked initiator cannot borrow through an allowed receiver
+    assert_eq!(
+        client.try_flash_loan(&initiator, &1_000_000_000, &receiver_id),
+        Err(Ok(Error::AccessDenied.into()))
+    );
+}
+
+#[test]
+fn test_bet_with_allowlisted_token() {
+    let env = Env::default();
+    let (admin, oracle_id, token_id, client, _) = init_test(&env);
+
+    let usdc_id = deploy_xlm_token(&env, &admin);
+    let usdc_client = MyTokenClient::new(&env, &usdc_id);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    client.add_token(&usdc_id, &1_000_000, &20_000_000);
+
+    assert_eq!(client.get_allowed_tokens().len(), 1);
+    assert_eq!(client.get_token_min_bet_amount(&usdc_id), 1_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let xlm_user = Address::generate(&env);
+
+    usdc_client.mint(&bull_user, &3_000_000);
+    usdc_client.mint(&bear_user, &1_000_000);
+    token_client.mint(&xlm_user, &DEFAULT_MIN_BET_AMOUNT);
+
+    client.bet_bull_with_token(&0, &current_epoch, &bull_user, &usdc_id, &3_000_000);
+    client.bet_bear_with_token(&0, &current_epoch, &bear_user, &usdc_id, &1_000_000);
+    client.bet_bear(&0, &current_epoch, &xlm_user, &DEFAULT_MIN_BET_AMOUNT);
+
+    assert_eq!(
+        client.get_bet_info(&0, &current_epoch, &bull_user).token,
+        usdc_id
+    );
+
+    // Pools are tracked separately per token
+    let usdc_pool = client.get_round_token_pool(&0, &current_epoch, &usdc_id);
+    assert_eq!(usdc_pool.total_amount, 4_000_000);
+    assert_eq!(usdc_pool.bull_amount, 3_000_000);
+    assert_eq!(usdc_pool.bear_amount, 1_000_000);
+
+    let round = client.get_round(&0, &current_epoch);
+    assert_eq!(round.total_amount, DEFAULT_MIN_BET_AMOUNT);
+    assert_eq!(round.bear_amount, DEFAULT_MIN_BET_AMOUNT);
+
+    // Lock the round then close it with a higher price
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    execute_round_at_price(&env, &client, &oracle_id, 110);
+
+    let usdc_pool = client.get_round_token_pool(&0, &current_epoch, &usdc_id);
+    let usdc_treasury_amt = (4_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    assert_eq!(usdc_pool.reward_base_cal_amount, 3_000_000);
+    assert_eq!(usdc_pool.reward_amount, 4_000_000 - usdc_treasury_amt);
+    assert_eq!(
+        client.get_token_treasury_amount(&usdc_id),
+        usdc_treasury_amt
+    );
+
+    // Bull won with no XLM on the bull side
+    let round = client.get_round(&0, &current_epoch);
+    assert_eq!(round.reward_base_cal_amount, 0);
+}
+
+#[test]
+#[should_panic(expected = "TOKEN_NOT_ALLOWED")]
+fn test_bet_with_token_not_allowed() {
+    let env = Env::default();
+    let (admin, _, _, client, _) = init_test(&env);
+
+    let other_token_id = deploy_xlm_token(&env, &admin);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    let user = Address::generate(&env);
+
+    client.bet_bull_with_token(&0, &current_epoch, &user, &other_token_id, &1_000_000);
+    // Should panic
+}
+
+#[test]
+fn test_deposit_and_withdraw_balance() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+
+    client.deposit(&user, &token_id, &100_000_000);
+
+    assert_eq!(client.get_balance(&user, &token_id), 100_000_000);
+    assert_eq!(token_client.balance(&user), 0);
+
+    client.withdraw(&user, &token_id, &40_000_000);
+
+    assert_eq!(client.get_balance(&user, &token_id), 60_000_000);
+    assert_eq!(token_client.balance(&user), 40_000_000);
+}
+
+#[test]
+fn test_bet_from_balance_and_claim_to_balance() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&bull_user, &100_000_000);
+    token_client.mint(&bear_user, &100_000_000);
+
+    client.deposit(&bull_user, &token_id, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull_from_balance(&0, &current_epoch, &bull_user, &token_id, &100_000_000);
+    client.bet_bear(&0, &current_epoch, &bear_user, &100_000_000);
+
+    assert_eq!(client.get_balance(&bull_user, &token_id), 0);
+    assert_eq!(
+        client.get_round(&0, &current_epoch).bull_amount,
+        100_000_000
+    );
+
+    // Lock the round then close it with a higher price
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    execute_round_at_price(&env, &client, &oracle_id, 110);
+
+    assert!(client.claimable(&0, &current_epoch, &bull_user));
+    assert!(!client.claimable(&0, &current_epoch, &bear_user));
+
+    client.claim_to_balance(&bull_user, &0, &Vec::from_array(&env, [current_epoch]));
+
+    let treasury_amt = (200_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    assert_eq!(
+        client.get_balance(&bull_user, &token_id),
+        200_000_000 - treasury_amt
+    );
+    assert!(client.get_bet_info(&0, &current_epoch, &bull_user).claimed);
+}
+
+#[test]
+fn test_claim_winnings() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&bull_user, &100_000_000);
+    token_client.mint(&bear_user, &300_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &bull_user, &100_000_000);
+    client.bet_bear(&0, &current_epoch, &bear_user, &300_000_000);
+
+    // Lock the round then close it with a lower price
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    execute_round_at_price(&env, &client, &oracle_id, 90);
+
+    client.claim(&bear_user, &0, &Vec::from_array(&env, [current_epoch]));
+
+    let treasury_amt = (400_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    assert_eq!(token_client.balance(&bear_user), 400_000_000 - treasury_amt);
+    assert_eq!(client.get_treasury_amount(), treasury_amt);
+}
+
+#[test]
+fn test_refund_unsettled_round() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &user, &100_000_000);
 
-    let bet_info = client.get_bet_info(&current_epoch, &user);
+    assert!(!client.refundable(&0, &current_epoch, &user));
+
+    // The round is never executed and the buffer expires
+    let round = client.get_round(&0, &current_epoch);
+    env.ledger()
+        .set_timestamp(round.close_timestamp + DEFAULT_BUFFER_SECONDS + 1);
+
+    assert!(client.refundable(&0, &current_epoch, &user));
+
+    client.claim(&user, &0, &Vec::from_array(&env, [current_epoch]));
+
+    assert_eq!(token_client.balance(&user), 100_000_000);
+}
+
+#[test]
+#[should_panic(expected = "ALREADY_CLAIMED")]
+fn test_claim_twice() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &user, &100_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    execute_round_at_price(&env, &client, &oracle_id, 110);
+
+    let epochs = Vec::from_array(&env, [current_epoch]);
+
+    client.claim(&user, &0, &epochs);
+    client.claim(&user, &0, &epochs); // Should panic
+}
+
+#[test]
+fn test_bet_from_allowance_by_relayer() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+    token_client.approve(&user, &relayer, &100_000_000, &99999);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull_from(&relayer, &user, &0, &current_epoch, &DEFAULT_MIN_BET_AMOUNT);
+
+    let bet_info = client.get_bet_info(&0, &current_epoch, &user);
+    assert_eq!(bet_info.position, Position::Bull);
     assert_eq!(bet_info.amount, DEFAULT_MIN_BET_AMOUNT);
+
+    assert_eq!(
+        token_client.allowance(&user, &relayer),
+        100_000_000 - DEFAULT_MIN_BET_AMOUNT
+    );
+    assert_eq!(
+        token_client.balance(&user),
+        100_000_000 - DEFAULT_MIN_BET_AMOUNT
+    );
+}
+
+#[test]
+fn test_bet_from_allowance_to_contract() {
+    let env = Env::default();
+    let (_, _, token_id, client, contract_id) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+    token_client.approve(&user, &contract_id, &100_000_000, &99999);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bear_from(
+        &contract_id,
+        &user,
+        &0,
+        &current_epoch,
+        &DEFAULT_MIN_BET_AMOUNT,
+    );
+
+    assert_eq!(
+        client.get_bet_info(&0, &current_epoch, &user).position,
+        Position::Bear
+    );
+    assert_eq!(
+        token_client.allowance(&user, &contract_id),
+        100_000_000 - DEFAULT_MIN_BET_AMOUNT
+    );
+}
+
+#[test]
+#[should_panic(expected = "INSUFFICIENT_ALLOWANCE")]
+fn test_bet_from_without_allowance() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull_from(&relayer, &user, &0, &current_epoch, &DEFAULT_MIN_BET_AMOUNT);
+    // Should panic
+}
+
+#[test]
+fn test_execute_bet_intent() {
+    let env = Env::default();
+    let (admin, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+
+    token_client.mint(&user, &100_000_000);
+    client.deposit(&user, &token_id, &100_000_000);
+
+    client.register_signing_key(
+        &user,
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+    );
+
+    assert_eq!(client.get_operator(), admin);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    let intent = BetIntent {
+        user: user.clone(),
+        series_id: 0,
+        epoch: current_epoch,
+        position: Position::Bear,
+        token: token_id.clone(),
+        amount: DEFAULT_MIN_BET_AMOUNT,
+        nonce: 0,
+        expiry: env.ledger().timestamp() + 60,
+        funding: IntentFunding::Balance,
+    };
+
+    let signature = sign_intent(&env, &client, &signing_key, &intent);
+
+    client.execute_bet_intent(&intent, &signature);
+
+    let bet_info = client.get_bet_info(&0, &current_epoch, &user);
+    assert_eq!(bet_info.position, Position::Bear);
+    assert_eq!(bet_info.amount, DEFAULT_MIN_BET_AMOUNT);
+
+    assert_eq!(client.get_intent_nonce(&user), 1);
+    assert_eq!(
+        client.get_balance(&user, &token_id),
+        100_000_000 - DEFAULT_MIN_BET_AMOUNT
+    );
+}
+
+#[test]
+#[should_panic(expected = "INVALID_NONCE")]
+fn test_execute_bet_intent_replay() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+
+    token_client.mint(&user, &100_000_000);
+    client.deposit(&user, &token_id, &100_000_000);
+
+    client.register_signing_key(
+        &user,
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+    );
+
+    let current_epoch = start_genesis(&env, &client);
+
+    let intent = BetIntent {
+        user: user.clone(),
+        series_id: 0,
+        epoch: current_epoch,
+        position: Position::Bull,
+        token: token_id.clone(),
+        amount: DEFAULT_MIN_BET_AMOUNT,
+        nonce: 0,
+        expiry: env.ledger().timestamp() + 60,
+        funding: IntentFunding::Balance,
+    };
+
+    let signature = sign_intent(&env, &client, &signing_key, &intent);
+
+    client.execute_bet_intent(&intent, &signature);
+    client.execute_bet_intent(&intent, &signature); // Should panic
+}
+
+#[test]
+#[should_panic]
+fn test_execute_bet_intent_with_wrong_signer() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let attacker_key = SigningKey::from_bytes(&[9u8; 32]);
+
+    client.register_signing_key(
+        &user,
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+    );
+
+    let current_epoch = start_genesis(&env, &client);
+
+    let intent = BetIntent {
+        user: user.clone(),
+        series_id: 0,
+        epoch: current_epoch,
+        position: Position::Bull,
+        token: token_id.clone(),
+        amount: DEFAULT_MIN_BET_AMOUNT,
+        nonce: 0,
+        expiry: env.ledger().timestamp() + 60,
+        funding: IntentFunding::Allowance,
+    };
+
+    let signature = sign_intent(&env, &client, &attacker_key, &intent);
+
+    client.execute_bet_intent(&intent, &signature); // Should panic
+}
+
+#[test]
+fn test_gift_bet_belongs_to_beneficiary() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let sponsor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&sponsor, &100_000_000);
+    token_client.mint(&bear_user, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull_for(&sponsor, &beneficiary, &0, &current_epoch, &100_000_000);
+
+    // The beneficiary does not need to sign a gift
+    assert!(!env
+        .auths()
+        .iter()
+        .any(|(address, _)| *address == beneficiary));
+
+    client.bet_bear(&0, &current_epoch, &bear_user, &100_000_000);
+
+    assert_eq!(token_client.balance(&sponsor), 0);
+    assert_eq!(
+        client.get_bet_info(&0, &current_epoch, &beneficiary).amount,
+        100_000_000
+    );
+    assert_eq!(client.get_user_rounds(&0, &sponsor).len(), 0);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    execute_round_at_price(&env, &client, &oracle_id, 110);
+
+    assert!(client.claimable(&0, &current_epoch, &beneficiary));
+
+    client.claim(&beneficiary, &0, &Vec::from_array(&env, [current_epoch]));
+
+    let treasury_amt = (200_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    assert_eq!(
+        token_client.balance(&beneficiary),
+        200_000_000 - treasury_amt
+    );
+    assert_eq!(token_client.balance(&sponsor), 0);
+}
+
+#[test]
+#[should_panic(expected = "PAYER_NOT_ALLOWED")]
+fn test_gift_bet_from_payer_not_allowed() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let sponsor = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&stranger, &100_000_000);
+
+    // Beneficiary opts in to gifts from its sponsor only
+    client.set_gift_payers(&beneficiary, &Vec::from_array(&env, [sponsor]));
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull_for(&stranger, &beneficiary, &0, &current_epoch, &100_000_000);
+    // Should panic
+}
+
+#[test]
+fn test_referral_fee_sharing() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let referrer = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    client.set_referral_fee_share(&2_000); // 20%
+    client.register_referrer(&user, &referrer);
+
+    assert_eq!(client.get_referrer(&user), Some(referrer.clone()));
+    assert_eq!(client.get_referral_stats(&referrer).referred_count, 1);
+
+    token_client.mint(&user, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &user, &100_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    execute_round_at_price(&env, &client, &oracle_id, 110);
+
+    let treasury_amt = (100_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+    let referral_amt = treasury_amt * 2_000 / 10_000;
+
+    let stats = client.get_referral_stats(&referrer);
+    assert_eq!(stats.claimable.get(token_id.clone()), Some(referral_amt));
+    assert_eq!(stats.total_earned.get(token_id.clone()), Some(referral_amt));
+    assert_eq!(client.get_treasury_amount(), treasury_amt - referral_amt);
+
+    client.claim_referral_rewards(&referrer);
+
+    assert_eq!(token_client.balance(&referrer), referral_amt);
+    assert_eq!(
+        client.get_referral_stats(&referrer).claimable.get(token_id),
+        None
+    );
+}
+
+#[test]
+fn test_referral_fee_follows_fee_curve() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let referrer = Address::generate(&env);
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    client.set_referral_fee_share(&2_000); // 20%
+    client.register_referrer(&bull_user, &referrer);
+
+    client.set_fee_curve(&Some(FeeCurve {
+        min_fee: 100,
+        max_fee: 1000,
+    }));
+
+    token_client.mint(&bull_user, &300_000_000);
+    token_client.mint(&bear_user, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &bull_user, &300_000_000);
+    client.bet_bear(&0, &current_epoch, &bear_user, &100_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 1000);
+    execute_round_at_price(&env, &client, &oracle_id, 1100);
+
+    // The referrer shares the fee the round settled with, not the flat fee
+    let round_fee = client.get_round(&0, &current_epoch).treasury_fee;
+
+    assert_eq!(round_fee, 550);
+
+    let referral_amt = (300_000_000 * round_fee as i128 / 10_000) * 2_000 / 10_000;
+
+    assert_eq!(
+        client.get_referral_stats(&referrer).claimable.get(token_id),
+        Some(referral_amt)
+    );
+}
+
+#[test]
+fn test_referrer_bound_on_first_bet() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let referrer = Address::generate(&env);
+    let other_referrer = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    client.set_referral_fee_share(&2_000); // 20%
+
+    token_client.mint(&user, &200_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull_referred(&0, &current_epoch, &user, &100_000_000, &referrer);
+
+    assert_eq!(client.get_referrer(&user), Some(referrer.clone()));
+    assert_eq!(client.get_referral_stats(&referrer).referred_count, 1);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+
+    // A later referral link does not replace the referrer
+    let next_epoch = client.get_current_epoch(&0);
+    let round = client.get_round(&0, &next_epoch);
+    env.ledger().set_timestamp(round.start_timestamp + 1);
+
+    client.bet_bear_referred(&0, &next_epoch, &user, &100_000_000, &other_referrer);
+
+    assert_eq!(client.get_referrer(&user), Some(referrer.clone()));
+    assert_eq!(client.get_referral_stats(&other_referrer).referred_count, 0);
+
+    execute_round_at_price(&env, &client, &oracle_id, 110);
+
+    // The referrer earns on the first bet
+    let treasury_amt = (100_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+    let referral_amt = treasury_amt * 2_000 / 10_000;
+
+    assert_eq!(
+        client.get_referral_stats(&referrer).claimable.get(token_id),
+        Some(referral_amt)
+    );
+}
+
+#[test]
+#[should_panic(expected = "REFERRER_AFTER_FIRST_BET")]
+fn test_register_referrer_after_first_bet() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let referrer = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &user, &100_000_000);
+
+    client.register_referrer(&user, &referrer); // Should panic
+}
+
+#[test]
+fn test_user_stats_and_leaderboard() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&bull_user, &200_000_000);
+    token_client.mint(&bear_user, &200_000_000);
+
+    let first_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &first_epoch, &bull_user, &100_000_000);
+    client.bet_bear(&0, &first_epoch, &bear_user, &100_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+
+    // Bet on the next round while the first one is locked
+    let second_epoch = client.get_current_epoch(&0);
+    env.ledger()
+        .set_timestamp(client.get_round(&0, &second_epoch).start_timestamp + 1);
+
+    client.bet_bull(&0, &second_epoch, &bull_user, &100_000_000);
+    client.bet_bear(&0, &second_epoch, &bear_user, &100_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 110);
+    execute_round_at_price(&env, &client, &oracle_id, 120);
+
+    client.claim(
+        &bull_user,
+        &0,
+        &Vec::from_array(&env, [first_epoch, second_epoch]),
+    );
+
+    let reward = 200_000_000 - (200_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    let stats = client.get_user_stats(&bull_user);
+    assert_eq!(stats.rounds_played, 2);
+    assert_eq!(stats.rounds_won, 2);
+    assert_eq!(stats.total_wagered, 200_000_000);
+    assert_eq!(stats.total_claimed, 2 * reward);
+    assert_eq!(stats.net_profit, 2 * reward - 200_000_000);
+    assert_eq!(stats.current_win_streak, 2);
+    assert_eq!(stats.best_win_streak, 2);
+
+    // Losses are settled without a claim
+    client.sync_user_stats(&bear_user);
+
+    let stats = client.get_user_stats(&bear_user);
+    assert_eq!(stats.rounds_played, 2);
+    assert_eq!(stats.rounds_won, 0);
+    assert_eq!(stats.total_claimed, 0);
+    assert_eq!(stats.net_profit, -200_000_000);
+
+    let leaderboard = client.get_leaderboard(&0);
+    assert_eq!(leaderboard.len(), 2);
+    assert_eq!(leaderboard.get(0).unwrap().user, bull_user);
+    assert_eq!(leaderboard.get(0).unwrap().score, 2 * reward - 200_000_000);
+    assert_eq!(leaderboard.get(1).unwrap().user, bear_user);
+    assert_eq!(leaderboard.get(1).unwrap().score, -200_000_000);
+}
+
+#[test]
+fn test_unclaimed_loss_resets_win_streak() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let other_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &300_000_000);
+    token_client.mint(&other_user, &300_000_000);
+
+    let first_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &first_epoch, &user, &100_000_000);
+    client.bet_bear(&0, &first_epoch, &other_user, &100_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+
+    let second_epoch = client.get_current_epoch(&0);
+    env.ledger()
+        .set_timestamp(client.get_round(&0, &second_epoch).start_timestamp + 1);
+
+    client.bet_bull(&0, &second_epoch, &user, &100_000_000);
+    client.bet_bear(&0, &second_epoch, &other_user, &100_000_000);
+
+    // The first round is won, the second one is lost, neither is claimed
+    execute_round_at_price(&env, &client, &oracle_id, 110);
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+
+    client.sync_user_stats(&user);
+
+    let stats = client.get_user_stats(&user);
+    assert_eq!(stats.rounds_won, 1);
+    assert_eq!(stats.current_win_streak, 0);
+    assert_eq!(stats.best_win_streak, 1);
+    assert_eq!(stats.total_claimed, 0);
+}
+
+#[test]
+fn test_season_prizes() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let sponsor = Address::generate(&env);
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    // Season covering the first bettable round only
+    let season_id = client.create_season(
+        &0,
+        &2,
+        &2,
+        &RankingMetric::NetProfit,
+        &Vec::from_array(&env, [7_000u32, 3_000u32]),
+    );
+
+    assert_eq!(season_id, 1);
+
+    token_client.mint(&sponsor, &1_000_000_000);
+    client.fund_season(&sponsor, &season_id, &1_000_000_000);
+
+    assert_eq!(client.get_season(&season_id).prize_pool, 1_000_000_000);
+
+    token_client.mint(&bull_user, &100_000_000);
+    token_client.mint(&bear_user, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    assert_eq!(client.get_season_id(&0, &current_epoch), season_id);
+
+    client.bet_bull(&0, &current_epoch, &bull_user, &100_000_000);
+    client.bet_bear(&0, &current_epoch, &bear_user, &100_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    execute_round_at_price(&env, &client, &oracle_id, 110);
+
+    client.sync_user_stats(&bull_user);
+    client.sync_user_stats(&bear_user);
+
+    // Finalization waits for the grace period after the last round
+    let last_round = client.get_round(&0, &current_epoch);
+    env.ledger()
+        .set_timestamp(last_round.close_timestamp + 86_400);
+
+    client.finalize_season(&season_id);
+
+    // The bear user lost money and does not fill the second slot
+    let season = client.get_season(&season_id);
+    assert_eq!(season.winners, Vec::from_array(&env, [bull_user.clone()]));
+
+    client.claim_season_prize(&season_id, &bull_user);
+
+    assert_eq!(token_client.balance(&bull_user), 700_000_000);
+
+    // The unallocated prize goes back to the treasury after the claim period
+    let treasury_amount = client.get_treasury_amount();
+
+    env.ledger()
+        .set_timestamp(season.finalized_timestamp + 2_592_000);
+
+    client.sweep_season(&season_id);
+
+    assert_eq!(client.get_treasury_amount(), treasury_amount + 300_000_000);
+}
+
+#[test]
+#[should_panic(expected = "SEASON_GRACE_PERIOD")]
+fn test_finalize_season_during_grace_period() {
+    let env = Env::default();
+    let (_, oracle_id, _, client, _) = init_test(&env);
+
+    let season_id = client.create_season(
+        &0,
+        &2,
+        &2,
+        &RankingMetric::WinCount,
+        &Vec::from_array(&env, [10_000u32]),
+    );
+
+    start_genesis(&env, &client);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    execute_round_at_price(&env, &client, &oracle_id, 110);
+
+    client.finalize_season(&season_id); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "SEASON_NOT_ENDED")]
+fn test_finalize_season_before_end() {
+    let env = Env::default();
+    let (_, _, _, client, _) = init_test(&env);
+
+    let season_id = client.create_season(
+        &0,
+        &2,
+        &10,
+        &RankingMetric::WinCount,
+        &Vec::from_array(&env, [10_000u32]),
+    );
+
+    start_genesis(&env, &client);
+
+    client.finalize_season(&season_id); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "SEASON_NOT_ENDED")]
+fn test_finalize_strike_season_with_open_round() {
+    let env = Env::default();
+    let (_, oracle_id, _, client, _) = init_test(&env);
+
+    let series_id = client.create_strike_series(&DEFAULT_BUFFER_SECONDS);
+
+    let season_id = client.create_season(
+        &series_id,
+        &1,
+        &2,
+        &RankingMetric::WinCount,
+        &Vec::from_array(&env, [10_000u32]),
+    );
+
+    let now = env.ledger().timestamp();
+
+    // The first round of the season expires long after the second one
+    client.create_strike_round(
+        &series_id,
+        &normalize_price(100),
+        &(now + 600),
+        &(now + 30 * 86_400),
+    );
+    let epoch = client.create_strike_round(
+        &series_id,
+        &normalize_price(100),
+        &(now + 600),
+        &(now + 3_600),
+    );
+
+    env.ledger().set_timestamp(now + 3_600);
+
+    let reflect_client = reflector_oracle::Client::new(&env, &oracle_id);
+    let updates = get_updates(&env, &generate_asset(&env), normalize_price(110));
+    reflect_client.set_price(&updates, &((now + 3_600) * 1000));
+
+    client.settle_strike_round(&series_id, &epoch);
+
+    env.ledger().set_timestamp(now + 3_600 + 2 * 86_400);
+
+    client.finalize_season(&season_id); // Should panic
+}
+
+#[test]
+fn test_jackpot_draw() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    client.set_jackpot_fee_share(&5_000); // 50%
+    client.set_jackpot_interval(&2);
+
+    token_client.mint(&bull_user, &300_000_000);
+    token_client.mint(&bear_user, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &bull_user, &300_000_000);
+    client.bet_bear(&0, &current_epoch, &bear_user, &100_000_000);
+
+    let period = (current_epoch - 1) / 2;
+
+    assert_eq!(client.get_jackpot_round_entries(&current_epoch).len(), 2);
+    assert_eq!(client.get_jackpot_entries(&period).len(), 0);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    execute_round_at_price(&env, &client, &oracle_id, 110);
+
+    let treasury_amt = (400_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+    let jackpot_amt = treasury_amt / 2;
+
+    // Epoch 2 closes the period, so the jackpot is drawn and paid out
+    assert_eq!(client.get_jackpot_amount(), 0);
+    assert_eq!(client.get_jackpot_entries(&period).len(), 0);
+    assert_eq!(client.get_treasury_amount(), treasury_amt - jackpot_amt);
+
+    let bull_balance = client.get_balance(&bull_user, &token_id);
+    let bear_balance = client.get_balance(&bear_user, &token_id);
+
+    // The testutils PRNG is seeded deterministically, the bull user wins the draw
+    assert_eq!(bull_balance, jackpot_amt);
+    assert_eq!(bear_balance, 0);
+}
+
+#[test]
+fn test_refunded_bet_leaves_jackpot() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    client.set_jackpot_fee_share(&5_000); // 50%
+
+    token_client.mint(&user, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &user, &100_000_000);
+
+    assert_eq!(client.get_jackpot_round_entries(&current_epoch).len(), 1);
+
+    // The round is never executed and the buffer expires
+    let round = client.get_round(&0, &current_epoch);
+    env.ledger()
+        .set_timestamp(round.close_timestamp + DEFAULT_BUFFER_SECONDS + 1);
+
+    client.claim(&user, &0, &Vec::from_array(&env, [current_epoch]));
+
+    assert_eq!(client.get_jackpot_round_entries(&current_epoch).len(), 0);
+    assert_eq!(client.get_jackpot_entries(&0).len(), 0);
+}
+
+#[test]
+fn test_claim_and_bet() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&bull_user, &100_000_000);
+    token_client.mint(&bear_user, &300_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &bull_user, &100_000_000);
+    client.bet_bear(&0, &current_epoch, &bear_user, &300_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    execute_round_at_price(&env, &client, &oracle_id, 90);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+
+    let next_epoch = client.get_current_epoch(&0);
+
+    client.claim_and_bet(
+        &bear_user,
+        &0,
+        &Vec::from_array(&env, [current_epoch]),
+        &Position::Bull,
+        &Some(100_000_000),
+    );
+
+    let treasury_amt = (400_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    // No tokens leave the contract, the rest of the winnings stays in the balance
+    assert_eq!(token_client.balance(&bear_user), 0);
+    assert_eq!(
+        client.get_balance(&bear_user, &token_id),
+        300_000_000 - treasury_amt
+    );
+
+    let bet_info = client.get_bet_info(&0, &next_epoch, &bear_user);
+
+    assert_eq!(bet_info.amount, 100_000_000);
+    assert!(client.get_bet_info(&0, &current_epoch, &bear_user).claimed);
+}
+
+#[test]
+#[should_panic(expected = "INVALID_AMOUNT")]
+fn test_claim_and_bet_negative_amount() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&bull_user, &100_000_000);
+    token_client.mint(&bear_user, &300_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &bull_user, &100_000_000);
+    client.bet_bear(&0, &current_epoch, &bear_user, &300_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    execute_round_at_price(&env, &client, &oracle_id, 90);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+
+    client.claim_and_bet(
+        &bear_user,
+        &0,
+        &Vec::from_array(&env, [current_epoch]),
+        &Position::Bull,
+        &Some(-100_000_000),
+    ); // Should panic
+}
+
+#[test]
+fn test_standing_order() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &300_000_000);
+
+    client.create_standing_order(&user, &0, &Position::Bull, &100_000_000, &3);
+
+    assert_eq!(token_client.balance(&user), 0);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    assert_eq!(client.execute_standing_orders(&0), 1);
+    // Bets are placed once per round
+    assert_eq!(client.execute_standing_orders(&0), 0);
+
+    execute_round_at_price(&env, &client, &oracle_id, 100);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+
+    assert_eq!(client.execute_standing_orders(&0), 1);
+
+    assert_eq!(
+        client.get_bet_info(&0, &current_epoch, &user).position,
+        Position::Bull
+    );
+    assert_eq!(
+        client.get_bet_info(&0, &(current_epoch + 1), &user).amount,
+        100_000_000
+    );
+    assert_eq!(
+        client.get_standing_order(&user),
+        Some(StandingOrder {
+            series_id: 0,
+            position: Position::Bull,
+            amount: 100_000_000,
+            remaining_rounds: 1,
+            last_epoch: current_epoch + 1,
+        })
+    );
+
+    // Cancelling refunds the stake of the remaining round
+    client.cancel_standing_order(&user);
+
+    assert_eq!(token_client.balance(&user), 100_000_000);
+    assert_eq!(client.get_standing_order(&user), None);
+    assert_eq!(client.get_standing_order_users(&0).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "INVALID_POSITION")]
+fn test_standing_order_with_invalid_position() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+
+    let series_id = client.create_bucket_series(
+        &DEFAULT_INTERVAL_SECONDS,
+        &DEFAULT_BUFFER_SECONDS,
+        &Vec::from_array(&env, [-100, 0, 100]),
+    );
+
+    client.create_standing_order(&user, &series_id, &Position::Bull, &100_000_000, &1);
+    // Should panic
+}
+
+#[test]
+#[should_panic(expected = "STANDING_ORDER_ESCROW_TOO_LOW")]
+fn test_standing_order_below_min_escrow() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &300_000_000);
+
+    client.set_standing_order_min_escrow(&500_000_000);
+
+    assert_eq!(client.get_standing_order_min_escrow(), 500_000_000);
+
+    client.create_standing_order(&user, &0, &Position::Bull, &100_000_000, &3); // Should panic
+}
+
+#[test]
+fn test_concurrent_series() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &200_000_000);
+
+    let series_id = client.create_series(&60, &10);
+
+    assert_eq!(series_id, 1);
+    assert_eq!(client.get_series_count(), 2);
+    assert_eq!(client.get_interval_seconds(&series_id), 60);
+    assert_eq!(client.get_buffer_seconds(&series_id), 10);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    // The new series keeps its own epoch counter and timeframe
+    client.genesis_start_round(&series_id);
+
+    let round = client.get_round(&series_id, &1);
+
+    assert_eq!(client.get_current_epoch(&series_id), 1);
+    assert_eq!(round.lock_timestamp, round.start_timestamp + 60);
+    assert_eq!(client.get_current_epoch(&0), current_epoch);
+
+    env.ledger().set_timestamp(round.start_timestamp + 1);
+
+    client.bet_bull(&0, &current_epoch, &user, &100_000_000);
+    client.bet_bear(&series_id, &1, &user, &100_000_000);
+
+    assert_eq!(
+        client.get_bet_info(&0, &current_epoch, &user).position,
+        Position::Bull
+    );
+    assert_eq!(
+        client.get_bet_info(&series_id, &1, &user).position,
+        Position::Bear
+    );
+    assert_eq!(
+        client.get_user_rounds(&0, &user),
+        Vec::from_array(&env, [current_epoch])
+    );
+    assert_eq!(
+        client.get_user_rounds(&series_id, &user),
+        Vec::from_array(&env, [1])
+    );
+    assert_eq!(
+        client.get_round(&0, &current_epoch).bull_amount,
+        100_000_000
+    );
+    assert_eq!(client.get_round(&series_id, &1).bear_amount, 100_000_000);
+}
+
+#[test]
+fn test_bucket_series() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let winner = Address::generate(&env);
+    let loser = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&winner, &100_000_000);
+    token_client.mint(&loser, &300_000_000);
+
+    // Down more than 1%, down 0 to 1%, up 0 to 1%, up more than 1%
+    let series_id = client.create_bucket_series(
+        &DEFAULT_INTERVAL_SECONDS,
+        &DEFAULT_BUFFER_SECONDS,
+        &Vec::from_array(&env, [-100, 0, 100]),
+    );
+
+    let current_epoch = start_series_genesis(&env, &client, series_id);
+
+    client.bet_bucket(&series_id, &current_epoch, &winner, &2, &100_000_000);
+    client.bet_bucket(&series_id, &current_epoch, &loser, &3, &300_000_000);
+
+    assert_eq!(
+        client.get_round(&series_id, &current_epoch).bucket_amounts,
+        Vec::from_array(&env, [0, 0, 100_000_000, 300_000_000])
+    );
+
+    // Up 0.5% from lock to close
+    execute_series_round_at_price(&env, &client, &oracle_id, series_id, 1000);
+    execute_series_round_at_price(&env, &client, &oracle_id, series_id, 1005);
+
+    let round = client.get_round(&series_id, &current_epoch);
+
+    assert_eq!(round.winning_bucket, Some(2));
+    assert!(client.claimable(&series_id, &current_epoch, &winner));
+    assert!(!client.claimable(&series_id, &current_epoch, &loser));
+
+    client.claim(&winner, &series_id, &Vec::from_array(&env, [current_epoch]));
+
+    let treasury_amt = (400_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    assert_eq!(token_client.balance(&winner), 400_000_000 - treasury_amt);
+}
+
+#[test]
+fn test_bucket_round_without_winner_bets() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+
+    let series_id = client.create_bucket_series(
+        &DEFAULT_INTERVAL_SECONDS,
+        &DEFAULT_BUFFER_SECONDS,
+        &Vec::from_array(&env, [-100, 0, 100]),
+    );
+
+    let current_epoch = start_series_genesis(&env, &client, series_id);
+
+    client.bet_bucket(&series_id, &current_epoch, &user, &3, &100_000_000);
+
+    let treasury_amount = client.get_treasury_amount();
+
+    // Up 0.5% lands in the empty bucket 2
+    execute_series_round_at_price(&env, &client, &oracle_id, series_id, 1000);
+    execute_series_round_at_price(&env, &client, &oracle_id, series_id, 1005);
+
+    let round = client.get_round(&series_id, &current_epoch);
+
+    // Nobody can claim the pool, so it all goes to the treasury
+    assert_eq!(round.winning_bucket, Some(2));
+    assert_eq!(round.reward_amount, 0);
+    assert_eq!(client.get_treasury_amount(), treasury_amount + 100_000_000);
+}
+
+#[test]
+#[should_panic(expected = "INVALID_POSITION")]
+fn test_bull_bet_on_bucket_series() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+
+    let series_id = client.create_bucket_series(
+        &DEFAULT_INTERVAL_SECONDS,
+        &DEFAULT_BUFFER_SECONDS,
+        &Vec::from_array(&env, [0]),
+    );
+
+    let current_epoch = start_series_genesis(&env, &client, series_id);
+
+    client.bet_bull(&series_id, &current_epoch, &user, &100_000_000); // Should panic
+}
+
+#[test]
+fn test_strike_round() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&bull_user, &100_000_000);
+    token_client.mint(&bear_user, &300_000_000);
+
+    // Settlement can wait until the oracle has published a newer price
+    let oracle_resolution_seconds = REFLECTOR_RESOLUTION as u64 / 1000;
+    let series_id = client.create_strike_series(&oracle_resolution_seconds);
+
+    let now = env.ledger().timestamp();
+    let lock_timestamp = now + 600;
+    let expiry_timestamp = now + 3_600;
+
+    // Two strike rounds can be open side by side
+    let epoch = client.create_strike_round(
+        &series_id,
+        &normalize_price(1000),
+        &lock_timestamp,
+        &expiry_timestamp,
+    );
+    let other_epoch = client.create_strike_round(
+        &series_id,
+        &normalize_price(900),
+        &lock_timestamp,
+        &expiry_timestamp,
+    );
+
+    env.ledger().set_timestamp(now + 1);
+
+    client.bet_bull(&series_id, &epoch, &bull_user, &100_000_000);
+    client.bet_bear(&series_id, &epoch, &bear_user, &100_000_000);
+    client.bet_bear(&series_id, &other_epoch, &bear_user, &200_000_000);
+
+    // Price closes above the strike of the first round
+    env.ledger().set_timestamp(expiry_timestamp);
+
+    let reflect_client = reflector_oracle::Client::new(&env, &oracle_id);
+    let updates = get_updates(&env, &generate_asset(&env), normalize_price(1010));
+    reflect_client.set_price(&updates, &(expiry_timestamp * 1000));
+
+    // A later price below the strike does not change the outcome
+    let settle_timestamp = expiry_timestamp + oracle_resolution_seconds;
+    env.ledger().set_timestamp(settle_timestamp);
+    let updates = get_updates(&env, &generate_asset(&env), normalize_price(990));
+    reflect_client.set_price(&updates, &(settle_timestamp * 1000));
+
+    client.settle_strike_round(&series_id, &epoch);
+
+    assert_eq!(
+        client.get_round(&series_id, &epoch).close_price,
+        normalize_price(1010)
+    );
+
+    assert!(client.claimable(&series_id, &epoch, &bull_user));
+    assert!(!client.claimable(&series_id, &epoch, &bear_user));
+
+    client.claim(&bull_user, &series_id, &Vec::from_array(&env, [epoch]));
+
+    let treasury_amt = (200_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    assert_eq!(token_client.balance(&bull_user), 200_000_000 - treasury_amt);
+}
+
+#[test]
+#[should_panic(expected = "ROUND_ALREADY_ENDED")]
+fn test_settle_strike_round_twice() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&bear_user, &100_000_000);
+
+    let oracle_resolution_seconds = REFLECTOR_RESOLUTION as u64 / 1000;
+    let series_id = client.create_strike_series(&oracle_resolution_seconds);
+
+    let now = env.ledger().timestamp();
+    let expiry_timestamp = now + 3_600;
+
+    let epoch = client.create_strike_round(
+        &series_id,
+        &normalize_price(1000),
+        &(now + 600),
+        &expiry_timestamp,
+    );
+
+    env.ledger().set_timestamp(now + 1);
+
+    client.bet_bear(&series_id, &epoch, &bear_user, &100_000_000);
+
+    // Price closes above the strike, so the round has no winner
+    env.ledger().set_timestamp(expiry_timestamp);
+
+    let reflect_client = reflector_oracle::Client::new(&env, &oracle_id);
+    let updates = get_updates(&env, &generate_asset(&env), normalize_price(1010));
+    reflect_client.set_price(&updates, &(expiry_timestamp * 1000));
+
+    client.settle_strike_round(&series_id, &epoch);
+
+    assert_eq!(client.get_treasury_amount(), 100_000_000);
+
+    client.settle_strike_round(&series_id, &epoch); // Should panic
+}
+
+#[test]
+fn test_head_to_head_series() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let xlm_user = Address::generate(&env);
+    let btc_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&xlm_user, &100_000_000);
+    token_client.mint(&btc_user, &300_000_000);
+
+    let xlm = Symbol::new(&env, "XLM");
+    let btc = Symbol::new(&env, "BTC");
+
+    let series_id = client.create_head_to_head_series(
+        &DEFAULT_INTERVAL_SECONDS,
+        &DEFAULT_BUFFER_SECONDS,
+        &xlm,
+        &btc,
+    );
+
+    assert_eq!(
+        client.get_series_assets(&series_id),
+        Vec::from_array(&env, [xlm.clone(), btc.clone()])
+    );
+
+    let current_epoch = start_series_genesis(&env, &client, series_id);
+
+    client.bet_asset(&series_id, &current_epoch, &xlm_user, &xlm, &100_000_000);
+    client.bet_asset(&series_id, &current_epoch, &btc_user, &btc, &300_000_000);
+
+    // XLM up 10%, BTC up 5%
+    execute_series_round_at_prices(&env, &client, &oracle_id, series_id, [100, 60_000]);
+    execute_series_round_at_prices(&env, &client, &oracle_id, series_id, [110, 63_000]);
+
+    let round = client.get_round(&series_id, &current_epoch);
+
+    assert_eq!(round.lock_price, normalize_price(100));
+    assert_eq!(round.rival_lock_price, normalize_price(60_000));
+    assert_eq!(round.close_price, normalize_price(110));
+    assert_eq!(round.rival_close_price, normalize_price(63_000));
+
+    assert!(client.claimable(&series_id, &current_epoch, &xlm_user));
+    assert!(!client.claimable(&series_id, &current_epoch, &btc_user));
+
+    client.claim(
+        &xlm_user,
+        &series_id,
+        &Vec::from_array(&env, [current_epoch]),
+    );
+
+    let treasury_amt = (400_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    assert_eq!(token_client.balance(&xlm_user), 400_000_000 - treasury_amt);
+}
+
+#[test]
+#[should_panic(expected = "INVALID_POSITION")]
+fn test_bet_unknown_asset_on_head_to_head_series() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+
+    let series_id = client.create_head_to_head_series(
+        &DEFAULT_INTERVAL_SECONDS,
+        &DEFAULT_BUFFER_SECONDS,
+        &Symbol::new(&env, "XLM"),
+        &Symbol::new(&env, "BTC"),
+    );
+
+    let current_epoch = start_series_genesis(&env, &client, series_id);
+
+    client.bet_asset(
+        &series_id,
+        &current_epoch,
+        &user,
+        &Symbol::new(&env, "ETH"),
+        &100_000_000,
+    );
+}
+
+#[test]
+fn test_volatility_series() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let big_user = Address::generate(&env);
+    let small_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&big_user, &100_000_000);
+    token_client.mint(&small_user, &300_000_000);
+
+    // Big move above 1% in either direction
+    let series_id =
+        client.create_volatility_series(&DEFAULT_INTERVAL_SECONDS, &DEFAULT_BUFFER_SECONDS, &100);
+
+    assert_eq!(client.get_move_threshold(&series_id), 100);
+    assert_eq!(client.get_move_threshold(&0), 0);
+
+    let current_epoch = start_series_genesis(&env, &client, series_id);
+
+    client.bet_big_move(&series_id, &current_epoch, &big_user, &100_000_000);
+    client.bet_small_move(&series_id, &current_epoch, &small_user, &300_000_000);
+
+    let round = client.get_round(&series_id, &current_epoch);
+
+    assert_eq!(round.move_threshold, 100);
+    assert_eq!(round.bull_amount, 100_000_000);
+    assert_eq!(round.bear_amount, 300_000_000);
+
+    // Down 1.5% from lock to close
+    execute_series_round_at_price(&env, &client, &oracle_id, series_id, 1000);
+    execute_series_round_at_price(&env, &client, &oracle_id, series_id, 985);
+
+    assert!(client.claimable(&series_id, &current_epoch, &big_user));
+    assert!(!client.claimable(&series_id, &current_epoch, &small_user));
+
+    client.claim(
+        &big_user,
+        &series_id,
+        &Vec::from_array(&env, [current_epoch]),
+    );
+
+    let treasury_amt = (400_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    assert_eq!(token_client.balance(&big_user), 400_000_000 - treasury_amt);
+}
+
+/// Creates an event series and opens a round betting until 100 seconds from now, closing 100 seconds later
+fn create_event_round(
+    env: &Env,
+    client: &PredictionMarketClient,
+    resolver: &Address,
+) -> (u32, u128) {
+    let series_id = client.create_event_series(&1_000, &100);
+
+    let now = env.ledger().timestamp();
+
+    let epoch = client.create_event_round(
+        &series_id,
+        &String::from_str(env, "Will protocol X launch by date Y?"),
+        resolver,
+        &(now + 100),
+        &(now + 200),
+    );
+
+    env.ledger().set_timestamp(now + 1);
+
+    (series_id, epoch)
+}
+
+#[test]
+fn test_event_round() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let resolver = Address::generate(&env);
+    let yes_user = Address::generate(&env);
+    let no_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&yes_user, &100_000_000);
+    token_client.mint(&no_user, &300_000_000);
+
+    let (series_id, epoch) = create_event_round(&env, &client, &resolver);
+
+    assert!(client.is_event_series(&series_id));
+    assert_eq!(client.get_event_info(&series_id, &epoch).resolver, resolver);
+
+    client.bet_yes(&series_id, &epoch, &yes_user, &100_000_000);
+    client.bet_no(&series_id, &epoch, &no_user, &300_000_000);
+
+    let round = client.get_round(&series_id, &epoch);
+
+    // Resolver reports no, the owner overrides it during the dispute window
+    env.ledger().set_timestamp(round.close_timestamp);
+
+    client.resolve_event(&series_id, &epoch, &false);
+    client.override_event_outcome(&series_id, &epoch, &true);
+
+    assert_eq!(
+        client.get_event_info(&series_id, &epoch).outcome,
+        Some(true)
+    );
+
+    env.ledger().set_timestamp(round.close_timestamp + 100);
+
+    client.settle_event_round(&series_id, &epoch);
+
+    assert_eq!(
+        client.get_round(&series_id, &epoch).event_outcome,
+        Some(true)
+    );
+    assert!(client.claimable(&series_id, &epoch, &yes_user));
+    assert!(!client.claimable(&series_id, &epoch, &no_user));
+
+    client.claim(&yes_user, &series_id, &Vec::from_array(&env, [epoch]));
+
+    let treasury_amt = (400_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    assert_eq!(token_client.balance(&yes_user), 400_000_000 - treasury_amt);
+}
+
+#[test]
+#[should_panic(expected = "DISPUTE_WINDOW_OPEN")]
+fn test_settle_event_round_during_dispute_window() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let resolver = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+
+    let (series_id, epoch) = create_event_round(&env, &client, &resolver);
+
+    client.bet_yes(&series_id, &epoch, &user, &100_000_000);
+
+    let round = client.get_round(&series_id, &epoch);
+
+    env.ledger().set_timestamp(round.close_timestamp);
+
+    client.resolve_event(&series_id, &epoch, &true);
+    client.settle_event_round(&series_id, &epoch);
+}
+
+#[test]
+fn test_p2p_bet() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, contract_id) = init_test(&env);
+
+    let proposer = Address::generate(&env);
+    let taker = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&proposer, &100_000_000);
+    token_client.mint(&taker, &300_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    // Proposer takes bull at 3 to 1 against the taker
+    let bet_id = client.propose_p2p_bet(
+        &proposer,
+        &0,
+        &current_epoch,
+        &Position::Bull,
+        &100_000_000,
+        &Some(300_000_000),
+        &Some(taker.clone()),
+    );
+
+    client.accept_p2p_bet(&taker, &bet_id);
+
+    let bet = client.get_p2p_bet(&bet_id);
+
+    assert_eq!(bet.status, P2PBetStatus::Matched);
+    assert_eq!(bet.taker, Some(taker.clone()));
+    assert_eq!(token_client.balance(&contract_id), 400_000_000);
+
+    // Escrowed stakes stay out of the public pools
+    assert_eq!(client.get_round(&0, &current_epoch).total_amount, 0);
+
+    execute_round_at_price(&env, &client, &oracle_id, 1000);
+    execute_round_at_price(&env, &client, &oracle_id, 1100);
+
+    client.settle_p2p_bet(&bet_id);
+
+    let treasury_amt = (400_000_000 * DEFAULT_TREASURY_FEE as i128) / 10_000;
+
+    assert_eq!(token_client.balance(&proposer), 400_000_000 - treasury_amt);
+    assert_eq!(token_client.balance(&taker), 0);
+    assert_eq!(client.get_p2p_bet(&bet_id).status, P2PBetStatus::Settled);
+}
+
+#[test]
+#[should_panic(expected = "INVALID_POSITION")]
+fn test_p2p_bet_on_bucket() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let proposer = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&proposer, &100_000_000);
+
+    let series_id = client.create_bucket_series(
+        &DEFAULT_INTERVAL_SECONDS,
+        &DEFAULT_BUFFER_SECONDS,
+        &Vec::from_array(&env, [0]),
+    );
+
+    let current_epoch = start_series_genesis(&env, &client, series_id);
+
+    // A bucket has no single opposite side to match
+    client.propose_p2p_bet(
+        &proposer,
+        &series_id,
+        &current_epoch,
+        &Position::Bucket(0),
+        &100_000_000,
+        &None,
+        &None,
+    ); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "INVALID_COUNTERPARTY")]
+fn test_accept_p2p_bet_by_other_user() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let proposer = Address::generate(&env);
+    let counterparty = Address::generate(&env);
+    let other_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&proposer, &100_000_000);
+    token_client.mint(&other_user, &100_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    let bet_id = client.propose_p2p_bet(
+        &proposer,
+        &0,
+        &current_epoch,
+        &Position::Bear,
+        &100_000_000,
+        &None,
+        &Some(counterparty),
+    );
+
+    client.accept_p2p_bet(&other_user, &bet_id);
+}
+
+#[test]
+fn test_p2p_bet_above_max_bet_amount() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let proposer = Address::generate(&env);
+    let taker = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&proposer, &300_000_000);
+    token_client.mint(&taker, &300_000_000);
+
+    client.set_max_bet_amount(&200_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    // P2P stakes are held to the same caps as the pool bets
+    assert_eq!(
+        client.try_propose_p2p_bet(
+            &proposer,
+            &0,
+            &current_epoch,
+            &Position::Bull,
+            &300_000_000,
+            &None,
+            &None,
+        ),
+        Err(Ok(Error::BetAmountTooHigh.into()))
+    );
+
+    let bet_id = client.propose_p2p_bet(
+        &proposer,
+        &0,
+        &current_epoch,
+        &Position::Bull,
+        &100_000_000,
+        &Some(300_000_000),
+        &None,
+    );
+
+    assert_eq!(
+        client.try_accept_p2p_bet(&taker, &bet_id),
+        Err(Ok(Error::BetAmountTooHigh.into()))
+    );
+    assert_eq!(token_client.balance(&taker), 300_000_000);
+}
+
+#[test]
+fn test_fixed_odds_bet() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let lp = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&lp, &1_000_000_000);
+    token_client.mint(&user, &100_000_000);
+
+    client.vault_deposit(&lp, &1_000_000_000);
+
+    client.set_house_limits(&500_000_000, &200_000_000);
+    client.set_house_odds(&0, &19_000, &19_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_fixed_odds(
+        &0,
+        &current_epoch,
+        &user,
+        &Position::Bull,
+        &100_000_000,
+        &19_000,
+    );
+
+    let round = client.get_round(&0, &current_epoch);
+
+    assert_eq!(round.house_amount, 100_000_000);
+    assert_eq!(round.house_bull_liability, 190_000_000);
+    assert_eq!(round.total_amount, 0);
+    assert_eq!(client.get_house_exposure(), 90_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 1000);
+    execute_round_at_price(&env, &client, &oracle_id, 1100);
+
+    // The vault pays the winnings beyond the stake and releases the exposure
+    assert_eq!(client.get_house_exposure(), 0);
+    assert_eq!(client.get_vault_total_assets(), 910_000_000);
+
+    assert_eq!(
+        client.claim_fixed_odds(&user, &0, &current_epoch),
+        190_000_000
+    );
+    assert_eq!(token_client.balance(&user), 190_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HOUSE_EXPOSURE_TOO_HIGH")]
+fn test_fixed_odds_bet_above_round_exposure() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let lp = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&lp, &1_000_000_000);
+    token_client.mint(&user, &300_000_000);
+
+    client.vault_deposit(&lp, &1_000_000_000);
+
+    client.set_house_limits(&1_000_000_000, &200_000_000);
+    client.set_house_odds(&0, &19_000, &19_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    // Worst case loss of 270_000_000 exceeds the round budget
+    client.bet_fixed_odds(
+        &0,
+        &current_epoch,
+        &user,
+        &Position::Bear,
+        &300_000_000,
+        &19_000,
+    );
+}
+
+#[test]
+fn test_release_house_exposure_of_refunded_round() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let lp = Address::generate(&env);
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&lp, &1_000_000_000);
+    token_client.mint(&bull_user, &100_000_000);
+    token_client.mint(&bear_user, &50_000_000);
+
+    client.vault_deposit(&lp, &1_000_000_000);
+
+    client.set_house_limits(&500_000_000, &200_000_000);
+    client.set_house_odds(&0, &19_000, &19_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_fixed_odds(
+        &0,
+        &current_epoch,
+        &bull_user,
+        &Position::Bull,
+        &100_000_000,
+        &19_000,
+    );
+    client.bet_fixed_odds(
+        &0,
+        &current_epoch,
+        &bear_user,
+        &Position::Bear,
+        &50_000_000,
+        &19_000,
+    );
+
+    assert_eq!(client.get_house_exposure(), 40_000_000);
+
+    // The round is never executed and the buffer expires
+    let round = client.get_round(&0, &current_epoch);
+    env.ledger()
+        .set_timestamp(round.close_timestamp + DEFAULT_BUFFER_SECONDS + 1);
+
+    // The whole exposure of the round is released before any bettor claims
+    client.release_house_exposure(&0, &current_epoch);
+
+    assert_eq!(client.get_house_exposure(), 0);
+
+    assert_eq!(
+        client.claim_fixed_odds(&bull_user, &0, &current_epoch),
+        100_000_000
+    );
+    assert_eq!(
+        client.claim_fixed_odds(&bear_user, &0, &current_epoch),
+        50_000_000
+    );
+
+    assert_eq!(client.get_house_exposure(), 0);
+    assert_eq!(client.get_vault_total_assets(), 1_000_000_000);
+}
+
+#[test]
+fn test_fixed_odds_bet_above_max_bet_amount() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let lp = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&lp, &1_000_000_000);
+    token_client.mint(&user, &100_000_000);
+
+    client.vault_deposit(&lp, &1_000_000_000);
+
+    client.set_house_limits(&500_000_000, &200_000_000);
+    client.set_house_odds(&0, &19_000, &19_000);
+    client.set_max_bet_amount(&50_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    // Fixed-odds bets are held to the same caps as the pool bets
+    assert_eq!(
+        client.try_bet_fixed_odds(
+            &0,
+            &current_epoch,
+            &user,
+            &Position::Bull,
+            &100_000_000,
+            &19_000,
+        ),
+        Err(Ok(Error::BetAmountTooHigh.into()))
+    );
+}
+
+#[test]
+fn test_fee_curve() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let bull_user = Address::generate(&env);
+    let bear_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&bull_user, &300_000_000);
+    token_client.mint(&bear_user, &100_000_000);
+
+    client.set_fee_curve(&Some(FeeCurve {
+        min_fee: 100,
+        max_fee: 1000,
+    }));
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &bull_user, &300_000_000);
+    client.bet_bear(&0, &current_epoch, &bear_user, &100_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 1000);
+    execute_round_at_price(&env, &client, &oracle_id, 1100);
+
+    // Largest side holds 75% of the pool: halfway between the minimum and maximum fee
+    let round = client.get_round(&0, &current_epoch);
+
+    assert_eq!(round.treasury_fee, 550);
+
+    client.claim(&bull_user, &0, &Vec::from_array(&env, [current_epoch]));
+
+    assert_eq!(token_client.balance(&bull_user), 378_000_000);
+
+    // Without a fee curve, rounds use the flat treasury fee
+    client.set_fee_curve(&None);
+
+    assert_eq!(client.get_fee_curve(), None);
+
+    execute_round_at_price(&env, &client, &oracle_id, 1100);
+
+    assert_eq!(
+        client.get_round(&0, &(current_epoch + 1)).treasury_fee,
+        DEFAULT_TREASURY_FEE
+    );
+}
+
+#[test]
+fn test_fee_curve_on_bucket_series() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let other_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &100_000_000);
+    token_client.mint(&other_user, &100_000_000);
+
+    client.set_fee_curve(&Some(FeeCurve {
+        min_fee: 100,
+        max_fee: 1000,
+    }));
+
+    let series_id = client.create_bucket_series(
+        &DEFAULT_INTERVAL_SECONDS,
+        &DEFAULT_BUFFER_SECONDS,
+        &Vec::from_array(&env, [-100, 0, 100]),
+    );
+
+    let current_epoch = start_series_genesis(&env, &client, series_id);
+
+    client.bet_bucket(&series_id, &current_epoch, &user, &2, &100_000_000);
+    client.bet_bucket(&series_id, &current_epoch, &other_user, &3, &100_000_000);
+
+    execute_series_round_at_price(&env, &client, &oracle_id, series_id, 1000);
+    execute_series_round_at_price(&env, &client, &oracle_id, series_id, 1005);
+
+    // Two of four buckets hold the pool: a third of the way to one-sided
+    assert_eq!(
+        client.get_round(&series_id, &current_epoch).treasury_fee,
+        399
+    );
+}
+
+#[test]
+fn test_bet_caps() {
+    let env = Env::default();
+    let (_, oracle_id, token_id, client, _) = init_test(&env);
+
+    let user = Address::generate(&env);
+    let other_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&user, &1_000_000_000);
+    token_client.mint(&other_user, &1_000_000_000);
+
+    client.set_max_bet_amount(&300_000_000);
+    client.set_max_round_amount(&500_000_000);
+    client.set_user_cap(&400_000_000, &2);
+
+    assert_eq!(client.get_max_bet_amount(), 300_000_000);
+    assert_eq!(client.get_max_round_amount(), 500_000_000);
+    assert_eq!(
+        client.get_user_cap(),
+        UserCap {
+            amount: 400_000_000,
+            rounds: 2
+        }
+    );
+
+    let current_epoch = start_genesis(&env, &client);
+
+    assert_eq!(
+        client.try_bet_bull(&0, &current_epoch, &user, &400_000_000),
+        Err(Ok(Error::BetAmountTooHigh.into()))
+    );
+
+    client.bet_bull(&0, &current_epoch, &user, &300_000_000);
+
+    assert_eq!(
+        client.try_bet_bear(&0, &current_epoch, &other_user, &300_000_000),
+        Err(Ok(Error::RoundCapExceeded.into()))
+    );
+
+    // Next round, the user already bet 300_000_000 in the window of 2 rounds
+    execute_round_at_price(&env, &client, &oracle_id, 1000);
+
+    let next_epoch = client.get_current_epoch(&0);
+    let round = client.get_round(&0, &next_epoch);
+    env.ledger().set_timestamp(round.start_timestamp + 1);
+
+    assert_eq!(
+        client.get_user_rolling_amount(&0, &next_epoch, &user),
+        300_000_000
+    );
+    assert_eq!(
+        client.try_bet_bull(&0, &next_epoch, &user, &200_000_000),
+        Err(Ok(Error::UserCapExceeded.into()))
+    );
+
+    client.bet_bull(&0, &next_epoch, &user, &100_000_000);
+}
+
+#[test]
+fn test_bet_caps_with_allowlisted_token() {
+    let env = Env::default();
+    let (admin, oracle_id, token_id, client, _) = init_test(&env);
+
+    let usdc_id = deploy_xlm_token(&env, &admin);
+    let usdc_client = MyTokenClient::new(&env, &usdc_id);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    let user = Address::generate(&env);
+    let other_user = Address::generate(&env);
+
+    usdc_client.mint(&user, &1_000_000_000);
+    token_client.mint(&other_user, &1_000_000_000);
+
+    // One USDC is worth two units of the betting token
+    client.add_token(&usdc_id, &1_000_000, &20_000_000);
+
+    assert_eq!(client.get_token_rate(&usdc_id), 20_000_000);
+
+    client.set_max_bet_amount(&300_000_000);
+    client.set_max_round_amount(&500_000_000);
+    client.set_user_cap(&400_000_000, &2);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    assert_eq!(
+        client.try_bet_bull_with_token(&0, &current_epoch, &user, &usdc_id, &200_000_000),
+        Err(Ok(Error::BetAmountTooHigh.into()))
+    );
+
+    client.bet_bull_with_token(&0, &current_epoch, &user, &usdc_id, &150_000_000);
+
+    // The USDC pool counts for 300_000_000 towards the round cap
+    assert_eq!(
+        client.try_bet_bear(&0, &current_epoch, &other_user, &300_000_000),
+        Err(Ok(Error::RoundCapExceeded.into()))
+    );
+
+    execute_round_at_price(&env, &client, &oracle_id, 1000);
+
+    let next_epoch = client.get_current_epoch(&0);
+    let round = client.get_round(&0, &next_epoch);
+    env.ledger().set_timestamp(round.start_timestamp + 1);
+
+    assert_eq!(
+        client.get_user_rolling_amount(&0, &next_epoch, &user),
+        300_000_000
+    );
+
+    // A higher rate values the same USDC bets above the user cap
+    client.set_token_rate(&usdc_id, &30_000_000);
+
+    assert_eq!(
+        client.try_bet_bull_with_token(&0, &next_epoch, &user, &usdc_id, &1_000_000),
+        Err(Ok(Error::UserCapExceeded.into()))
+    );
+}
+
+#[test]
+fn test_responsible_gaming_controls() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let excluded_user = Address::generate(&env);
+    let limited_user = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&excluded_user, &1_000_000_000);
+    token_client.mint(&limited_user, &1_000_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    let now = env.ledger().timestamp();
+
+    client.self_exclude(&excluded_user, &(now + 1_000));
+
+    assert_eq!(client.get_self_exclusion(&excluded_user), now + 1_000);
+    assert_eq!(
+        client.try_bet_bull(&0, &current_epoch, &excluded_user, &100_000_000),
+        Err(Ok(Error::SelfExcluded.into()))
+    );
+
+    client.set_user_limit(&limited_user, &100_000_000);
+
+    assert_eq!(
+        client.try_bet_bear(&0, &current_epoch, &limited_user, &150_000_000),
+        Err(Ok(Error::WagerLimitExceeded.into()))
+    );
+
+    client.bet_bear(&0, &current_epoch, &limited_user, &100_000_000);
+
+    assert_eq!(client.get_user_daily_wager(&limited_user), 100_000_000);
+
+    // Loosening the limit only applies after the cooldown
+    client.set_user_limit(&limited_user, &500_000_000);
+
+    assert_eq!(client.get_user_wager_limit(&limited_user), 100_000_000);
+
+    env.ledger().set_timestamp(now + 7 * 86_400);
+
+    assert_eq!(client.get_user_wager_limit(&limited_user), 500_000_000);
+}
+
+#[test]
+fn test_daily_wager_limit_with_allowlisted_token() {
+    let env = Env::default();
+    let (admin, oracle_id, _, client, _) = init_test(&env);
+
+    let usdc_id = deploy_xlm_token(&env, &admin);
+    let usdc_client = MyTokenClient::new(&env, &usdc_id);
+
+    let user = Address::generate(&env);
+
+    usdc_client.mint(&user, &1_000_000_000);
+
+    // One USDC is worth two units of the betting token
+    client.add_token(&usdc_id, &1_000_000, &20_000_000);
+
+    client.set_user_limit(&user, &300_000_000);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull_with_token(&0, &current_epoch, &user, &usdc_id, &100_000_000);
+
+    assert_eq!(client.get_user_daily_wager(&user), 200_000_000);
+
+    execute_round_at_price(&env, &client, &oracle_id, 1000);
+
+    let next_epoch = client.get_current_epoch(&0);
+    let round = client.get_round(&0, &next_epoch);
+    env.ledger().set_timestamp(round.start_timestamp + 1);
+
+    assert_eq!(
+        client.try_bet_bull_with_token(&0, &next_epoch, &user, &usdc_id, &60_000_000),
+        Err(Ok(Error::WagerLimitExceeded.into()))
+    );
+
+    // The total of the previous day no longer counts
+    env.ledger().set_timestamp(round.start_timestamp + 86_400);
+
+    assert_eq!(client.get_user_daily_wager(&user), 0);
+}
+
+#[test]
+fn test_access_control() {
+    let env = Env::default();
+    let (_, _, token_id, client, _) = init_test(&env);
+
+    let allowed_user = Address::generate(&env);
+    let blocked_user = Address::generate(&env);
+    let refunded_user = Address::generate(&env);
+    let compliance_manager = Address::generate(&env);
+    let token_client = MyTokenClient::new(&env, &token_id);
+
+    token_client.mint(&allowed_user, &1_000_000_000);
+    token_client.mint(&blocked_user, &1_000_000_000);
+    token_client.mint(&refunded_user, &1_000_000_000);
+
+    client.set_compliance_manager(&compliance_manager);
+
+    assert_eq!(client.get_access_mode(), AccessMode::Open);
+    assert_eq!(client.get_compliance_manager(), compliance_manager);
+
+    let current_epoch = start_genesis(&env, &client);
+
+    client.bet_bull(&0, &current_epoch, &refunded_user, &100_000_000);
+
+    // Blocked users are refused new bets and deposits
+    client.set_access_mode(&AccessMode::Blocklist);
+    client.set_blocked(&blocked_user, &true);
+    client.set_blocked(&refunded_user, &true);
+
+    assert!(client.is_blocked(&blocked_user));
+    assert!(!client.has_access(&blocked_user));
+    assert_eq!(
+        client.try_bet_bull(&0, &current_epoch, &blocked_user, &100_000_000),
+        Err(Ok(Error::AccessDenied.into()))
+    );
+    assert_eq!(
+        client.try_deposit(&blocked_user, &token_id, &100_000_000),
+        Err(Ok(Error::AccessDenied.into()))
+    );
+
+    client.deposit(&allowed_user, &token_id, &100_000_000);
+
+    // Only allowed users can bet in the allowlist mode
+    client.set_access_mode(&AccessMode::Allowlist);
+
+    assert_eq!(
+        client.try_bet_bear(&0, &current_epoch, &allowed_user, &100_000_000),
+        Err(Ok(Error::AccessDenied.into()))
+    );
+
+    client.set_allowed(&allowed_user, &true);
+    client.set_allowed(&blocked_user, &true);
+
+    assert!(client.has_access(&allowed_user));
+    assert!(!client.has_access(&blocked_user));
+
+    client.bet_bear(&0, &current_epoch, &allowed_user, &100_000_000);
+
+    // Blocked users can still get their refund
+    let round = client.get_round(&0, &current_epoch);
+    env.ledger()
+        .set_timestamp(round.close_timestamp + DEFAULT_BUFFER_SECONDS + 1);
+
+    client.claim(&refunded_user, &0, &Vec::from_array(&env, [current_epoch]));
+
+    assert_eq!(token_client.balance(&refunded_user), 1_000_000_000);
 }
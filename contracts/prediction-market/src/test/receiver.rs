@@ -0,0 +1,24 @@
+use soroban_sdk::{contract, contractimpl, token, Address, Env};
+
+// Flash loan receiver that repays the loan plus the fee from its own balance.
+#[contract]
+pub struct FlashLoanRepayer;
+
+#[contractimpl]
+impl FlashLoanRepayer {
+    pub fn execute_flash_loan(
+        e: Env,
+        caller: Address,
+        token: Address,
+        amount: i128,
+        fee_amount: i128,
+    ) {
+        let token_client = token::Client::new(&e, &token);
+
+        token_client.transfer(
+            &e.current_contract_address(),
+            &caller,
+            &(amount + fee_amount),
+        );
+    }
+}
@@ -37,78 +37,300 @@ export const networks = {
   },
 } as const;
 
-export enum Errors {
-  NotAuthorized = 1,
-  NotOperator = 2,
-  NotAdmin = 3,
-  AlreadyInitialized = 4,
-  NotInitialized = 5,
-  BetTooEarlyOrLate = 6,
-  RoundNotBettable = 7,
-  BetAmountTooLow = 8,
-  AlreadyBet = 9,
-  RoundNotStarted = 10,
-  RoundNotEnded = 11,
-  NotEligibleForClaim = 12,
-  NotEligibleForRefund = 13,
-  GenesisNotTriggered = 14,
-  GenesisAlreadyTriggered = 15,
-  TreasuryFeeTooHigh = 16,
-  InvalidBufferInterval = 17,
-  InvalidAmount = 18,
-  InvalidAddress = 19,
-  RoundAlreadyLocked = 20,
-  RoundNotLocked = 21,
-  OutsideBuffer = 22,
-  RewardsAlreadyCalculated = 23,
-  OracleUpdateExceeded = 24,
-  OracleRoundIdTooLow = 25,
-  TransferFailed = 26,
-  Paused = 27,
-  NotPaused = 28,
-}
+export const Errors = {
+  1: { message: "NotAuthorized" },
+  2: { message: "NotOperator" },
+  3: { message: "NotAdmin" },
+  4: { message: "AlreadyInitialized" },
+  5: { message: "NotInitialized" },
+  6: { message: "BetTooEarlyOrLate" },
+  7: { message: "RoundNotBettable" },
+  8: { message: "BetAmountTooLow" },
+  9: { message: "AlreadyBet" },
+  10: { message: "RoundNotStarted" },
+  11: { message: "RoundNotEnded" },
+  12: { message: "NotEligibleForClaim" },
+  13: { message: "NotEligibleForRefund" },
+  14: { message: "GenesisNotTriggered" },
+  15: { message: "GenesisAlreadyTriggered" },
+  16: { message: "TreasuryFeeTooHigh" },
+  17: { message: "InvalidBufferInterval" },
+  18: { message: "InvalidAmount" },
+  19: { message: "InvalidAddress" },
+  20: { message: "RoundAlreadyLocked" },
+  21: { message: "RoundNotLocked" },
+  22: { message: "OutsideBuffer" },
+  23: { message: "RewardsAlreadyCalculated" },
+  24: { message: "OracleUpdateExceeded" },
+  25: { message: "OracleRoundIdTooLow" },
+  26: { message: "TransferFailed" },
+  27: { message: "Paused" },
+  28: { message: "NotPaused" },
+  29: { message: "BetAmountTooHigh" },
+  30: { message: "RoundCapExceeded" },
+  31: { message: "UserCapExceeded" },
+  32: { message: "SelfExcluded" },
+  33: { message: "WagerLimitExceeded" },
+  34: { message: "AccessDenied" },
+};
 
 export type Position =
   | { tag: "Bull"; values: void }
-  | { tag: "Bear"; values: void };
+  | { tag: "Bear"; values: void }
+  | { tag: "Bucket"; values: readonly [u32] }
+  | { tag: "Asset"; values: readonly [string] }
+  | { tag: "BigMove"; values: void }
+  | { tag: "SmallMove"; values: void }
+  | { tag: "Yes"; values: void }
+  | { tag: "No"; values: void };
 
 export interface BetInfo {
   amount: i128;
   claimed: boolean;
   position: Position;
+  token: string;
+}
+
+export interface TokenPool {
+  bear_amount: i128;
+  bucket_amounts: Array<i128>;
+  bull_amount: i128;
+  reward_amount: i128;
+  reward_base_cal_amount: i128;
+  total_amount: i128;
 }
 
 export interface Round {
   bear_amount: i128;
+  bucket_amounts: Array<i128>;
   bull_amount: i128;
   close_price: i128;
   close_timestamp: u64;
   epoch: u128;
+  event_outcome: Option<boolean>;
+  house_amount: i128;
+  house_bear_liability: i128;
+  house_bull_liability: i128;
   lock_price: i128;
   lock_timestamp: u64;
+  move_threshold: u32;
   reward_amount: i128;
   reward_base_cal_amount: i128;
+  rival_close_price: i128;
+  rival_lock_price: i128;
   start_timestamp: u64;
+  token_pools: Map<string, TokenPool>;
   total_amount: i128;
+  treasury_fee: u32;
+  winning_bucket: Option<u32>;
+}
+
+export type IntentFunding =
+  | { tag: "Balance"; values: void }
+  | { tag: "Allowance"; values: void };
+
+export interface BetIntent {
+  amount: i128;
+  epoch: u128;
+  expiry: u64;
+  funding: IntentFunding;
+  nonce: u64;
+  position: Position;
+  series_id: u32;
+  token: string;
+  user: string;
+}
+
+export interface UserStats {
+  best_win_streak: u32;
+  current_win_streak: u32;
+  net_profit: i128;
+  rounds_played: u32;
+  rounds_won: u32;
+  total_claimed: i128;
+  total_wagered: i128;
+}
+
+export interface LeaderboardEntry {
+  score: i128;
+  user: string;
+}
+
+export type RankingMetric =
+  | { tag: "NetProfit"; values: void }
+  | { tag: "WinCount"; values: void };
+
+export interface Season {
+  claimed_amount: i128;
+  end_epoch: u128;
+  finalized: boolean;
+  finalized_timestamp: u64;
+  id: u32;
+  metric: RankingMetric;
+  payout_table: Array<u32>;
+  prize_pool: i128;
+  series_id: u32;
+  start_epoch: u128;
+  swept: boolean;
+  winners: Array<string>;
+}
+
+export interface ReferralStats {
+  claimable: Map<string, i128>;
+  referred_count: u32;
+  total_earned: Map<string, i128>;
+}
+
+export interface StandingOrder {
+  amount: i128;
+  last_epoch: u128;
+  position: Position;
+  remaining_rounds: u32;
+  series_id: u32;
+}
+
+export type P2PBetStatus =
+  | { tag: "Open"; values: void }
+  | { tag: "Matched"; values: void }
+  | { tag: "Cancelled"; values: void }
+  | { tag: "Settled"; values: void };
+
+export interface P2PBet {
+  counter_stake: i128;
+  counterparty: Option<string>;
+  epoch: u128;
+  position: Position;
+  proposer: string;
+  series_id: u32;
+  stake: i128;
+  status: P2PBetStatus;
+  taker: Option<string>;
+}
+
+export interface UserCap {
+  amount: i128;
+  rounds: u32;
+}
+
+export type AccessMode =
+  | { tag: "Open"; values: void }
+  | { tag: "Blocklist"; values: void }
+  | { tag: "Allowlist"; values: void };
+
+export interface UserLimit {
+  max_wager_per_day: i128;
+  pending_max_wager_per_day: Option<i128>;
+  pending_timestamp: u64;
+}
+
+export interface FeeCurve {
+  max_fee: u32;
+  min_fee: u32;
+}
+
+export interface HouseOdds {
+  bear_odds: u32;
+  bull_odds: u32;
+}
+
+export interface FixedOddsBet {
+  amount: i128;
+  claimed: boolean;
+  odds: u32;
+  position: Position;
+}
+
+export interface EventInfo {
+  outcome: Option<boolean>;
+  question: string;
+  resolved_timestamp: u64;
+  resolver: string;
+}
+
+export interface VaultPosition {
+  last_deposit_timestamp: u64;
+  last_withdraw_timestamp: u64;
+  shares: i128;
 }
 
 export type DataKey =
   | { tag: "Token"; values: void }
   | { tag: "OracleAddress"; values: void }
-  | { tag: "IntervalSeconds"; values: void }
-  | { tag: "BufferSeconds"; values: void }
+  | { tag: "MarketAsset"; values: void }
+  | { tag: "IntervalSeconds"; values: readonly [u32] }
+  | { tag: "BufferSeconds"; values: readonly [u32] }
   | { tag: "MinBetAmount"; values: void }
   | { tag: "TreasuryFee"; values: void }
   | { tag: "TreasuryAmount"; values: void }
-  | { tag: "CurrentEpoch"; values: void }
-  | { tag: "IsGenesisStarted"; values: void }
-  | { tag: "IsGenesisLocked"; values: void }
+  | { tag: "CurrentEpoch"; values: readonly [u32] }
+  | { tag: "IsGenesisStarted"; values: readonly [u32] }
+  | { tag: "IsGenesisLocked"; values: readonly [u32] }
   | { tag: "Paused"; values: void }
   | { tag: "Initialized"; values: void }
-  | { tag: "Rounds"; values: readonly [u128] }
-  | { tag: "BetInfos"; values: readonly [u128, string] }
-  | { tag: "UserRounds"; values: readonly [string] }
-  | { tag: "FlashLoanFee"; values: void };
+  | { tag: "Rounds"; values: readonly [u32, u128] }
+  | { tag: "BetInfos"; values: readonly [u32, u128, string] }
+  | { tag: "UserRounds"; values: readonly [u32, string] }
+  | { tag: "SeriesCount"; values: void }
+  | { tag: "SeriesBuckets"; values: readonly [u32] }
+  | { tag: "StrikeSeries"; values: readonly [u32] }
+  | { tag: "SeriesAssets"; values: readonly [u32] }
+  | { tag: "VolatilitySeries"; values: readonly [u32] }
+  | { tag: "EventSeries"; values: readonly [u32] }
+  | { tag: "EventInfos"; values: readonly [u32, u128] }
+  | { tag: "HouseOdds"; values: readonly [u32] }
+  | { tag: "FeeCurve"; values: void }
+  | { tag: "MaxBetAmount"; values: void }
+  | { tag: "MaxRoundAmount"; values: void }
+  | { tag: "UserCap"; values: void }
+  | { tag: "SelfExclusions"; values: readonly [string] }
+  | { tag: "UserLimits"; values: readonly [string] }
+  | { tag: "UserDailyWagers"; values: readonly [string] }
+  | { tag: "AccessMode"; values: void }
+  | { tag: "ComplianceManager"; values: void }
+  | { tag: "Blocklist"; values: readonly [string] }
+  | { tag: "Allowlist"; values: readonly [string] }
+  | { tag: "HouseMaxSideExposure"; values: void }
+  | { tag: "HouseMaxRoundExposure"; values: void }
+  | { tag: "HouseExposure"; values: void }
+  | { tag: "FixedOddsBets"; values: readonly [u32, u128, string] }
+  | { tag: "FlashLoanFee"; values: void }
+  | { tag: "FlashTreasuryAmount"; values: void }
+  | { tag: "VaultTotalAssets"; values: void }
+  | { tag: "VaultTotalShares"; values: void }
+  | { tag: "VaultPositions"; values: readonly [string] }
+  | { tag: "VaultCooldownSeconds"; values: void }
+  | { tag: "VaultTreasuryFeeShare"; values: void }
+  | { tag: "AllowedTokens"; values: void }
+  | { tag: "TokenMinBetAmount"; values: readonly [string] }
+  | { tag: "TokenRates"; values: readonly [string] }
+  | { tag: "TokenTreasuryAmount"; values: readonly [string] }
+  | { tag: "Balances"; values: readonly [string, string] }
+  | { tag: "Operator"; values: void }
+  | { tag: "SigningKeys"; values: readonly [string] }
+  | { tag: "IntentNonces"; values: readonly [string] }
+  | { tag: "GiftPayers"; values: readonly [string] }
+  | { tag: "Referrers"; values: readonly [string] }
+  | { tag: "ReferralStats"; values: readonly [string] }
+  | { tag: "ReferralFeeShare"; values: void }
+  | { tag: "RoundReferralStakes"; values: readonly [u32, u128, string] }
+  | { tag: "UserStats"; values: readonly [string] }
+  | { tag: "SeasonUserStats"; values: readonly [u32, string] }
+  | { tag: "Leaderboard"; values: readonly [u32] }
+  | { tag: "UnsettledStatsRounds"; values: readonly [string] }
+  | { tag: "SeasonCount"; values: void }
+  | { tag: "Seasons"; values: readonly [u32] }
+  | { tag: "SeasonPrizeClaimed"; values: readonly [u32, string] }
+  | { tag: "JackpotAmount"; values: void }
+  | { tag: "JackpotFeeShare"; values: void }
+  | { tag: "JackpotInterval"; values: void }
+  | { tag: "JackpotEntries"; values: readonly [u128] }
+  | { tag: "JackpotRoundEntries"; values: readonly [u128] }
+  | { tag: "JackpotPeriods"; values: void }
+  | { tag: "StandingOrders"; values: readonly [string] }
+  | { tag: "StandingOrderUsers"; values: readonly [u32] }
+  | { tag: "StandingOrderMinEscrow"; values: void }
+  | { tag: "P2PBetCount"; values: void }
+  | { tag: "P2PBets"; values: readonly [u32] };
 
 /**
  * Storage key for enumeration of accounts per role.
@@ -163,80 +385,4406 @@ export const RoleTransferError = {
 export interface Client {
   /**
    * Construct and simulate a genesis_start_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Function to start the genesis round
+   * Function to start the genesis round of a series
    * Only callable by the owner
+   * # Parameters
+   * - `series_id`: The id of the series
    */
-  genesis_start_round: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  genesis_start_round: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
    * Construct and simulate a genesis_lock_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Function to lock the genesis round
+   * Function to lock the genesis round of a series
    * Only callable by the owner
+   * # Parameters
+   * - `series_id`: The id of the series
    */
-  genesis_lock_round: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  genesis_lock_round: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
    * Construct and simulate a execute_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to execute a round of a series
+   * Only callable by the owner
+   * # Parameters
+   * - `series_id`: The id of the series
+   * # Events
+   * - `ROUND_LOCKED`: Emitted when a round is locked
+   * - `ROUND_ENDED`: Emitted when a round is ended
+   * - `REWARDS_CALCULATED`: Emitted when rewards are calculated
+   * - `ROUND_STARTED`: Emitted when a new round is started
    */
-  execute_round: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  execute_round: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a create_strike_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to open a strike round on whether the price will be above or below a strike at expiry
+   * Bull bets win above the strike and Bear bets below it, a close at the strike goes to the treasury
+   * Only callable by the owner
+   * # Parameters
+   * - `series_id`: The id of the strike series
+   * - `strike_price`: The strike price, in oracle price units
+   * - `lock_timestamp`: The time betting closes
+   * - `expiry_timestamp`: The time the round is settled against the strike
+   * # Returns
+   * - `u128`: The epoch of the new round
+   * # Events
+   * - `ROUND_STARTED`: Emitted when the round is created
+   * - `STRIKE_ROUND_CREATED`: Emitted with the strike and expiry of the round
+   */
+  create_strike_round: (
+    {
+      series_id,
+      strike_price,
+      lock_timestamp,
+      expiry_timestamp,
+    }: {
+      series_id: u32;
+      strike_price: i128;
+      lock_timestamp: u64;
+      expiry_timestamp: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u128>>;
+
+  /**
+   * Construct and simulate a settle_strike_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to settle a strike round with the oracle price at its expiry
+   * Only callable by the owner, within the buffer seconds after expiry
+   * # Parameters
+   * - `series_id`: The id of the strike series
+   * - `epoch`: The epoch of the round
+   * # Events
+   * - `ROUND_ENDED`: Emitted when the round is ended
+   * - `REWARDS_CALCULATED`: Emitted when rewards are calculated
+   */
+  settle_strike_round: (
+    { series_id, epoch }: { series_id: u32; epoch: u128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a create_event_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to open a yes/no round on an event settled by a resolver
+   * Only callable by the owner
+   * # Parameters
+   * - `series_id`: The id of the event series
+   * - `question`: The event the round is bet on
+   * - `resolver`: The address allowed to resolve the outcome of the event
+   * - `lock_timestamp`: The time betting closes
+   * - `close_timestamp`: The time from which the event can be resolved
+   * # Returns
+   * - `u128`: The epoch of the new round
+   * # Events
+   * - `ROUND_STARTED`: Emitted when the round is created
+   * - `EVENT_ROUND_CREATED`: Emitted with the question and resolver of the round
+   */
+  create_event_round: (
+    {
+      series_id,
+      question,
+      resolver,
+      lock_timestamp,
+      close_timestamp,
+    }: {
+      series_id: u32;
+      question: string;
+      resolver: string;
+      lock_timestamp: u64;
+      close_timestamp: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u128>>;
+
+  /**
+   * Construct and simulate a resolve_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function for the resolver to report the outcome of an event round
+   * The outcome opens a dispute window during which the owner can override it
+   * # Parameters
+   * - `series_id`: The id of the event series
+   * - `epoch`: The epoch of the round
+   * - `outcome`: Whether the event happened
+   * # Events
+   * - `EVENT_RESOLVED`: Emitted when the outcome is reported
+   */
+  resolve_event: (
+    {
+      series_id,
+      epoch,
+      outcome,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      outcome: boolean;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a override_event_outcome transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to override the outcome of an event round during its dispute window
+   * Only callable by the owner
+   * # Parameters
+   * - `series_id`: The id of the event series
+   * - `epoch`: The epoch of the round
+   * - `outcome`: Whether the event happened
+   * # Events
+   * - `EVENT_OVERRIDDEN`: Emitted when the outcome is overridden
+   */
+  override_event_outcome: (
+    {
+      series_id,
+      epoch,
+      outcome,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      outcome: boolean;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a settle_event_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to settle an event round with its outcome once the dispute window is over
+   * Callable by anyone, before the round becomes refundable
+   * # Parameters
+   * - `series_id`: The id of the event series
+   * - `epoch`: The epoch of the round
+   * # Events
+   * - `REWARDS_CALCULATED`: Emitted when rewards are calculated
+   */
+  settle_event_round: (
+    { series_id, epoch }: { series_id: u32; epoch: u128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bull transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on the bull side
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_bull: (
+    {
+      series_id,
+      epoch,
+      user,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bear transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on the bear side
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_bear: (
+    {
+      series_id,
+      epoch,
+      user,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bucket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on a price bucket of a bucket series
+   * # Parameters
+   * - `series_id`: The id of the bucket series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `bucket`: The index of the bucket, 0 being the lowest price change
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_bucket: (
+    {
+      series_id,
+      epoch,
+      user,
+      bucket,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      bucket: u32;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_asset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on an asset of a head-to-head series
+   * # Parameters
+   * - `series_id`: The id of the head-to-head series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `asset`: The asset expected to have the higher percentage change
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_asset: (
+    {
+      series_id,
+      epoch,
+      user,
+      asset,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      asset: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_big_move transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on a big move of a volatility series
+   * # Parameters
+   * - `series_id`: The id of the volatility series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_big_move: (
+    {
+      series_id,
+      epoch,
+      user,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_small_move transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on a small move of a volatility series
+   * # Parameters
+   * - `series_id`: The id of the volatility series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_small_move: (
+    {
+      series_id,
+      epoch,
+      user,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_yes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on yes in an event series
+   * # Parameters
+   * - `series_id`: The id of the event series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_yes: (
+    {
+      series_id,
+      epoch,
+      user,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_no transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on no in an event series
+   * # Parameters
+   * - `series_id`: The id of the event series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_no: (
+    {
+      series_id,
+      epoch,
+      user,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_fixed_odds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet at the fixed odds quoted by the house for a series
+   * The bet is backed by the vault and stays out of the parimutuel pools
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `position`: The side of the bet
+   * - `amount`: The amount of tokens to bet
+   * - `min_odds`: The lowest odds the user accepts (scaled by 10_000, e.g., 19_000 = 1.9x)
+   * # Events
+   * - `FIXED_ODDS_BET_PLACED`: Emitted when the bet is placed
+   */
+  bet_fixed_odds: (
+    {
+      series_id,
+      epoch,
+      user,
+      position,
+      amount,
+      min_odds,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      position: Position;
+      amount: i128;
+      min_odds: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a claim_fixed_odds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to claim the payout of a winning fixed-odds bet, or its refund if the round was never ended
+   * # Parameters
+   * - `user`: The address of the user claiming
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round
+   * # Returns
+   * - `i128`: The amount paid to the user
+   * # Events
+   * - `FIXED_ODDS_CLAIMED`: Emitted when the payout is claimed
+   */
+  claim_fixed_odds: (
+    { user, series_id, epoch }: { user: string; series_id: u32; epoch: u128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a release_house_exposure transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to release the exposure the vault reserved for the fixed-odds bets of a refunded round
+   * Callable by anyone, the stakes stay claimable by their bettors
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the refunded round
+   * # Events
+   * - `HOUSE_BOOK_RELEASED`: Emitted with the exposure released
+   */
+  release_house_exposure: (
+    { series_id, epoch }: { series_id: u32; epoch: u128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bull_with_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on the bull side with an allowlisted token
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `token`: The address of the token used for the bet
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_bull_with_token: (
+    {
+      series_id,
+      epoch,
+      user,
+      token,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      token: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bear_with_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on the bear side with an allowlisted token
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `token`: The address of the token used for the bet
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_bear_with_token: (
+    {
+      series_id,
+      epoch,
+      user,
+      token,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      token: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bull_from_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on the bull side funded from the internal balance
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `token`: The address of the token used for the bet
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   * - `BALANCE_DEBITED`: Emitted when the stake is debited from the balance
+   */
+  bet_bull_from_balance: (
+    {
+      series_id,
+      epoch,
+      user,
+      token,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      token: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bear_from_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on the bear side funded from the internal balance
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `token`: The address of the token used for the bet
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   * - `BALANCE_DEBITED`: Emitted when the stake is debited from the balance
+   */
+  bet_bear_from_balance: (
+    {
+      series_id,
+      epoch,
+      user,
+      token,
+      amount,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      token: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bull_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on the bull side funded under a token allowance
+   * `user` must have approved `spender`; when `spender` is this contract the user
+   * authorizes the bet, otherwise the spender does
+   * # Parameters
+   * - `spender`: The address spending the allowance
+   * - `user`: The address of the user funding and owning the bet
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_bull_from: (
+    {
+      spender,
+      user,
+      series_id,
+      epoch,
+      amount,
+    }: {
+      spender: string;
+      user: string;
+      series_id: u32;
+      epoch: u128;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bear_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on the bear side funded under a token allowance
+   * `user` must have approved `spender`; when `spender` is this contract the user
+   * authorizes the bet, otherwise the spender does
+   * # Parameters
+   * - `spender`: The address spending the allowance
+   * - `user`: The address of the user funding and owning the bet
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_bear_from: (
+    {
+      spender,
+      user,
+      series_id,
+      epoch,
+      amount,
+    }: {
+      spender: string;
+      user: string;
+      series_id: u32;
+      epoch: u128;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bull_for transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bull bet paid by `payer` on behalf of `beneficiary`
+   * The bet and its claim rights belong to the beneficiary
+   * Only the payer authorizes the bet, so it can be gifted to an offline beneficiary,
+   * unless the beneficiary opted in to an allowlist of payers with `set_gift_payers`
+   * # Parameters
+   * - `payer`: The address funding the bet
+   * - `beneficiary`: The address owning the bet
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   * - `BET_SPONSORED`: Emitted with the payer of the bet
+   */
+  bet_bull_for: (
+    {
+      payer,
+      beneficiary,
+      series_id,
+      epoch,
+      amount,
+    }: {
+      payer: string;
+      beneficiary: string;
+      series_id: u32;
+      epoch: u128;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bear_for transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bear bet paid by `payer` on behalf of `beneficiary`
+   * The bet and its claim rights belong to the beneficiary
+   * Only the payer authorizes the bet, so it can be gifted to an offline beneficiary,
+   * unless the beneficiary opted in to an allowlist of payers with `set_gift_payers`
+   * # Parameters
+   * - `payer`: The address funding the bet
+   * - `beneficiary`: The address owning the bet
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `amount`: The amount of tokens to bet
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   * - `BET_SPONSORED`: Emitted with the payer of the bet
+   */
+  bet_bear_for: (
+    {
+      payer,
+      beneficiary,
+      series_id,
+      epoch,
+      amount,
+    }: {
+      payer: string;
+      beneficiary: string;
+      series_id: u32;
+      epoch: u128;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_gift_payers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function for a beneficiary to only accept gifted bets from a list of payers
+   * An empty list accepts gifts from any payer, which is the default
+   * # Parameters
+   * - `beneficiary`: The address receiving the gifted bets
+   * - `payers`: The payers allowed to gift bets, at most 10
+   * # Events
+   * - `GIFT_PAYERS_UPDATED`: Emitted when the list is updated
+   */
+  set_gift_payers: (
+    { beneficiary, payers }: { beneficiary: string; payers: Array<string> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bull_referred transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on the bull side through a referral link
+   * The referrer is bound to the user on its first bet, it is ignored once the user has bet
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `amount`: The amount of tokens to bet
+   * - `referrer`: The address of the referrer of the user
+   * # Events
+   * - `REFERRER_REGISTERED`: Emitted when the referrer is bound to the user
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_bull_referred: (
+    {
+      series_id,
+      epoch,
+      user,
+      amount,
+      referrer,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      amount: i128;
+      referrer: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bet_bear_referred transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet on the bear side through a referral link
+   * The referrer is bound to the user on its first bet, it is ignored once the user has bet
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `user`: The address of the user placing the bet
+   * - `amount`: The amount of tokens to bet
+   * - `referrer`: The address of the referrer of the user
+   * # Events
+   * - `REFERRER_REGISTERED`: Emitted when the referrer is bound to the user
+   * - `BET_PLACED`: Emitted when a bet is placed
+   */
+  bet_bear_referred: (
+    {
+      series_id,
+      epoch,
+      user,
+      amount,
+      referrer,
+    }: {
+      series_id: u32;
+      epoch: u128;
+      user: string;
+      amount: i128;
+      referrer: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a register_referrer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to register the referrer of a user ahead of its first bet
+   * The referrer can only be set once, before the first bet of the user, the bet functions
+   * with a referrer bind it on the first bet instead
+   * # Parameters
+   * - `user`: The address of the referred user
+   * - `referrer`: The address of the referrer
+   * # Events
+   * - `REFERRER_REGISTERED`: Emitted when a referrer is registered
+   */
+  register_referrer: (
+    { user, referrer }: { user: string; referrer: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a claim_referral_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to claim the referral rewards accrued in every token
+   * # Parameters
+   * - `referrer`: The address of the referrer
+   * # Events
+   * - `REFERRAL_REWARDS_CLAIMED`: Emitted for every token paid out
+   */
+  claim_referral_rewards: (
+    { referrer }: { referrer: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a fund_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to add betting tokens to the prize pool of a season
+   * # Parameters
+   * - `funder`: The address funding the prize pool
+   * - `season_id`: The id of the season
+   * - `amount`: The amount of tokens to add
+   * # Events
+   * - `SEASON_FUNDED`: Emitted when the prize pool is funded
+   */
+  fund_season: (
+    {
+      funder,
+      season_id,
+      amount,
+    }: {
+      funder: string;
+      season_id: u32;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a claim_season_prize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to claim the prize of a finalized season
+   * Prizes can be claimed until the owner sweeps the season
+   * # Parameters
+   * - `season_id`: The id of the season
+   * - `user`: The address of a top finisher
+   * # Events
+   * - `SEASON_PRIZE_CLAIMED`: Emitted when a prize is claimed
+   */
+  claim_season_prize: (
+    { season_id, user }: { season_id: u32; user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a register_signing_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to register the ed25519 key used to sign bet intents
+   * # Parameters
+   * - `user`: The address of the user
+   * - `public_key`: The ed25519 public key of the user
+   * # Events
+   * - `SIGNING_KEY_REGISTERED`: Emitted when a signing key is registered
+   */
+  register_signing_key: (
+    { user, public_key }: { user: string; public_key: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a execute_bet_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to place a bet from an intent signed off-chain by the user
+   * Only callable by the operator, who pays the transaction fees
+   * # Parameters
+   * - `intent`: The bet intent signed by the user
+   * - `signature`: The ed25519 signature of `get_bet_intent_payload(intent)`
+   * # Events
+   * - `BET_PLACED`: Emitted when a bet is placed
+   * - `BET_INTENT_EXECUTED`: Emitted when the intent is consumed
+   */
+  execute_bet_intent: (
+    { intent, signature }: { intent: BetIntent; signature: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to claim winnings or refunds and transfer them to the user
+   * # Parameters
+   * - `user`: The address of the user claiming
+   * - `series_id`: The id of the series
+   * - `epochs`: The epochs of the rounds to claim
+   * # Events
+   * - `CLAIMED`: Emitted for every claimed round
+   */
+  claim: (
+    {
+      user,
+      series_id,
+      epochs,
+    }: {
+      user: string;
+      series_id: u32;
+      epochs: Array<u128>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a claim_to_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to claim winnings or refunds into the internal balance
+   * # Parameters
+   * - `user`: The address of the user claiming
+   * - `series_id`: The id of the series
+   * - `epochs`: The epochs of the rounds to claim
+   * # Events
+   * - `CLAIMED`: Emitted for every claimed round
+   * - `BALANCE_CREDITED`: Emitted for every token credited to the balance
+   */
+  claim_to_balance: (
+    {
+      user,
+      series_id,
+      epochs,
+    }: {
+      user: string;
+      series_id: u32;
+      epochs: Array<u128>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a claim_and_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to claim winnings or refunds and bet them on the current round of the same series
+   * The bet is placed in the betting token, the rest of the payouts is credited to the internal balance
+   * # Parameters
+   * - `user`: The address of the user claiming
+   * - `series_id`: The id of the series
+   * - `claim_epochs`: The epochs of the rounds to claim
+   * - `position`: The side of the new bet
+   * - `amount`: The amount to bet, or `None` to bet all of the betting token payouts
+   * # Events
+   * - `CLAIMED`: Emitted for every claimed round
+   * - `BET_PLACED`: Emitted when the bet is placed
+   * - `BALANCE_CREDITED`: Emitted for every token credited to the balance
+   */
+  claim_and_bet: (
+    {
+      user,
+      series_id,
+      claim_epochs,
+      position,
+      amount,
+    }: {
+      user: string;
+      series_id: u32;
+      claim_epochs: Array<u128>;
+      position: Position;
+      amount: Option<i128>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a sync_user_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to settle the ended rounds of a user into its stats and the season leaderboards
+   * Stats are settled whenever the user bets or claims, this covers users who do neither
+   * Callable by anyone
+   * # Parameters
+   * - `user`: The address of the user
+   */
+  sync_user_stats: (
+    { user }: { user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a deposit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to deposit tokens into the internal balance
+   * # Parameters
+   * - `user`: The address of the user depositing
+   * - `token`: The address of an accepted token
+   * - `amount`: The amount of tokens to deposit
+   * # Events
+   * - `BALANCE_CREDITED`: Emitted when the balance is credited
+   */
+  deposit: (
+    { user, token, amount }: { user: string; token: string; amount: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a withdraw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to withdraw tokens from the internal balance
+   * # Parameters
+   * - `user`: The address of the user withdrawing
+   * - `token`: The address of the token
+   * - `amount`: The amount of tokens to withdraw
+   * # Events
+   * - `BALANCE_DEBITED`: Emitted when the balance is debited
+   */
+  withdraw: (
+    { user, token, amount }: { user: string; token: string; amount: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a create_standing_order transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to create a standing order betting the same amount on the same side for several rounds
+   * The stake of every round is escrowed upfront in the betting token
+   * # Parameters
+   * - `user`: The address of the user creating the order
+   * - `series_id`: The id of the series to bet on
+   * - `position`: The side to bet on every round
+   * - `amount`: The amount to bet every round
+   * - `rounds`: The number of rounds to bet on
+   * # Events
+   * - `STANDING_ORDER_CREATED`: Emitted when the order is created
+   */
+  create_standing_order: (
+    {
+      user,
+      series_id,
+      position,
+      amount,
+      rounds,
+    }: {
+      user: string;
+      series_id: u32;
+      position: Position;
+      amount: i128;
+      rounds: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a cancel_standing_order transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to cancel a standing order and refund the unspent escrow
+   * # Parameters
+   * - `user`: The address of the user cancelling the order
+   * # Events
+   * - `STANDING_ORDER_CANCELLED`: Emitted when the order is cancelled
+   */
+  cancel_standing_order: (
+    { user }: { user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a execute_standing_orders transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Keeper function to place the bets of the standing orders on the current round of a series
+   * Callable by anyone while the current round is bettable
+   * Orders whose user already bet on the round are skipped for that round
+   * # Parameters
+   * - `series_id`: The id of the series
+   * # Returns
+   * - `u32`: The number of bets placed
+   * # Events
+   * - `BET_PLACED`: Emitted for every bet placed
+   */
+  execute_standing_orders: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a propose_p2p_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to propose a peer-to-peer bet on a round, escrowing the stake of the proposer
+   * The bet is accepted by taking the opposite side, and is settled by the outcome of the round
+   * without entering its pools
+   * # Parameters
+   * - `proposer`: The address of the user proposing the bet
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round to bet on
+   * - `position`: The side of the proposer, the taker gets the opposite side
+   * - `stake`: The amount staked by the proposer
+   * - `counter_stake`: The amount the taker should stake, equal to the stake if not set
+   * - `counterparty`: The only user allowed to accept the bet, anyone if not set
+   * # Returns
+   * - `u32`: The id of the bet
+   * # Events
+   * - `P2P_BET_PROPOSED`: Emitted when the bet is proposed
+   */
+  propose_p2p_bet: (
+    {
+      proposer,
+      series_id,
+      epoch,
+      position,
+      stake,
+      counter_stake,
+      counterparty,
+    }: {
+      proposer: string;
+      series_id: u32;
+      epoch: u128;
+      position: Position;
+      stake: i128;
+      counter_stake: Option<i128>;
+      counterparty: Option<string>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a accept_p2p_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to accept a peer-to-peer bet on the opposite side, escrowing the counter stake
+   * # Parameters
+   * - `taker`: The address of the user accepting the bet
+   * - `bet_id`: The id of the bet
+   * # Events
+   * - `P2P_BET_ACCEPTED`: Emitted when the bet is accepted
+   */
+  accept_p2p_bet: (
+    { taker, bet_id }: { taker: string; bet_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a cancel_p2p_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to cancel a peer-to-peer bet that was not accepted and refund the stake
+   * # Parameters
+   * - `bet_id`: The id of the bet
+   * # Events
+   * - `P2P_BET_CANCELLED`: Emitted when the bet is cancelled
+   */
+  cancel_p2p_bet: (
+    { bet_id }: { bet_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a settle_p2p_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to settle a matched peer-to-peer bet with the outcome of its round
+   * The winner takes both stakes minus the treasury fee, both stakes are refunded
+   * if the round ends without a winning side or is never ended
+   * Callable by anyone
+   * # Parameters
+   * - `bet_id`: The id of the bet
+   * # Events
+   * - `P2P_BET_SETTLED`: Emitted when the bet is settled
+   */
+  settle_p2p_bet: (
+    { bet_id }: { bet_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a self_exclude transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function for a user to lock themselves out of betting until a timestamp
+   * An exclusion can be extended but not shortened
+   * # Parameters
+   * - `user`: The address of the user
+   * - `until_timestamp`: The time betting is allowed again
+   * # Events
+   * - `SELF_EXCLUDED`: Emitted when the exclusion is set
+   */
+  self_exclude: (
+    { user, until_timestamp }: { user: string; until_timestamp: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_user_limit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function for a user to set the maximum amount they can wager per day, valued in the betting token
+   * A tighter limit applies at once, a looser one only after a cooldown of 7 days
+   * # Parameters
+   * - `user`: The address of the user
+   * - `max_wager_per_day`: The maximum amount wagered per day, 0 for no limit
+   * # Events
+   * - `USER_LIMIT_UPDATED`: Emitted when the limit is updated
+   */
+  set_user_limit: (
+    { user, max_wager_per_day }: { user: string; max_wager_per_day: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a flash_loan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Flash loan function to borrow tokens temporarily
+   * Loans are backed by the house vault liquidity and the fee accrues to vault LPs
+   * # Parameters
+   * - `initiator`: The address requesting the loan
+   * - `amount`: The amount of tokens to borrow
+   * - `receiver`: The address of the receiver of the tokens
+   */
+  flash_loan: (
+    {
+      initiator,
+      amount,
+      receiver,
+    }: {
+      initiator: string;
+      amount: i128;
+      receiver: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a vault_deposit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to deposit betting tokens into the house vault
+   * # Parameters
+   * - `lp`: The address of the liquidity provider
+   * - `amount`: The amount of tokens to deposit
+   * # Returns
+   * - `i128`: The amount of vault shares minted
+   * # Events
+   * - `VAULT_DEPOSIT`: Emitted when tokens are deposited into the vault
+   */
+  vault_deposit: (
+    { lp, amount }: { lp: string; amount: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a vault_withdraw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to redeem vault shares for betting tokens
+   * # Parameters
+   * - `lp`: The address of the liquidity provider
+   * - `shares`: The amount of vault shares to redeem
+   * # Returns
+   * - `i128`: The amount of tokens withdrawn
+   * # Events
+   * - `VAULT_WITHDRAW`: Emitted when tokens are withdrawn from the vault
+   */
+  vault_withdraw: (
+    { lp, shares }: { lp: string; shares: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a create_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to create a new series of rounds with its own timeframe
+   * Series share the token, treasury and oracle configuration
+   * Only callable by the owner
+   * # Parameters
+   * - `interval_seconds`: Duration of each round of the series in seconds
+   * - `buffer_seconds`: Buffer to execute the rounds of the series in seconds
+   * # Returns
+   * - `u32`: The id of the new series
+   * # Events
+   * - `SERIES_CREATED`: Emitted when a series is created
+   */
+  create_series: (
+    {
+      interval_seconds,
+      buffer_seconds,
+    }: {
+      interval_seconds: u64;
+      buffer_seconds: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a create_bucket_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to create a new series whose rounds are bet on price buckets instead of up/down
+   * Boundaries split the price change from lock to close into buckets, a change equal
+   * to a boundary falls in the bucket above it
+   * Only callable by the owner
+   * # Parameters
+   * - `interval_seconds`: Duration of each round of the series in seconds
+   * - `buffer_seconds`: Buffer to execute the rounds of the series in seconds
+   * - `boundaries`: Ascending price changes relative to the lock price (scaled by 100, e.g., -100 = -1%)
+   * # Returns
+   * - `u32`: The id of the new series
+   * # Events
+   * - `SERIES_CREATED`: Emitted when a series is created
+   */
+  create_bucket_series: (
+    {
+      interval_seconds,
+      buffer_seconds,
+      boundaries,
+    }: {
+      interval_seconds: u64;
+      buffer_seconds: u64;
+      boundaries: Array<i32>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a create_strike_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to create a new series of strike rounds
+   * Strike rounds are created one by one with their own strike price and expiry, and
+   * several of them can be open at the same time
+   * Only callable by the owner
+   * # Parameters
+   * - `buffer_seconds`: Buffer to settle a strike round after its expiry in seconds
+   * # Returns
+   * - `u32`: The id of the new series
+   * # Events
+   * - `SERIES_CREATED`: Emitted when a series is created
+   */
+  create_strike_series: (
+    { buffer_seconds }: { buffer_seconds: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a create_head_to_head_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to create a new series whose rounds are bet on which of two oracle assets
+   * has the higher percentage change between lock and close
+   * Only callable by the owner
+   * # Parameters
+   * - `interval_seconds`: Duration of each round of the series in seconds
+   * - `buffer_seconds`: Buffer to execute the rounds of the series in seconds
+   * - `asset`: The first asset, pooled on the bull side of the rounds
+   * - `rival_asset`: The second asset, pooled on the bear side of the rounds
+   * # Returns
+   * - `u32`: The id of the new series
+   * # Events
+   * - `SERIES_CREATED`: Emitted when a series is created
+   */
+  create_head_to_head_series: (
+    {
+      interval_seconds,
+      buffer_seconds,
+      asset,
+      rival_asset,
+    }: {
+      interval_seconds: u64;
+      buffer_seconds: u64;
+      asset: string;
+      rival_asset: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a create_volatility_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to create a new series whose rounds are bet on the size of the price move
+   * A round is a big move when the price changes from lock to close by more than the threshold
+   * in either direction, and a small move otherwise
+   * Only callable by the owner
+   * # Parameters
+   * - `interval_seconds`: Duration of each round of the series in seconds
+   * - `buffer_seconds`: Buffer to execute the rounds of the series in seconds
+   * - `move_threshold`: The price change separating small and big moves (scaled by 100, e.g., 100 = 1%)
+   * # Returns
+   * - `u32`: The id of the new series
+   * # Events
+   * - `SERIES_CREATED`: Emitted when a series is created
+   */
+  create_volatility_series: (
+    {
+      interval_seconds,
+      buffer_seconds,
+      move_threshold,
+    }: {
+      interval_seconds: u64;
+      buffer_seconds: u64;
+      move_threshold: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a create_event_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to create a new series of yes/no event rounds settled by resolvers
+   * Event rounds are created one by one, each with its own question and resolver
+   * Only callable by the owner
+   * # Parameters
+   * - `buffer_seconds`: Time after the close of a round to resolve and settle it, after which bets are refunded
+   * - `dispute_seconds`: Time after a resolution during which the owner can override it
+   * # Returns
+   * - `u32`: The id of the new series
+   * # Events
+   * - `SERIES_CREATED`: Emitted when a series is created
+   */
+  create_event_series: (
+    {
+      buffer_seconds,
+      dispute_seconds,
+    }: {
+      buffer_seconds: u64;
+      dispute_seconds: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a set_vault_cooldown_seconds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the cooldown between vault deposits and withdrawals
+   * Only callable by the owner
+   * # Parameters
+   * - `cooldown_seconds`: The cooldown in seconds
+   */
+  set_vault_cooldown_seconds: (
+    { cooldown_seconds }: { cooldown_seconds: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_vault_treasury_fee_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the share of the treasury fee routed to the vault
+   * Only callable by the owner
+   * # Parameters
+   * - `fee_share`: Share of the treasury fee (scaled by 100, e.g., 5000 = 50%)
+   */
+  set_vault_treasury_fee_share: (
+    { fee_share }: { fee_share: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a add_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to allowlist an additional betting token
+   * Only callable by the owner
+   * # Parameters
+   * - `token`: The address of the SAC or fungible token
+   * - `min_bet_amount`: Minimum amount required to place a bet with this token
+   * - `rate`: The value of the token in the betting token (scaled by 10_000_000), used to
+   * hold its bets to the caps
+   * # Events
+   * - `TOKEN_ADDED`: Emitted when a token is allowlisted
+   */
+  add_token: (
+    {
+      token,
+      min_bet_amount,
+      rate,
+    }: {
+      token: string;
+      min_bet_amount: i128;
+      rate: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_token_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to update the rate of an allowlisted token as its price moves
+   * Only callable by the owner
+   * # Parameters
+   * - `token`: The address of the token
+   * - `rate`: The value of the token in the betting token (scaled by 10_000_000)
+   * # Events
+   * - `TOKEN_RATE_UPDATED`: Emitted when the rate is updated
+   */
+  set_token_rate: (
+    { token, rate }: { token: string; rate: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a remove_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to remove a token from the allowlist
+   * Existing bets placed with the token are unaffected
+   * Only callable by the owner
+   * # Parameters
+   * - `token`: The address of the token
+   * # Events
+   * - `TOKEN_REMOVED`: Emitted when a token is removed from the allowlist
+   */
+  remove_token: (
+    { token }: { token: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_access_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the access mode gating bets, flash loans and deposits
+   * Claims and refunds of placed bets are never gated
+   * Only callable by the owner
+   * # Parameters
+   * - `mode`: Open to everyone, closed to blocked addresses, or open to allowed addresses only
+   * # Events
+   * - `ACCESS_MODE_UPDATED`: Emitted when the access mode is updated
+   */
+  set_access_mode: (
+    { mode }: { mode: AccessMode },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_compliance_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the compliance manager maintaining the access lists
+   * Only callable by the owner
+   * # Parameters
+   * - `manager`: The address of the compliance manager
+   * # Events
+   * - `COMPLIANCE_MANAGER_UPDATED`: Emitted when the compliance manager is updated
+   */
+  set_compliance_manager: (
+    { manager }: { manager: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_blocked transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to add or remove an address from the blocklist
+   * Blocked addresses are refused in the blocklist and allowlist modes
+   * Only callable by the compliance manager
+   * # Parameters
+   * - `user`: The address to update
+   * - `blocked`: Whether the address is blocked
+   * # Events
+   * - `BLOCKLIST_UPDATED`: Emitted when the blocklist is updated
+   */
+  set_blocked: (
+    { user, blocked }: { user: string; blocked: boolean },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_allowed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to add or remove an address from the allowlist
+   * Only callable by the compliance manager
+   * # Parameters
+   * - `user`: The address to update
+   * - `allowed`: Whether the address is allowed
+   * # Events
+   * - `ALLOWLIST_UPDATED`: Emitted when the allowlist is updated
+   */
+  set_allowed: (
+    { user, allowed }: { user: string; allowed: boolean },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_operator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the operator relaying signed bet intents
+   * Only callable by the owner
+   * # Parameters
+   * - `operator`: The address of the operator
+   */
+  set_operator: (
+    { operator }: { operator: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_referral_fee_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the share of the treasury fee paid to referrers
+   * Only callable by the owner
+   * # Parameters
+   * - `fee_share`: Share of the treasury fee (scaled by 100, e.g., 2000 = 20%)
+   */
+  set_referral_fee_share: (
+    { fee_share }: { fee_share: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a create_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to create a season over a range of future epochs of a series
+   * Only callable by the owner
+   * # Parameters
+   * - `series_id`: The id of the series the season follows
+   * - `start_epoch`: The first epoch of the season
+   * - `end_epoch`: The last epoch of the season
+   * - `metric`: The metric ranking the season leaderboard
+   * - `payout_table`: Share of the prize pool per rank (scaled by 100, e.g., 5000 = 50%)
+   * # Returns
+   * - `u32`: The id of the new season
+   * # Events
+   * - `SEASON_CREATED`: Emitted when a season is created
+   */
+  create_season: (
+    {
+      series_id,
+      start_epoch,
+      end_epoch,
+      metric,
+      payout_table,
+    }: {
+      series_id: u32;
+      start_epoch: u128;
+      end_epoch: u128;
+      metric: RankingMetric;
+      payout_table: Array<u32>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a fund_season_from_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to move part of the treasury into the prize pool of a season
+   * Only callable by the owner
+   * # Parameters
+   * - `season_id`: The id of the season
+   * - `amount`: The amount of tokens to move
+   * # Events
+   * - `SEASON_FUNDED`: Emitted when the prize pool is funded
+   */
+  fund_season_from_treasury: (
+    { season_id, amount }: { season_id: u32; amount: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a finalize_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to freeze the leaderboard of an ended season and open prize claims
+   * Allowed once every round of the season has ended and the grace period after the last one
+   * to close has passed, so that stats of every entrant can be settled with `sync_user_stats`
+   * Only users with a positive score fill the payout slots
+   * Only callable by the owner
+   * # Parameters
+   * - `season_id`: The id of the season
+   * # Events
+   * - `SEASON_FINALIZED`: Emitted when the season is finalized
+   */
+  finalize_season: (
+    { season_id }: { season_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a sweep_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to move the unallocated and unclaimed prizes of a season back to the treasury
+   * Only callable by the owner, once the claim period after finalization has passed
+   * # Parameters
+   * - `season_id`: The id of the season
+   * # Events
+   * - `SEASON_SWEPT`: Emitted when the season is swept
+   */
+  sweep_season: (
+    { season_id }: { season_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_jackpot_fee_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the share of the treasury fee funding the jackpot
+   * Only callable by the owner
+   * # Parameters
+   * - `fee_share`: Share of the treasury fee (scaled by 100, e.g., 1000 = 10%)
+   */
+  set_jackpot_fee_share: (
+    { fee_share }: { fee_share: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_jackpot_interval transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the number of rounds between jackpot draws
+   * Only callable by the owner
+   * # Parameters
+   * - `interval`: The number of rounds per jackpot period
+   */
+  set_jackpot_interval: (
+    { interval }: { interval: u128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_standing_order_min_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the minimum escrow of a new standing order in the betting token
+   * Raises the cost of filling the standing order slots of a series
+   * Only callable by the owner
+   * # Parameters
+   * - `min_escrow`: The minimum of the amount times the rounds of an order, 0 for none
+   */
+  set_standing_order_min_escrow: (
+    { min_escrow }: { min_escrow: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_fee_curve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the fee curve replacing the flat treasury fee
+   * The fee of a round goes from the minimum fee on a balanced pool to the maximum fee
+   * on a one-sided pool
+   * Only callable by the owner
+   * # Parameters
+   * - `fee_curve`: The minimum and maximum fees (scaled by 100), None to use the flat treasury fee
+   */
+  set_fee_curve: (
+    { fee_curve }: { fee_curve: Option<FeeCurve> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_house_odds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the fixed odds quoted by the house on each side of a series
+   * Only callable by the owner
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `bull_odds`: The odds of the bull side (scaled by 10_000, e.g., 19_000 = 1.9x), 0 to disable
+   * - `bear_odds`: The odds of the bear side (scaled by 10_000, e.g., 19_000 = 1.9x), 0 to disable
+   */
+  set_house_odds: (
+    {
+      series_id,
+      bull_odds,
+      bear_odds,
+    }: {
+      series_id: u32;
+      bull_odds: u32;
+      bear_odds: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_house_limits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the risk budget of the house for fixed-odds bets
+   * Only callable by the owner
+   * # Parameters
+   * - `max_side_exposure`: The maximum payout owed to one side of a round
+   * - `max_round_exposure`: The maximum loss of the house on a round
+   */
+  set_house_limits: (
+    {
+      max_side_exposure,
+      max_round_exposure,
+    }: {
+      max_side_exposure: i128;
+      max_round_exposure: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_max_bet_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the maximum amount of a single bet, bets in other tokens are valued in
+   * the betting token
+   * Only callable by the owner
+   * # Parameters
+   * - `max_bet_amount`: The maximum bet amount, 0 for no cap
+   */
+  set_max_bet_amount: (
+    { max_bet_amount }: { max_bet_amount: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_max_round_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the maximum total of a round across its token pools, valued in the betting token
+   * Only callable by the owner
+   * # Parameters
+   * - `max_round_amount`: The maximum total amount bet on a round, 0 for no cap
+   */
+  set_max_round_amount: (
+    { max_round_amount }: { max_round_amount: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_user_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Function to set the rolling cap of every user, valued in the betting token
+   * A user can bet at most `amount` over the last `rounds` rounds of a series
+   * Only callable by the owner
+   * # Parameters
+   * - `amount`: The maximum amount bet over the window, 0 for no cap
+   * - `rounds`: The number of rounds in the window, at most 100
+   */
+  set_user_cap: (
+    { amount, rounds }: { amount: i128; rounds: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a get_xlm_oracle_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Internal function to get XLM price from the oracle
+   * # Returns
+   * - `i128`: XLM price in stroops
+   */
+  get_xlm_oracle_price: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_market_asset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_market_asset: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a get_series_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_series_count: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_series_buckets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the bucket boundaries of a series
+   * # Parameters
+   * - `series_id`: The id of the series
+   * # Returns
+   * - `Vec<i32>`: The boundaries, empty for an up/down series
+   */
+  get_series_buckets: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<i32>>>;
+
+  /**
+   * Construct and simulate a get_series_assets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the assets of a head-to-head series
+   * # Parameters
+   * - `series_id`: The id of the series
+   * # Returns
+   * - `Vec<Symbol>`: The first and second asset, empty for a single asset series
+   */
+  get_series_assets: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<string>>>;
+
+  /**
+   * Construct and simulate a get_move_threshold transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the move threshold of a volatility series
+   * # Parameters
+   * - `series_id`: The id of the series
+   * # Returns
+   * - `u32`: The threshold (scaled by 100), 0 for a directional series
+   */
+  get_move_threshold: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a is_event_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_event_series: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a get_event_dispute_seconds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_event_dispute_seconds: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a get_event_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_event_info: (
+    { series_id, epoch }: { series_id: u32; epoch: u128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<EventInfo>>;
+
+  /**
+   * Construct and simulate a is_strike_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_strike_series: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a get_is_genesis_started transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_is_genesis_started: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a get_is_genesis_locked transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_is_genesis_locked: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a get_current_epoch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_current_epoch: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u128>>;
+
+  /**
+   * Construct and simulate a get_token_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_token_address: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a get_min_bet_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_min_bet_amount: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_treasury_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_treasury_fee: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_treasury_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_treasury_amount: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_round: (
+    { series_id, epoch }: { series_id: u32; epoch: u128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Round>>;
+
+  /**
+   * Construct and simulate a get_bet_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_bet_info: (
+    { series_id, epoch, user }: { series_id: u32; epoch: u128; user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<BetInfo>>;
+
+  /**
+   * Construct and simulate a get_user_rounds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_user_rounds: (
+    { series_id, user }: { series_id: u32; user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<u128>>>;
+
+  /**
+   * Construct and simulate a get_oracle_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_oracle_address: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a get_interval_seconds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_interval_seconds: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a get_buffer_seconds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_buffer_seconds: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a get_allowed_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the tokens accepted besides the betting token
+   */
+  get_allowed_tokens: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>;
+
+  /**
+   * Construct and simulate a get_token_min_bet_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the minimum bet amount of an accepted token
+   * # Parameters
+   * - `token`: The address of the token
+   */
+  get_token_min_bet_amount: (
+    { token }: { token: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_token_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the value of a token in the betting token
+   * # Parameters
+   * - `token`: The address of the token
+   * # Returns
+   * - `i128`: The rate of the token (scaled by 10_000_000)
+   */
+  get_token_rate: (
+    { token }: { token: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_token_treasury_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the treasury amount collected in a token
+   * # Parameters
+   * - `token`: The address of the token
+   */
+  get_token_treasury_amount: (
+    { token }: { token: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the internal balance of a user
+   * # Parameters
+   * - `user`: The address of the user
+   * - `token`: The address of the token
+   */
+  get_balance: (
+    { user, token }: { user: string; token: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a claimable transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to check if a user can claim winnings for a round
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round
+   * - `user`: The address of the user
+   */
+  claimable: (
+    { series_id, epoch, user }: { series_id: u32; epoch: u128; user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a refundable transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to check if a user can get a refund for a round
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round
+   * - `user`: The address of the user
+   */
+  refundable: (
+    { series_id, epoch, user }: { series_id: u32; epoch: u128; user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a get_referrer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_referrer: (
+    { user }: { user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a get_referral_fee_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_referral_fee_share: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_gift_payers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the payers allowed to gift bets to a beneficiary
+   * # Parameters
+   * - `beneficiary`: The address receiving the gifted bets
+   * # Returns
+   * - `Vec<Address>`: The allowed payers, empty when any payer is accepted
+   */
+  get_gift_payers: (
+    { beneficiary }: { beneficiary: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<string>>>;
+
+  /**
+   * Construct and simulate a get_referral_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the referral stats of a referrer
+   * Earned and claimable amounts are tracked per token
+   * # Parameters
+   * - `referrer`: The address of the referrer
+   */
+  get_referral_stats: (
+    { referrer }: { referrer: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<ReferralStats>>;
+
+  /**
+   * Construct and simulate a get_season_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_season_count: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_season: (
+    { season_id }: { season_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Season>>;
+
+  /**
+   * Construct and simulate a get_season_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the season an epoch of a series counts toward
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round
+   * # Returns
+   * - `u32`: The id of the season, or 0 when the epoch is outside every season
+   */
+  get_season_id: (
+    { series_id, epoch }: { series_id: u32; epoch: u128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_user_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the lifetime stats of a user
+   * Wins, losses and net profit only include rounds settled into the stats, see `sync_user_stats`
+   * # Parameters
+   * - `user`: The address of the user
+   */
+  get_user_stats: (
+    { user }: { user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<UserStats>>;
+
+  /**
+   * Construct and simulate a get_season_user_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the stats of a user within a season
+   * Win streaks are only tracked in lifetime stats
+   * # Parameters
+   * - `season_id`: The id of the season
+   * - `user`: The address of the user
+   */
+  get_season_user_stats: (
+    { season_id, user }: { season_id: u32; user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<UserStats>>;
+
+  /**
+   * Construct and simulate a get_leaderboard transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the top users of a season, best first
+   * The leaderboard keeps the top 10 entries and is only updated when a user's own score changes,
+   * so a user pushed out of it does not come back when the users above lose ground, only on the
+   * next settled round of its own. Off-chain rankings should read `get_season_user_stats`
+   * # Parameters
+   * - `season_id`: The id of the season
+   */
+  get_leaderboard: (
+    { season_id }: { season_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<LeaderboardEntry>>>;
+
+  /**
+   * Construct and simulate a get_jackpot_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_jackpot_amount: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_jackpot_fee_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_jackpot_fee_share: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_jackpot_interval transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_jackpot_interval: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u128>>;
+
+  /**
+   * Construct and simulate a get_jackpot_entries transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the stake of every bettor in a jackpot period
+   * Only stakes of settled rounds enter the draw of their period
+   * # Parameters
+   * - `period`: The jackpot period, `(epoch - 1) / interval`
+   */
+  get_jackpot_entries: (
+    { period }: { period: u128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Map<string, i128>>>;
+
+  /**
+   * Construct and simulate a get_jackpot_round_entries transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the stake of every bettor in a round of the default series
+   * that is not settled yet
+   * # Parameters
+   * - `epoch`: The epoch of the round
+   */
+  get_jackpot_round_entries: (
+    { epoch }: { epoch: u128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Map<string, i128>>>;
+
+  /**
+   * Construct and simulate a get_self_exclusion transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_self_exclusion: (
+    { user }: { user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a get_user_limit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_user_limit: (
+    { user }: { user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<UserLimit>>>;
+
+  /**
+   * Construct and simulate a get_user_wager_limit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the daily wager limit of a user currently in force
+   * # Parameters
+   * - `user`: The address of the user
+   * # Returns
+   * - `i128`: The maximum amount wagered per day, 0 for no limit
+   */
+  get_user_wager_limit: (
+    { user }: { user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_user_daily_wager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the amount a user wagered today, valued in the betting token
+   * # Parameters
+   * - `user`: The address of the user
+   */
+  get_user_daily_wager: (
+    { user }: { user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_fee_curve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_fee_curve: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<FeeCurve>>>;
+
+  /**
+   * Construct and simulate a get_max_bet_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_max_bet_amount: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_max_round_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_max_round_amount: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_user_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_user_cap: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<UserCap>>;
+
+  /**
+   * Construct and simulate a get_user_rolling_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the amount a user bet over the rolling cap window, valued in the betting token
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The last epoch of the window
+   * - `user`: The address of the user
+   */
+  get_user_rolling_amount: (
+    { series_id, epoch, user }: { series_id: u32; epoch: u128; user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_house_odds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_house_odds: (
+    { series_id }: { series_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<HouseOdds>>;
+
+  /**
+   * Construct and simulate a get_house_max_side_exposure transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_house_max_side_exposure: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_house_max_round_exposure transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_house_max_round_exposure: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_house_exposure transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the vault assets reserved for the worst case of open fixed-odds rounds
+   */
+  get_house_exposure: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
 
   /**
-   * Construct and simulate a bet_bull transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Function to place a bet on the bull side
-   * # Parameters
-   * - `epoch`: The epoch of the round to bet on
-   * - `user`: The address of the user placing the bet
-   * - `amount`: The amount of tokens to bet
-   * # Events
-   * - `BET_PLACED`: Emitted when a bet is placed
+   * Construct and simulate a get_fixed_odds_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  bet_bull: (
-    { epoch, user, amount }: { epoch: u128; user: string; amount: i128 },
+  get_fixed_odds_bet: (
+    { series_id, epoch, user }: { series_id: u32; epoch: u128; user: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -253,20 +4801,13 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Option<FixedOddsBet>>>;
 
   /**
-   * Construct and simulate a bet_bear transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Function to place a bet on the bear side
-   * # Parameters
-   * - `epoch`: The epoch of the round to bet on
-   * - `user`: The address of the user placing the bet
-   * - `amount`: The amount of tokens to bet
-   * # Events
-   * - `BET_PLACED`: Emitted when a bet is placed
+   * Construct and simulate a get_standing_order transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  bet_bear: (
-    { epoch, user, amount }: { epoch: u128; user: string; amount: i128 },
+  get_standing_order: (
+    { user }: { user: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -283,17 +4824,13 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Option<StandingOrder>>>;
 
   /**
-   * Construct and simulate a flash_loan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Flash loan function to borrow tokens temporarily
-   * # Parameters
-   * - `amount`: The amount of tokens to borrow
-   * - `receiver`: The address of the receiver of the tokens
+   * Construct and simulate a get_standing_order_users transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  flash_loan: (
-    { amount, receiver }: { amount: i128; receiver: string },
+  get_standing_order_users: (
+    { series_id }: { series_id: u32 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -310,15 +4847,12 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Array<string>>>;
 
   /**
-   * Construct and simulate a get_xlm_oracle_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Internal function to get XLM price from the oracle
-   * # Returns
-   * - `i128`: XLM price in stroops
+   * Construct and simulate a get_standing_order_min_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_xlm_oracle_price: (options?: {
+  get_standing_order_min_escrow: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -336,9 +4870,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<i128>>;
 
   /**
-   * Construct and simulate a get_is_genesis_started transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_p2p_bet_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_is_genesis_started: (options?: {
+  get_p2p_bet_count: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -353,32 +4887,35 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>;
+  }) => Promise<AssembledTransaction<u32>>;
 
   /**
-   * Construct and simulate a get_is_genesis_locked transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_p2p_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_is_genesis_locked: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_p2p_bet: (
+    { bet_id }: { bet_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<P2PBet>>;
 
   /**
-   * Construct and simulate a get_current_epoch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_operator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_current_epoch: (options?: {
+  get_operator: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -393,12 +4930,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u128>>;
+  }) => Promise<AssembledTransaction<string>>;
 
   /**
-   * Construct and simulate a get_token_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_access_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_token_address: (options?: {
+  get_access_mode: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -413,12 +4950,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>;
+  }) => Promise<AssembledTransaction<AccessMode>>;
 
   /**
-   * Construct and simulate a get_min_bet_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_compliance_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_min_bet_amount: (options?: {
+  get_compliance_manager: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -433,53 +4970,62 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>;
+  }) => Promise<AssembledTransaction<string>>;
 
   /**
-   * Construct and simulate a get_treasury_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a is_blocked transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_treasury_fee: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  is_blocked: (
+    { user }: { user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
 
   /**
-   * Construct and simulate a get_treasury_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a is_allowed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_treasury_amount: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  is_allowed: (
+    { user }: { user: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
 
   /**
-   * Construct and simulate a get_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a has_access transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to check if an address can bet, take flash loans and deposit
+   * # Parameters
+   * - `user`: The address to check
    */
-  get_round: (
-    { epoch }: { epoch: u128 },
+  has_access: (
+    { user }: { user: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -496,13 +5042,13 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Round>>;
+  ) => Promise<AssembledTransaction<boolean>>;
 
   /**
-   * Construct and simulate a get_bet_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_signing_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_bet_info: (
-    { epoch, user }: { epoch: u128; user: string },
+  get_signing_key: (
+    { user }: { user: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -519,12 +5065,15 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<BetInfo>>;
+  ) => Promise<AssembledTransaction<Option<Buffer>>>;
 
   /**
-   * Construct and simulate a get_user_rounds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_intent_nonce transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the nonce expected in the next intent of a user
+   * # Parameters
+   * - `user`: The address of the user
    */
-  get_user_rounds: (
+  get_intent_nonce: (
     { user }: { user: string },
     options?: {
       /**
@@ -542,12 +5091,67 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Array<u128>>>;
+  ) => Promise<AssembledTransaction<u64>>;
 
   /**
-   * Construct and simulate a get_oracle_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_bet_intent_payload transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the bytes a user signs for a bet intent
+   * The payload binds the intent to this contract to prevent cross-contract replay
+   * # Parameters
+   * - `intent`: The bet intent to sign
    */
-  get_oracle_address: (options?: {
+  get_bet_intent_payload: (
+    { intent }: { intent: BetIntent },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a get_round_token_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Readonly function to get the pool of a token in a round
+   * # Parameters
+   * - `series_id`: The id of the series
+   * - `epoch`: The epoch of the round
+   * - `token`: The address of the token
+   */
+  get_round_token_pool: (
+    { series_id, epoch, token }: { series_id: u32; epoch: u128; token: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<TokenPool>>;
+
+  /**
+   * Construct and simulate a get_flash_treasury_amount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_flash_treasury_amount: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -562,12 +5166,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>;
+  }) => Promise<AssembledTransaction<i128>>;
 
   /**
-   * Construct and simulate a get_interval_seconds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_vault_total_assets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_interval_seconds: (options?: {
+  get_vault_total_assets: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -582,12 +5186,55 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>;
+  }) => Promise<AssembledTransaction<i128>>;
 
   /**
-   * Construct and simulate a get_buffer_seconds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_vault_total_shares transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_vault_total_shares: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a get_vault_position transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_vault_position: (
+    { lp }: { lp: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<VaultPosition>>;
+
+  /**
+   * Construct and simulate a get_vault_cooldown_seconds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_buffer_seconds: (options?: {
+  get_vault_cooldown_seconds: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -604,16 +5251,37 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<u64>>;
 
+  /**
+   * Construct and simulate a get_vault_treasury_fee_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_vault_treasury_fee_share: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
   /**
    * Construct and simulate a is_bettable transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Readonly function to check if a round is bettable
    * # Parameters
+   * - `series_id`: The id of the series
    * - `epoch`: The epoch of the round to check
    * # Returns
    * - `bool`: True if the round is bettable, false otherwise
    */
   is_bettable: (
-    { epoch }: { epoch: u128 },
+    { series_id, epoch }: { series_id: u32; epoch: u128 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -659,7 +5327,10 @@ export interface Client {
     {
       new_owner,
       live_until_ledger,
-    }: { new_owner: string; live_until_ledger: u32 },
+    }: {
+      new_owner: string;
+      live_until_ledger: u32;
+    },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -730,6 +5401,7 @@ export class Client extends ContractClient {
       treasury_fee,
       flash_loan_fee,
       oracle_address,
+      asset,
     }: {
       owner: string;
       intervals_seconds: u64;
@@ -739,6 +5411,7 @@ export class Client extends ContractClient {
       treasury_fee: u32;
       flash_loan_fee: u32;
       oracle_address: string;
+      asset: string;
     },
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
@@ -761,6 +5434,7 @@ export class Client extends ContractClient {
         treasury_fee,
         flash_loan_fee,
         oracle_address,
+        asset,
       },
       options,
     );
@@ -768,33 +5442,197 @@ export class Client extends ContractClient {
   constructor(public readonly options: ContractClientOptions) {
     super(
       new ContractSpec([
-        "AAAAAwAAAAAAAAAAAAAABUVycm9yAAAAAAAAHAAAAAAAAAANTm90QXV0aG9yaXplZAAAAAAAAAEAAAAAAAAAC05vdE9wZXJhdG9yAAAAAAIAAAAAAAAACE5vdEFkbWluAAAAAwAAAAAAAAASQWxyZWFkeUluaXRpYWxpemVkAAAAAAAEAAAAAAAAAA5Ob3RJbml0aWFsaXplZAAAAAAABQAAAAAAAAARQmV0VG9vRWFybHlPckxhdGUAAAAAAAAGAAAAAAAAABBSb3VuZE5vdEJldHRhYmxlAAAABwAAAAAAAAAPQmV0QW1vdW50VG9vTG93AAAAAAgAAAAAAAAACkFscmVhZHlCZXQAAAAAAAkAAAAAAAAAD1JvdW5kTm90U3RhcnRlZAAAAAAKAAAAAAAAAA1Sb3VuZE5vdEVuZGVkAAAAAAAACwAAAAAAAAATTm90RWxpZ2libGVGb3JDbGFpbQAAAAAMAAAAAAAAABROb3RFbGlnaWJsZUZvclJlZnVuZAAAAA0AAAAAAAAAE0dlbmVzaXNOb3RUcmlnZ2VyZWQAAAAADgAAAAAAAAAXR2VuZXNpc0FscmVhZHlUcmlnZ2VyZWQAAAAADwAAAAAAAAASVHJlYXN1cnlGZWVUb29IaWdoAAAAAAAQAAAAAAAAABVJbnZhbGlkQnVmZmVySW50ZXJ2YWwAAAAAAAARAAAAAAAAAA1JbnZhbGlkQW1vdW50AAAAAAAAEgAAAAAAAAAOSW52YWxpZEFkZHJlc3MAAAAAABMAAAAAAAAAElJvdW5kQWxyZWFkeUxvY2tlZAAAAAAAFAAAAAAAAAAOUm91bmROb3RMb2NrZWQAAAAAABUAAAAAAAAADU91dHNpZGVCdWZmZXIAAAAAAAAWAAAAAAAAABhSZXdhcmRzQWxyZWFkeUNhbGN1bGF0ZWQAAAAXAAAAAAAAABRPcmFjbGVVcGRhdGVFeGNlZWRlZAAAABgAAAAAAAAAE09yYWNsZVJvdW5kSWRUb29Mb3cAAAAAGQAAAAAAAAAOVHJhbnNmZXJGYWlsZWQAAAAAABoAAAAAAAAABlBhdXNlZAAAAAAAGwAAAAAAAAAJTm90UGF1c2VkAAAAAAAAHA==",
-        "AAAAAgAAAAAAAAAAAAAACFBvc2l0aW9uAAAAAgAAAAAAAAAAAAAABEJ1bGwAAAAAAAAAAAAAAARCZWFy",
-        "AAAAAQAAAAAAAAAAAAAAB0JldEluZm8AAAAAAwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAdjbGFpbWVkAAAAAAEAAAAAAAAACHBvc2l0aW9uAAAH0AAAAAhQb3NpdGlvbg==",
-        "AAAAAQAAAAAAAAAAAAAABVJvdW5kAAAAAAAACwAAAAAAAAALYmVhcl9hbW91bnQAAAAACwAAAAAAAAALYnVsbF9hbW91bnQAAAAACwAAAAAAAAALY2xvc2VfcHJpY2UAAAAACwAAAAAAAAAPY2xvc2VfdGltZXN0YW1wAAAAAAYAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAKbG9ja19wcmljZQAAAAAACwAAAAAAAAAObG9ja190aW1lc3RhbXAAAAAAAAYAAAAAAAAADXJld2FyZF9hbW91bnQAAAAAAAALAAAAAAAAABZyZXdhcmRfYmFzZV9jYWxfYW1vdW50AAAAAAALAAAAAAAAAA9zdGFydF90aW1lc3RhbXAAAAAABgAAAAAAAAAMdG90YWxfYW1vdW50AAAACw==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAEAAAAAAAAAAAAAAABVRva2VuAAAAAAAAAAAAAAAAAAANT3JhY2xlQWRkcmVzcwAAAAAAAAAAAAAAAAAAD0ludGVydmFsU2Vjb25kcwAAAAAAAAAAAAAAAA1CdWZmZXJTZWNvbmRzAAAAAAAAAAAAAAAAAAAMTWluQmV0QW1vdW50AAAAAAAAAAAAAAALVHJlYXN1cnlGZWUAAAAAAAAAAAAAAAAOVHJlYXN1cnlBbW91bnQAAAAAAAAAAAAAAAAADEN1cnJlbnRFcG9jaAAAAAAAAAAAAAAAEElzR2VuZXNpc1N0YXJ0ZWQAAAAAAAAAAAAAAA9Jc0dlbmVzaXNMb2NrZWQAAAAAAAAAAAAAAAAGUGF1c2VkAAAAAAAAAAAAAAAAAAtJbml0aWFsaXplZAAAAAABAAAAAAAAAAZSb3VuZHMAAAAAAAEAAAAKAAAAAQAAAAAAAAAIQmV0SW5mb3MAAAACAAAACgAAABMAAAABAAAAAAAAAApVc2VyUm91bmRzAAAAAAABAAAAEwAAAAAAAAAAAAAADEZsYXNoTG9hbkZlZQ==",
-        "AAAAAAAAAX1Db25zdHJ1Y3RvciB0byBpbml0aWFsaXplIHRoZSBQcmVkaWN0aW9uIE1hcmtldCBjb250cmFjdAojIFBhcmFtZXRlcnMKLSBgb3duZXJgOiBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBvd25lcgotIGBpbnRlcnZhbHNfc2Vjb25kc2A6IER1cmF0aW9uIG9mIGVhY2ggcHJlZGljdGlvbiByb3VuZCBpbiBzZWNvbmRzCi0gYG1pbl9iZXRfYW1vdW50YDogTWluaW11bSBhbW91bnQgcmVxdWlyZWQgdG8gcGxhY2UgYSBiZXQKLSBgdG9rZW5fYWRkcmVzc2A6IEFkZHJlc3Mgb2YgdGhlIHRva2VuIHVzZWQgZm9yIGJldHRpbmcKLSBgdHJlYXN1cnlfZmVlYDogRmVlIHBlcmNlbnRhZ2UgdGFrZW4gYnkgdGhlIHRyZWFzdXJ5IChzY2FsZWQgYnkgMTAwLCBlLmcuLCAxMDAgPSAxJSkAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAgAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAARaW50ZXJ2YWxzX3NlY29uZHMAAAAAAAAGAAAAAAAAAA5idWZmZXJfc2Vjb25kcwAAAAAABgAAAAAAAAAObWluX2JldF9hbW91bnQAAAAAAAsAAAAAAAAADXRva2VuX2FkZHJlc3MAAAAAAAATAAAAAAAAAAx0cmVhc3VyeV9mZWUAAAAEAAAAAAAAAA5mbGFzaF9sb2FuX2ZlZQAAAAAABAAAAAAAAAAOb3JhY2xlX2FkZHJlc3MAAAAAABMAAAAA",
-        "AAAAAAAAAD5GdW5jdGlvbiB0byBzdGFydCB0aGUgZ2VuZXNpcyByb3VuZApPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgAAAAAAE2dlbmVzaXNfc3RhcnRfcm91bmQAAAAAAAAAAAA=",
-        "AAAAAAAAAD1GdW5jdGlvbiB0byBsb2NrIHRoZSBnZW5lc2lzIHJvdW5kCk9ubHkgY2FsbGFibGUgYnkgdGhlIG93bmVyAAAAAAAAEmdlbmVzaXNfbG9ja19yb3VuZAAAAAAAAAAAAAA=",
-        "AAAAAAAAAAAAAAANZXhlY3V0ZV9yb3VuZAAAAAAAAAAAAAAA",
-        "AAAAAAAAAPFGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYnVsbCBzaWRlCiMgUGFyYW1ldGVycwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gYmV0IG9uCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlciBwbGFjaW5nIHRoZSBiZXQKLSBgYW1vdW50YDogVGhlIGFtb3VudCBvZiB0b2tlbnMgdG8gYmV0CiMgRXZlbnRzCi0gYEJFVF9QTEFDRURgOiBFbWl0dGVkIHdoZW4gYSBiZXQgaXMgcGxhY2VkAAAAAAAACGJldF9idWxsAAAAAwAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-        "AAAAAAAAAPFGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYmVhciBzaWRlCiMgUGFyYW1ldGVycwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gYmV0IG9uCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlciBwbGFjaW5nIHRoZSBiZXQKLSBgYW1vdW50YDogVGhlIGFtb3VudCBvZiB0b2tlbnMgdG8gYmV0CiMgRXZlbnRzCi0gYEJFVF9QTEFDRURgOiBFbWl0dGVkIHdoZW4gYSBiZXQgaXMgcGxhY2VkAAAAAAAACGJldF9iZWFyAAAAAwAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-        "AAAAAAAAAKBGbGFzaCBsb2FuIGZ1bmN0aW9uIHRvIGJvcnJvdyB0b2tlbnMgdGVtcG9yYXJpbHkKIyBQYXJhbWV0ZXJzCi0gYGFtb3VudGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHRvIGJvcnJvdwotIGByZWNlaXZlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSByZWNlaXZlciBvZiB0aGUgdG9rZW5zAAAACmZsYXNoX2xvYW4AAAAAAAIAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAIcmVjZWl2ZXIAAAATAAAAAA==",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAIgAAAAAAAAANTm90QXV0aG9yaXplZAAAAAAAAAEAAAAAAAAAC05vdE9wZXJhdG9yAAAAAAIAAAAAAAAACE5vdEFkbWluAAAAAwAAAAAAAAASQWxyZWFkeUluaXRpYWxpemVkAAAAAAAEAAAAAAAAAA5Ob3RJbml0aWFsaXplZAAAAAAABQAAAAAAAAARQmV0VG9vRWFybHlPckxhdGUAAAAAAAAGAAAAAAAAABBSb3VuZE5vdEJldHRhYmxlAAAABwAAAAAAAAAPQmV0QW1vdW50VG9vTG93AAAAAAgAAAAAAAAACkFscmVhZHlCZXQAAAAAAAkAAAAAAAAAD1JvdW5kTm90U3RhcnRlZAAAAAAKAAAAAAAAAA1Sb3VuZE5vdEVuZGVkAAAAAAAACwAAAAAAAAATTm90RWxpZ2libGVGb3JDbGFpbQAAAAAMAAAAAAAAABROb3RFbGlnaWJsZUZvclJlZnVuZAAAAA0AAAAAAAAAE0dlbmVzaXNOb3RUcmlnZ2VyZWQAAAAADgAAAAAAAAAXR2VuZXNpc0FscmVhZHlUcmlnZ2VyZWQAAAAADwAAAAAAAAASVHJlYXN1cnlGZWVUb29IaWdoAAAAAAAQAAAAAAAAABVJbnZhbGlkQnVmZmVySW50ZXJ2YWwAAAAAAAARAAAAAAAAAA1JbnZhbGlkQW1vdW50AAAAAAAAEgAAAAAAAAAOSW52YWxpZEFkZHJlc3MAAAAAABMAAAAAAAAAElJvdW5kQWxyZWFkeUxvY2tlZAAAAAAAFAAAAAAAAAAOUm91bmROb3RMb2NrZWQAAAAAABUAAAAAAAAADU91dHNpZGVCdWZmZXIAAAAAAAAWAAAAAAAAABhSZXdhcmRzQWxyZWFkeUNhbGN1bGF0ZWQAAAAXAAAAAAAAABRPcmFjbGVVcGRhdGVFeGNlZWRlZAAAABgAAAAAAAAAE09yYWNsZVJvdW5kSWRUb29Mb3cAAAAAGQAAAAAAAAAOVHJhbnNmZXJGYWlsZWQAAAAAABoAAAAAAAAABlBhdXNlZAAAAAAAGwAAAAAAAAAJTm90UGF1c2VkAAAAAAAAHAAAAAAAAAAQQmV0QW1vdW50VG9vSGlnaAAAAB0AAAAAAAAAEFJvdW5kQ2FwRXhjZWVkZWQAAAAeAAAAAAAAAA9Vc2VyQ2FwRXhjZWVkZWQAAAAAHwAAAAAAAAAMU2VsZkV4Y2x1ZGVkAAAAIAAAAAAAAAASV2FnZXJMaW1pdEV4Y2VlZGVkAAAAAAAhAAAAAAAAAAxBY2Nlc3NEZW5pZWQAAAAi",
+        "AAAAAgAAAAAAAAAAAAAACFBvc2l0aW9uAAAACAAAAAAAAAAAAAAABEJ1bGwAAAAAAAAAAAAAAARCZWFyAAAAAQAAAAAAAAAGQnVja2V0AAAAAAABAAAABAAAAAEAAAAAAAAABUFzc2V0AAAAAAAAAQAAABEAAAAAAAAAAAAAAAdCaWdNb3ZlAAAAAAAAAAAAAAAACVNtYWxsTW92ZQAAAAAAAAAAAAAAAAAAA1llcwAAAAAAAAAAAAAAAAJObwAA",
+        "AAAAAQAAAAAAAAAAAAAAB0JldEluZm8AAAAABAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAdjbGFpbWVkAAAAAAEAAAAAAAAACHBvc2l0aW9uAAAH0AAAAAhQb3NpdGlvbgAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAQAAAAAAAAAAAAAACVRva2VuUG9vbAAAAAAAAAYAAAAAAAAAC2JlYXJfYW1vdW50AAAAAAsAAAAAAAAADmJ1Y2tldF9hbW91bnRzAAAAAAPqAAAACwAAAAAAAAALYnVsbF9hbW91bnQAAAAACwAAAAAAAAANcmV3YXJkX2Ftb3VudAAAAAAAAAsAAAAAAAAAFnJld2FyZF9iYXNlX2NhbF9hbW91bnQAAAAAAAsAAAAAAAAADHRvdGFsX2Ftb3VudAAAAAs=",
+        "AAAAAQAAAAAAAAAAAAAABVJvdW5kAAAAAAAAFgAAAAAAAAALYmVhcl9hbW91bnQAAAAACwAAAAAAAAAOYnVja2V0X2Ftb3VudHMAAAAAA+oAAAALAAAAAAAAAAtidWxsX2Ftb3VudAAAAAALAAAAAAAAAAtjbG9zZV9wcmljZQAAAAALAAAAAAAAAA9jbG9zZV90aW1lc3RhbXAAAAAABgAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAAAAAA1ldmVudF9vdXRjb21lAAAAAAAD6AAAAAEAAAAAAAAADGhvdXNlX2Ftb3VudAAAAAsAAAAAAAAAFGhvdXNlX2JlYXJfbGlhYmlsaXR5AAAACwAAAAAAAAAUaG91c2VfYnVsbF9saWFiaWxpdHkAAAALAAAAAAAAAApsb2NrX3ByaWNlAAAAAAALAAAAAAAAAA5sb2NrX3RpbWVzdGFtcAAAAAAABgAAAAAAAAAObW92ZV90aHJlc2hvbGQAAAAAAAQAAAAAAAAADXJld2FyZF9hbW91bnQAAAAAAAALAAAAAAAAABZyZXdhcmRfYmFzZV9jYWxfYW1vdW50AAAAAAALAAAAAAAAABFyaXZhbF9jbG9zZV9wcmljZQAAAAAAAAsAAAAAAAAAEHJpdmFsX2xvY2tfcHJpY2UAAAALAAAAAAAAAA9zdGFydF90aW1lc3RhbXAAAAAABgAAAAAAAAALdG9rZW5fcG9vbHMAAAAD7AAAABMAAAfQAAAACVRva2VuUG9vbAAAAAAAAAAAAAAMdG90YWxfYW1vdW50AAAACwAAAAAAAAAMdHJlYXN1cnlfZmVlAAAABAAAAAAAAAAOd2lubmluZ19idWNrZXQAAAAAA+gAAAAE",
+        "AAAAAgAAAAAAAAAAAAAADUludGVudEZ1bmRpbmcAAAAAAAACAAAAAAAAAAAAAAAHQmFsYW5jZQAAAAAAAAAAAAAAAAlBbGxvd2FuY2UAAAA=",
+        "AAAAAQAAAAAAAAAAAAAACUJldEludGVudAAAAAAAAAkAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAAAAAAZleHBpcnkAAAAAAAYAAAAAAAAAB2Z1bmRpbmcAAAAH0AAAAA1JbnRlbnRGdW5kaW5nAAAAAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAACHBvc2l0aW9uAAAH0AAAAAhQb3NpdGlvbgAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAR1c2VyAAAAEw==",
+        "AAAAAQAAAAAAAAAAAAAACVVzZXJTdGF0cwAAAAAAAAcAAAAAAAAAD2Jlc3Rfd2luX3N0cmVhawAAAAAEAAAAAAAAABJjdXJyZW50X3dpbl9zdHJlYWsAAAAAAAQAAAAAAAAACm5ldF9wcm9maXQAAAAAAAsAAAAAAAAADXJvdW5kc19wbGF5ZWQAAAAAAAAEAAAAAAAAAApyb3VuZHNfd29uAAAAAAAEAAAAAAAAAA10b3RhbF9jbGFpbWVkAAAAAAAACwAAAAAAAAANdG90YWxfd2FnZXJlZAAAAAAAAAs=",
+        "AAAAAQAAAAAAAAAAAAAAEExlYWRlcmJvYXJkRW50cnkAAAACAAAAAAAAAAVzY29yZQAAAAAAAAsAAAAAAAAABHVzZXIAAAAT",
+        "AAAAAgAAAAAAAAAAAAAADVJhbmtpbmdNZXRyaWMAAAAAAAACAAAAAAAAAAAAAAAJTmV0UHJvZml0AAAAAAAAAAAAAAAAAAAIV2luQ291bnQ=",
+        "AAAAAQAAAAAAAAAAAAAABlNlYXNvbgAAAAAADAAAAAAAAAAOY2xhaW1lZF9hbW91bnQAAAAAAAsAAAAAAAAACWVuZF9lcG9jaAAAAAAAAAoAAAAAAAAACWZpbmFsaXplZAAAAAAAAAEAAAAAAAAAE2ZpbmFsaXplZF90aW1lc3RhbXAAAAAABgAAAAAAAAACaWQAAAAAAAQAAAAAAAAABm1ldHJpYwAAAAAH0AAAAA1SYW5raW5nTWV0cmljAAAAAAAAAAAAAAxwYXlvdXRfdGFibGUAAAPqAAAABAAAAAAAAAAKcHJpemVfcG9vbAAAAAAACwAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAALc3RhcnRfZXBvY2gAAAAACgAAAAAAAAAFc3dlcHQAAAAAAAABAAAAAAAAAAd3aW5uZXJzAAAAA+oAAAAT",
+        "AAAAAQAAAAAAAAAAAAAADVJlZmVycmFsU3RhdHMAAAAAAAADAAAAAAAAAAljbGFpbWFibGUAAAAAAAPsAAAAEwAAAAsAAAAAAAAADnJlZmVycmVkX2NvdW50AAAAAAAEAAAAAAAAAAx0b3RhbF9lYXJuZWQAAAPsAAAAEwAAAAs=",
+        "AAAAAQAAAAAAAAAAAAAADVN0YW5kaW5nT3JkZXIAAAAAAAAFAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACmxhc3RfZXBvY2gAAAAAAAoAAAAAAAAACHBvc2l0aW9uAAAH0AAAAAhQb3NpdGlvbgAAAAAAAAAQcmVtYWluaW5nX3JvdW5kcwAAAAQAAAAAAAAACXNlcmllc19pZAAAAAAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAADFAyUEJldFN0YXR1cwAAAAQAAAAAAAAAAAAAAARPcGVuAAAAAAAAAAAAAAAHTWF0Y2hlZAAAAAAAAAAAAAAAAAlDYW5jZWxsZWQAAAAAAAAAAAAAAAAAAAdTZXR0bGVkAA==",
+        "AAAAAQAAAAAAAAAAAAAABlAyUEJldAAAAAAACQAAAAAAAAANY291bnRlcl9zdGFrZQAAAAAAAAsAAAAAAAAADGNvdW50ZXJwYXJ0eQAAA+gAAAATAAAAAAAAAAVlcG9jaAAAAAAAAAoAAAAAAAAACHBvc2l0aW9uAAAH0AAAAAhQb3NpdGlvbgAAAAAAAAAIcHJvcG9zZXIAAAATAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAVzdGFrZQAAAAAAAAsAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAxQMlBCZXRTdGF0dXMAAAAAAAAABXRha2VyAAAAAAAD6AAAABM=",
+        "AAAAAQAAAAAAAAAAAAAAB1VzZXJDYXAAAAAAAgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAZyb3VuZHMAAAAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAACkFjY2Vzc01vZGUAAAAAAAMAAAAAAAAAAAAAAARPcGVuAAAAAAAAAAAAAAAJQmxvY2tsaXN0AAAAAAAAAAAAAAAAAAAJQWxsb3dsaXN0AAAA",
+        "AAAAAQAAAAAAAAAAAAAACVVzZXJMaW1pdAAAAAAAAAMAAAAAAAAAEW1heF93YWdlcl9wZXJfZGF5AAAAAAAACwAAAAAAAAAZcGVuZGluZ19tYXhfd2FnZXJfcGVyX2RheQAAAAAAA+gAAAALAAAAAAAAABFwZW5kaW5nX3RpbWVzdGFtcAAAAAAAAAY=",
+        "AAAAAQAAAAAAAAAAAAAACEZlZUN1cnZlAAAAAgAAAAAAAAAHbWF4X2ZlZQAAAAAEAAAAAAAAAAdtaW5fZmVlAAAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAACUhvdXNlT2RkcwAAAAAAAAIAAAAAAAAACWJlYXJfb2RkcwAAAAAAAAQAAAAAAAAACWJ1bGxfb2RkcwAAAAAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAADEZpeGVkT2Rkc0JldAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAHY2xhaW1lZAAAAAABAAAAAAAAAARvZGRzAAAABAAAAAAAAAAIcG9zaXRpb24AAAfQAAAACFBvc2l0aW9u",
+        "AAAAAQAAAAAAAAAAAAAACUV2ZW50SW5mbwAAAAAAAAQAAAAAAAAAB291dGNvbWUAAAAD6AAAAAEAAAAAAAAACHF1ZXN0aW9uAAAAEAAAAAAAAAAScmVzb2x2ZWRfdGltZXN0YW1wAAAAAAAGAAAAAAAAAAhyZXNvbHZlcgAAABM=",
+        "AAAAAQAAAAAAAAAAAAAADVZhdWx0UG9zaXRpb24AAAAAAAADAAAAAAAAABZsYXN0X2RlcG9zaXRfdGltZXN0YW1wAAAAAAAGAAAAAAAAABdsYXN0X3dpdGhkcmF3X3RpbWVzdGFtcAAAAAAGAAAAAAAAAAZzaGFyZXMAAAAAAAs=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAATQAAAAAAAAAAAAAABVRva2VuAAAAAAAAAAAAAAAAAAANT3JhY2xlQWRkcmVzcwAAAAAAAAAAAAAAAAAAC01hcmtldEFzc2V0AAAAAAEAAAAAAAAAD0ludGVydmFsU2Vjb25kcwAAAAABAAAABAAAAAEAAAAAAAAADUJ1ZmZlclNlY29uZHMAAAAAAAABAAAABAAAAAAAAAAAAAAADE1pbkJldEFtb3VudAAAAAAAAAAAAAAAC1RyZWFzdXJ5RmVlAAAAAAAAAAAAAAAADlRyZWFzdXJ5QW1vdW50AAAAAAABAAAAAAAAAAxDdXJyZW50RXBvY2gAAAABAAAABAAAAAEAAAAAAAAAEElzR2VuZXNpc1N0YXJ0ZWQAAAABAAAABAAAAAEAAAAAAAAAD0lzR2VuZXNpc0xvY2tlZAAAAAABAAAABAAAAAAAAAAAAAAABlBhdXNlZAAAAAAAAAAAAAAAAAALSW5pdGlhbGl6ZWQAAAAAAQAAAAAAAAAGUm91bmRzAAAAAAACAAAABAAAAAoAAAABAAAAAAAAAAhCZXRJbmZvcwAAAAMAAAAEAAAACgAAABMAAAABAAAAAAAAAApVc2VyUm91bmRzAAAAAAACAAAABAAAABMAAAAAAAAAAAAAAAtTZXJpZXNDb3VudAAAAAABAAAAAAAAAA1TZXJpZXNCdWNrZXRzAAAAAAAAAQAAAAQAAAABAAAAAAAAAAxTdHJpa2VTZXJpZXMAAAABAAAABAAAAAEAAAAAAAAADFNlcmllc0Fzc2V0cwAAAAEAAAAEAAAAAQAAAAAAAAAQVm9sYXRpbGl0eVNlcmllcwAAAAEAAAAEAAAAAQAAAAAAAAALRXZlbnRTZXJpZXMAAAAAAQAAAAQAAAABAAAAAAAAAApFdmVudEluZm9zAAAAAAACAAAABAAAAAoAAAABAAAAAAAAAAlIb3VzZU9kZHMAAAAAAAABAAAABAAAAAAAAAAAAAAACEZlZUN1cnZlAAAAAAAAAAAAAAAMTWF4QmV0QW1vdW50AAAAAAAAAAAAAAAOTWF4Um91bmRBbW91bnQAAAAAAAAAAAAAAAAAB1VzZXJDYXAAAAAAAQAAAAAAAAAOU2VsZkV4Y2x1c2lvbnMAAAAAAAEAAAATAAAAAQAAAAAAAAAKVXNlckxpbWl0cwAAAAAAAQAAABMAAAABAAAAAAAAAA9Vc2VyRGFpbHlXYWdlcnMAAAAAAQAAABMAAAAAAAAAAAAAAApBY2Nlc3NNb2RlAAAAAAAAAAAAAAAAABFDb21wbGlhbmNlTWFuYWdlcgAAAAAAAAEAAAAAAAAACUJsb2NrbGlzdAAAAAAAAAEAAAATAAAAAQAAAAAAAAAJQWxsb3dsaXN0AAAAAAAAAQAAABMAAAAAAAAAAAAAABRIb3VzZU1heFNpZGVFeHBvc3VyZQAAAAAAAAAAAAAAFUhvdXNlTWF4Um91bmRFeHBvc3VyZQAAAAAAAAAAAAAAAAAADUhvdXNlRXhwb3N1cmUAAAAAAAABAAAAAAAAAA1GaXhlZE9kZHNCZXRzAAAAAAAAAwAAAAQAAAAKAAAAEwAAAAAAAAAAAAAADEZsYXNoTG9hbkZlZQAAAAAAAAAAAAAAE0ZsYXNoVHJlYXN1cnlBbW91bnQAAAAAAAAAAAAAAAAQVmF1bHRUb3RhbEFzc2V0cwAAAAAAAAAAAAAAEFZhdWx0VG90YWxTaGFyZXMAAAABAAAAAAAAAA5WYXVsdFBvc2l0aW9ucwAAAAAAAQAAABMAAAAAAAAAAAAAABRWYXVsdENvb2xkb3duU2Vjb25kcwAAAAAAAAAAAAAAFVZhdWx0VHJlYXN1cnlGZWVTaGFyZQAAAAAAAAAAAAAAAAAADUFsbG93ZWRUb2tlbnMAAAAAAAABAAAAAAAAABFUb2tlbk1pbkJldEFtb3VudAAAAAAAAAEAAAATAAAAAQAAAAAAAAAKVG9rZW5SYXRlcwAAAAAAAQAAABMAAAABAAAAAAAAABNUb2tlblRyZWFzdXJ5QW1vdW50AAAAAAEAAAATAAAAAQAAAAAAAAAIQmFsYW5jZXMAAAACAAAAEwAAABMAAAAAAAAAAAAAAAhPcGVyYXRvcgAAAAEAAAAAAAAAC1NpZ25pbmdLZXlzAAAAAAEAAAATAAAAAQAAAAAAAAAMSW50ZW50Tm9uY2VzAAAAAQAAABMAAAABAAAAAAAAAApHaWZ0UGF5ZXJzAAAAAAABAAAAEwAAAAEAAAAAAAAACVJlZmVycmVycwAAAAAAAAEAAAATAAAAAQAAAAAAAAANUmVmZXJyYWxTdGF0cwAAAAAAAAEAAAATAAAAAAAAAAAAAAAQUmVmZXJyYWxGZWVTaGFyZQAAAAEAAAAAAAAAE1JvdW5kUmVmZXJyYWxTdGFrZXMAAAAAAwAAAAQAAAAKAAAAEwAAAAEAAAAAAAAACVVzZXJTdGF0cwAAAAAAAAEAAAATAAAAAQAAAAAAAAAPU2Vhc29uVXNlclN0YXRzAAAAAAIAAAAEAAAAEwAAAAEAAAAAAAAAC0xlYWRlcmJvYXJkAAAAAAEAAAAEAAAAAQAAAAAAAAAUVW5zZXR0bGVkU3RhdHNSb3VuZHMAAAABAAAAEwAAAAAAAAAAAAAAC1NlYXNvbkNvdW50AAAAAAEAAAAAAAAAB1NlYXNvbnMAAAAAAQAAAAQAAAABAAAAAAAAABJTZWFzb25Qcml6ZUNsYWltZWQAAAAAAAIAAAAEAAAAEwAAAAAAAAAAAAAADUphY2twb3RBbW91bnQAAAAAAAAAAAAAAAAAAA9KYWNrcG90RmVlU2hhcmUAAAAAAAAAAAAAAAAPSmFja3BvdEludGVydmFsAAAAAAEAAAAAAAAADkphY2twb3RFbnRyaWVzAAAAAAABAAAACgAAAAEAAAAAAAAAE0phY2twb3RSb3VuZEVudHJpZXMAAAAAAQAAAAoAAAAAAAAAAAAAAA5KYWNrcG90UGVyaW9kcwAAAAAAAQAAAAAAAAAOU3RhbmRpbmdPcmRlcnMAAAAAAAEAAAATAAAAAQAAAAAAAAASU3RhbmRpbmdPcmRlclVzZXJzAAAAAAABAAAABAAAAAAAAAAAAAAAFlN0YW5kaW5nT3JkZXJNaW5Fc2Nyb3cAAAAAAAAAAAAAAAAAC1AyUEJldENvdW50AAAAAAEAAAAAAAAAB1AyUEJldHMAAAAAAQAAAAQ=",
+        "AAAAAAAAAfpDb25zdHJ1Y3RvciB0byBpbml0aWFsaXplIHRoZSBQcmVkaWN0aW9uIE1hcmtldCBjb250cmFjdAojIFBhcmFtZXRlcnMKLSBgb3duZXJgOiBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBvd25lcgotIGBpbnRlcnZhbHNfc2Vjb25kc2A6IER1cmF0aW9uIG9mIGVhY2ggcHJlZGljdGlvbiByb3VuZCBpbiBzZWNvbmRzCi0gYG1pbl9iZXRfYW1vdW50YDogTWluaW11bSBhbW91bnQgcmVxdWlyZWQgdG8gcGxhY2UgYSBiZXQKLSBgdG9rZW5fYWRkcmVzc2A6IEFkZHJlc3Mgb2YgdGhlIHRva2VuIHVzZWQgZm9yIGJldHRpbmcKLSBgdHJlYXN1cnlfZmVlYDogRmVlIHBlcmNlbnRhZ2UgdGFrZW4gYnkgdGhlIHRyZWFzdXJ5IChzY2FsZWQgYnkgMTAwLCBlLmcuLCAxMDAgPSAxJSkKLSBgb3JhY2xlX2FkZHJlc3NgOiBBZGRyZXNzIG9mIHRoZSBSZWZsZWN0b3Igb3JhY2xlCi0gYGFzc2V0YDogU3ltYm9sIG9mIHRoZSBvcmFjbGUgYXNzZXQgcHJlZGljdGVkIGJ5IHRoZSBtYXJrZXQsIGUuZy4sIFhMTQAAAAAADV9fY29uc3RydWN0b3IAAAAAAAAJAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAEWludGVydmFsc19zZWNvbmRzAAAAAAAABgAAAAAAAAAOYnVmZmVyX3NlY29uZHMAAAAAAAYAAAAAAAAADm1pbl9iZXRfYW1vdW50AAAAAAALAAAAAAAAAA10b2tlbl9hZGRyZXNzAAAAAAAAEwAAAAAAAAAMdHJlYXN1cnlfZmVlAAAABAAAAAAAAAAOZmxhc2hfbG9hbl9mZWUAAAAAAAQAAAAAAAAADm9yYWNsZV9hZGRyZXNzAAAAAAATAAAAAAAAAAVhc3NldAAAAAAAABEAAAAA",
+        "AAAAAAAAAHtGdW5jdGlvbiB0byBzdGFydCB0aGUgZ2VuZXNpcyByb3VuZCBvZiBhIHNlcmllcwpPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBzZXJpZXMAAAAAE2dlbmVzaXNfc3RhcnRfcm91bmQAAAAAAQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAA=",
+        "AAAAAAAAAHpGdW5jdGlvbiB0byBsb2NrIHRoZSBnZW5lc2lzIHJvdW5kIG9mIGEgc2VyaWVzCk9ubHkgY2FsbGFibGUgYnkgdGhlIG93bmVyCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwAAAAAAEmdlbmVzaXNfbG9ja19yb3VuZAAAAAAAAQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAA=",
+        "AAAAAAAAAU9GdW5jdGlvbiB0byBleGVjdXRlIGEgcm91bmQgb2YgYSBzZXJpZXMKT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgc2VyaWVzCiMgRXZlbnRzCi0gYFJPVU5EX0xPQ0tFRGA6IEVtaXR0ZWQgd2hlbiBhIHJvdW5kIGlzIGxvY2tlZAotIGBST1VORF9FTkRFRGA6IEVtaXR0ZWQgd2hlbiBhIHJvdW5kIGlzIGVuZGVkCi0gYFJFV0FSRFNfQ0FMQ1VMQVRFRGA6IEVtaXR0ZWQgd2hlbiByZXdhcmRzIGFyZSBjYWxjdWxhdGVkCi0gYFJPVU5EX1NUQVJURURgOiBFbWl0dGVkIHdoZW4gYSBuZXcgcm91bmQgaXMgc3RhcnRlZAAAAAANZXhlY3V0ZV9yb3VuZAAAAAAAAAEAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAA",
+        "AAAAAAAAAndGdW5jdGlvbiB0byBvcGVuIGEgc3RyaWtlIHJvdW5kIG9uIHdoZXRoZXIgdGhlIHByaWNlIHdpbGwgYmUgYWJvdmUgb3IgYmVsb3cgYSBzdHJpa2UgYXQgZXhwaXJ5CkJ1bGwgYmV0cyB3aW4gYWJvdmUgdGhlIHN0cmlrZSBhbmQgQmVhciBiZXRzIGJlbG93IGl0LCBhIGNsb3NlIGF0IHRoZSBzdHJpa2UgZ29lcyB0byB0aGUgdHJlYXN1cnkKT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgc3RyaWtlIHNlcmllcwotIGBzdHJpa2VfcHJpY2VgOiBUaGUgc3RyaWtlIHByaWNlLCBpbiBvcmFjbGUgcHJpY2UgdW5pdHMKLSBgbG9ja190aW1lc3RhbXBgOiBUaGUgdGltZSBiZXR0aW5nIGNsb3NlcwotIGBleHBpcnlfdGltZXN0YW1wYDogVGhlIHRpbWUgdGhlIHJvdW5kIGlzIHNldHRsZWQgYWdhaW5zdCB0aGUgc3RyaWtlCiMgUmV0dXJucwotIGB1MTI4YDogVGhlIGVwb2NoIG9mIHRoZSBuZXcgcm91bmQKIyBFdmVudHMKLSBgUk9VTkRfU1RBUlRFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgcm91bmQgaXMgY3JlYXRlZAotIGBTVFJJS0VfUk9VTkRfQ1JFQVRFRGA6IEVtaXR0ZWQgd2l0aCB0aGUgc3RyaWtlIGFuZCBleHBpcnkgb2YgdGhlIHJvdW5kAAAAABNjcmVhdGVfc3RyaWtlX3JvdW5kAAAAAAQAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAADHN0cmlrZV9wcmljZQAAAAsAAAAAAAAADmxvY2tfdGltZXN0YW1wAAAAAAAGAAAAAAAAABBleHBpcnlfdGltZXN0YW1wAAAABgAAAAEAAAAK",
+        "AAAAAAAAAVhGdW5jdGlvbiB0byBzZXR0bGUgYSBzdHJpa2Ugcm91bmQgd2l0aCB0aGUgb3JhY2xlIHByaWNlIGF0IGl0cyBleHBpcnkKT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIsIHdpdGhpbiB0aGUgYnVmZmVyIHNlY29uZHMgYWZ0ZXIgZXhwaXJ5CiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHN0cmlrZSBzZXJpZXMKLSBgZXBvY2hgOiBUaGUgZXBvY2ggb2YgdGhlIHJvdW5kCiMgRXZlbnRzCi0gYFJPVU5EX0VOREVEYDogRW1pdHRlZCB3aGVuIHRoZSByb3VuZCBpcyBlbmRlZAotIGBSRVdBUkRTX0NBTENVTEFURURgOiBFbWl0dGVkIHdoZW4gcmV3YXJkcyBhcmUgY2FsY3VsYXRlZAAAABNzZXR0bGVfc3RyaWtlX3JvdW5kAAAAAAIAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAA=",
+        "AAAAAAAAAi5GdW5jdGlvbiB0byBvcGVuIGEgeWVzL25vIHJvdW5kIG9uIGFuIGV2ZW50IHNldHRsZWQgYnkgYSByZXNvbHZlcgpPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBldmVudCBzZXJpZXMKLSBgcXVlc3Rpb25gOiBUaGUgZXZlbnQgdGhlIHJvdW5kIGlzIGJldCBvbgotIGByZXNvbHZlcmA6IFRoZSBhZGRyZXNzIGFsbG93ZWQgdG8gcmVzb2x2ZSB0aGUgb3V0Y29tZSBvZiB0aGUgZXZlbnQKLSBgbG9ja190aW1lc3RhbXBgOiBUaGUgdGltZSBiZXR0aW5nIGNsb3NlcwotIGBjbG9zZV90aW1lc3RhbXBgOiBUaGUgdGltZSBmcm9tIHdoaWNoIHRoZSBldmVudCBjYW4gYmUgcmVzb2x2ZWQKIyBSZXR1cm5zCi0gYHUxMjhgOiBUaGUgZXBvY2ggb2YgdGhlIG5ldyByb3VuZAojIEV2ZW50cwotIGBST1VORF9TVEFSVEVEYDogRW1pdHRlZCB3aGVuIHRoZSByb3VuZCBpcyBjcmVhdGVkCi0gYEVWRU5UX1JPVU5EX0NSRUFURURgOiBFbWl0dGVkIHdpdGggdGhlIHF1ZXN0aW9uIGFuZCByZXNvbHZlciBvZiB0aGUgcm91bmQAAAAAABJjcmVhdGVfZXZlbnRfcm91bmQAAAAAAAUAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAACHF1ZXN0aW9uAAAAEAAAAAAAAAAIcmVzb2x2ZXIAAAATAAAAAAAAAA5sb2NrX3RpbWVzdGFtcAAAAAAABgAAAAAAAAAPY2xvc2VfdGltZXN0YW1wAAAAAAYAAAABAAAACg==",
+        "AAAAAAAAAU5GdW5jdGlvbiBmb3IgdGhlIHJlc29sdmVyIHRvIHJlcG9ydCB0aGUgb3V0Y29tZSBvZiBhbiBldmVudCByb3VuZApUaGUgb3V0Y29tZSBvcGVucyBhIGRpc3B1dGUgd2luZG93IGR1cmluZyB3aGljaCB0aGUgb3duZXIgY2FuIG92ZXJyaWRlIGl0CiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIGV2ZW50IHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQKLSBgb3V0Y29tZWA6IFdoZXRoZXIgdGhlIGV2ZW50IGhhcHBlbmVkCiMgRXZlbnRzCi0gYEVWRU5UX1JFU09MVkVEYDogRW1pdHRlZCB3aGVuIHRoZSBvdXRjb21lIGlzIHJlcG9ydGVkAAAAAAANcmVzb2x2ZV9ldmVudAAAAAAAAAMAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAHb3V0Y29tZQAAAAABAAAAAA==",
+        "AAAAAAAAAS5GdW5jdGlvbiB0byBvdmVycmlkZSB0aGUgb3V0Y29tZSBvZiBhbiBldmVudCByb3VuZCBkdXJpbmcgaXRzIGRpc3B1dGUgd2luZG93Ck9ubHkgY2FsbGFibGUgYnkgdGhlIG93bmVyCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIGV2ZW50IHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQKLSBgb3V0Y29tZWA6IFdoZXRoZXIgdGhlIGV2ZW50IGhhcHBlbmVkCiMgRXZlbnRzCi0gYEVWRU5UX09WRVJSSURERU5gOiBFbWl0dGVkIHdoZW4gdGhlIG91dGNvbWUgaXMgb3ZlcnJpZGRlbgAAAAAAFm92ZXJyaWRlX2V2ZW50X291dGNvbWUAAAAAAAMAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAHb3V0Y29tZQAAAAABAAAAAA==",
+        "AAAAAAAAAShGdW5jdGlvbiB0byBzZXR0bGUgYW4gZXZlbnQgcm91bmQgd2l0aCBpdHMgb3V0Y29tZSBvbmNlIHRoZSBkaXNwdXRlIHdpbmRvdyBpcyBvdmVyCkNhbGxhYmxlIGJ5IGFueW9uZSwgYmVmb3JlIHRoZSByb3VuZCBiZWNvbWVzIHJlZnVuZGFibGUKIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgZXZlbnQgc2VyaWVzCi0gYGVwb2NoYDogVGhlIGVwb2NoIG9mIHRoZSByb3VuZAojIEV2ZW50cwotIGBSRVdBUkRTX0NBTENVTEFURURgOiBFbWl0dGVkIHdoZW4gcmV3YXJkcyBhcmUgY2FsY3VsYXRlZAAAABJzZXR0bGVfZXZlbnRfcm91bmQAAAAAAAIAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAA=",
+        "AAAAAAAAARVGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYnVsbCBzaWRlCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gYmV0IG9uCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlciBwbGFjaW5nIHRoZSBiZXQKLSBgYW1vdW50YDogVGhlIGFtb3VudCBvZiB0b2tlbnMgdG8gYmV0CiMgRXZlbnRzCi0gYEJFVF9QTEFDRURgOiBFbWl0dGVkIHdoZW4gYSBiZXQgaXMgcGxhY2VkAAAAAAAACGJldF9idWxsAAAABAAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAARVGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYmVhciBzaWRlCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gYmV0IG9uCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlciBwbGFjaW5nIHRoZSBiZXQKLSBgYW1vdW50YDogVGhlIGFtb3VudCBvZiB0b2tlbnMgdG8gYmV0CiMgRXZlbnRzCi0gYEJFVF9QTEFDRURgOiBFbWl0dGVkIHdoZW4gYSBiZXQgaXMgcGxhY2VkAAAAAAAACGJldF9iZWFyAAAABAAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAXVGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiBhIHByaWNlIGJ1Y2tldCBvZiBhIGJ1Y2tldCBzZXJpZXMKIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgYnVja2V0IHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gYmV0IG9uCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlciBwbGFjaW5nIHRoZSBiZXQKLSBgYnVja2V0YDogVGhlIGluZGV4IG9mIHRoZSBidWNrZXQsIDAgYmVpbmcgdGhlIGxvd2VzdCBwcmljZSBjaGFuZ2UKLSBgYW1vdW50YDogVGhlIGFtb3VudCBvZiB0b2tlbnMgdG8gYmV0CiMgRXZlbnRzCi0gYEJFVF9QTEFDRURgOiBFbWl0dGVkIHdoZW4gYSBiZXQgaXMgcGxhY2VkAAAAAAAACmJldF9idWNrZXQAAAAAAAUAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAEdXNlcgAAABMAAAAAAAAABmJ1Y2tldAAAAAAABAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAXlGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiBhbiBhc3NldCBvZiBhIGhlYWQtdG8taGVhZCBzZXJpZXMKIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgaGVhZC10by1oZWFkIHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gYmV0IG9uCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlciBwbGFjaW5nIHRoZSBiZXQKLSBgYXNzZXRgOiBUaGUgYXNzZXQgZXhwZWN0ZWQgdG8gaGF2ZSB0aGUgaGlnaGVyIHBlcmNlbnRhZ2UgY2hhbmdlCi0gYGFtb3VudGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHRvIGJldAojIEV2ZW50cwotIGBCRVRfUExBQ0VEYDogRW1pdHRlZCB3aGVuIGEgYmV0IGlzIHBsYWNlZAAAAAAAAAliZXRfYXNzZXQAAAAAAAAFAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAVlcG9jaAAAAAAAAAoAAAAAAAAABHVzZXIAAAATAAAAAAAAAAVhc3NldAAAAAAAABEAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAATRGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiBhIGJpZyBtb3ZlIG9mIGEgdm9sYXRpbGl0eSBzZXJpZXMKIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgdm9sYXRpbGl0eSBzZXJpZXMKLSBgZXBvY2hgOiBUaGUgZXBvY2ggb2YgdGhlIHJvdW5kIHRvIGJldCBvbgotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIgcGxhY2luZyB0aGUgYmV0Ci0gYGFtb3VudGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHRvIGJldAojIEV2ZW50cwotIGBCRVRfUExBQ0VEYDogRW1pdHRlZCB3aGVuIGEgYmV0IGlzIHBsYWNlZAAAAAxiZXRfYmlnX21vdmUAAAAEAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAVlcG9jaAAAAAAAAAoAAAAAAAAABHVzZXIAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAATZGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiBhIHNtYWxsIG1vdmUgb2YgYSB2b2xhdGlsaXR5IHNlcmllcwojIFBhcmFtZXRlcnMKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSB2b2xhdGlsaXR5IHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gYmV0IG9uCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlciBwbGFjaW5nIHRoZSBiZXQKLSBgYW1vdW50YDogVGhlIGFtb3VudCBvZiB0b2tlbnMgdG8gYmV0CiMgRXZlbnRzCi0gYEJFVF9QTEFDRURgOiBFbWl0dGVkIHdoZW4gYSBiZXQgaXMgcGxhY2VkAAAAAAAOYmV0X3NtYWxsX21vdmUAAAAAAAQAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAEdXNlcgAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAASRGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB5ZXMgaW4gYW4gZXZlbnQgc2VyaWVzCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIGV2ZW50IHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gYmV0IG9uCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlciBwbGFjaW5nIHRoZSBiZXQKLSBgYW1vdW50YDogVGhlIGFtb3VudCBvZiB0b2tlbnMgdG8gYmV0CiMgRXZlbnRzCi0gYEJFVF9QTEFDRURgOiBFbWl0dGVkIHdoZW4gYSBiZXQgaXMgcGxhY2VkAAAAB2JldF95ZXMAAAAABAAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAASNGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiBubyBpbiBhbiBldmVudCBzZXJpZXMKIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgZXZlbnQgc2VyaWVzCi0gYGVwb2NoYDogVGhlIGVwb2NoIG9mIHRoZSByb3VuZCB0byBiZXQgb24KLSBgdXNlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB1c2VyIHBsYWNpbmcgdGhlIGJldAotIGBhbW91bnRgOiBUaGUgYW1vdW50IG9mIHRva2VucyB0byBiZXQKIyBFdmVudHMKLSBgQkVUX1BMQUNFRGA6IEVtaXR0ZWQgd2hlbiBhIGJldCBpcyBwbGFjZWQAAAAABmJldF9ubwAAAAAABAAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAgJGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBhdCB0aGUgZml4ZWQgb2RkcyBxdW90ZWQgYnkgdGhlIGhvdXNlIGZvciBhIHNlcmllcwpUaGUgYmV0IGlzIGJhY2tlZCBieSB0aGUgdmF1bHQgYW5kIHN0YXlzIG91dCBvZiB0aGUgcGFyaW11dHVlbCBwb29scwojIFBhcmFtZXRlcnMKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBzZXJpZXMKLSBgZXBvY2hgOiBUaGUgZXBvY2ggb2YgdGhlIHJvdW5kIHRvIGJldCBvbgotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIgcGxhY2luZyB0aGUgYmV0Ci0gYHBvc2l0aW9uYDogVGhlIHNpZGUgb2YgdGhlIGJldAotIGBhbW91bnRgOiBUaGUgYW1vdW50IG9mIHRva2VucyB0byBiZXQKLSBgbWluX29kZHNgOiBUaGUgbG93ZXN0IG9kZHMgdGhlIHVzZXIgYWNjZXB0cyAoc2NhbGVkIGJ5IDEwXzAwMCwgZS5nLiwgMTlfMDAwID0gMS45eCkKIyBFdmVudHMKLSBgRklYRURfT0REU19CRVRfUExBQ0VEYDogRW1pdHRlZCB3aGVuIHRoZSBiZXQgaXMgcGxhY2VkAAAAAAAOYmV0X2ZpeGVkX29kZHMAAAAAAAYAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAEdXNlcgAAABMAAAAAAAAACHBvc2l0aW9uAAAH0AAAAAhQb3NpdGlvbgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAhtaW5fb2RkcwAAAAQAAAAA",
+        "AAAAAAAAAVZGdW5jdGlvbiB0byBjbGFpbSB0aGUgcGF5b3V0IG9mIGEgd2lubmluZyBmaXhlZC1vZGRzIGJldCwgb3IgaXRzIHJlZnVuZCBpZiB0aGUgcm91bmQgd2FzIG5ldmVyIGVuZGVkCiMgUGFyYW1ldGVycwotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIgY2xhaW1pbmcKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBzZXJpZXMKLSBgZXBvY2hgOiBUaGUgZXBvY2ggb2YgdGhlIHJvdW5kCiMgUmV0dXJucwotIGBpMTI4YDogVGhlIGFtb3VudCBwYWlkIHRvIHRoZSB1c2VyCiMgRXZlbnRzCi0gYEZJWEVEX09ERFNfQ0xBSU1FRGA6IEVtaXR0ZWQgd2hlbiB0aGUgcGF5b3V0IGlzIGNsYWltZWQAAAAAABBjbGFpbV9maXhlZF9vZGRzAAAAAwAAAAAAAAAEdXNlcgAAABMAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAEAAAAL",
+        "AAAAAAAAAT9GdW5jdGlvbiB0byByZWxlYXNlIHRoZSBleHBvc3VyZSB0aGUgdmF1bHQgcmVzZXJ2ZWQgZm9yIHRoZSBmaXhlZC1vZGRzIGJldHMgb2YgYSByZWZ1bmRlZCByb3VuZApDYWxsYWJsZSBieSBhbnlvbmUsIHRoZSBzdGFrZXMgc3RheSBjbGFpbWFibGUgYnkgdGhlaXIgYmV0dG9ycwojIFBhcmFtZXRlcnMKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBzZXJpZXMKLSBgZXBvY2hgOiBUaGUgZXBvY2ggb2YgdGhlIHJlZnVuZGVkIHJvdW5kCiMgRXZlbnRzCi0gYEhPVVNFX0JPT0tfUkVMRUFTRURgOiBFbWl0dGVkIHdpdGggdGhlIGV4cG9zdXJlIHJlbGVhc2VkAAAAABZyZWxlYXNlX2hvdXNlX2V4cG9zdXJlAAAAAAACAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAVlcG9jaAAAAAAAAAoAAAAA",
+        "AAAAAAAAAWRGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYnVsbCBzaWRlIHdpdGggYW4gYWxsb3dsaXN0ZWQgdG9rZW4KIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgc2VyaWVzCi0gYGVwb2NoYDogVGhlIGVwb2NoIG9mIHRoZSByb3VuZCB0byBiZXQgb24KLSBgdXNlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB1c2VyIHBsYWNpbmcgdGhlIGJldAotIGB0b2tlbmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB0b2tlbiB1c2VkIGZvciB0aGUgYmV0Ci0gYGFtb3VudGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHRvIGJldAojIEV2ZW50cwotIGBCRVRfUExBQ0VEYDogRW1pdHRlZCB3aGVuIGEgYmV0IGlzIHBsYWNlZAAAABNiZXRfYnVsbF93aXRoX3Rva2VuAAAAAAUAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAEdXNlcgAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAWRGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYmVhciBzaWRlIHdpdGggYW4gYWxsb3dsaXN0ZWQgdG9rZW4KIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgc2VyaWVzCi0gYGVwb2NoYDogVGhlIGVwb2NoIG9mIHRoZSByb3VuZCB0byBiZXQgb24KLSBgdXNlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB1c2VyIHBsYWNpbmcgdGhlIGJldAotIGB0b2tlbmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB0b2tlbiB1c2VkIGZvciB0aGUgYmV0Ci0gYGFtb3VudGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHRvIGJldAojIEV2ZW50cwotIGBCRVRfUExBQ0VEYDogRW1pdHRlZCB3aGVuIGEgYmV0IGlzIHBsYWNlZAAAABNiZXRfYmVhcl93aXRoX3Rva2VuAAAAAAUAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAEdXNlcgAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAbNGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYnVsbCBzaWRlIGZ1bmRlZCBmcm9tIHRoZSBpbnRlcm5hbCBiYWxhbmNlCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gYmV0IG9uCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlciBwbGFjaW5nIHRoZSBiZXQKLSBgdG9rZW5gOiBUaGUgYWRkcmVzcyBvZiB0aGUgdG9rZW4gdXNlZCBmb3IgdGhlIGJldAotIGBhbW91bnRgOiBUaGUgYW1vdW50IG9mIHRva2VucyB0byBiZXQKIyBFdmVudHMKLSBgQkVUX1BMQUNFRGA6IEVtaXR0ZWQgd2hlbiBhIGJldCBpcyBwbGFjZWQKLSBgQkFMQU5DRV9ERUJJVEVEYDogRW1pdHRlZCB3aGVuIHRoZSBzdGFrZSBpcyBkZWJpdGVkIGZyb20gdGhlIGJhbGFuY2UAAAAAFWJldF9idWxsX2Zyb21fYmFsYW5jZQAAAAAAAAUAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAEdXNlcgAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAbNGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYmVhciBzaWRlIGZ1bmRlZCBmcm9tIHRoZSBpbnRlcm5hbCBiYWxhbmNlCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gYmV0IG9uCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlciBwbGFjaW5nIHRoZSBiZXQKLSBgdG9rZW5gOiBUaGUgYWRkcmVzcyBvZiB0aGUgdG9rZW4gdXNlZCBmb3IgdGhlIGJldAotIGBhbW91bnRgOiBUaGUgYW1vdW50IG9mIHRva2VucyB0byBiZXQKIyBFdmVudHMKLSBgQkVUX1BMQUNFRGA6IEVtaXR0ZWQgd2hlbiBhIGJldCBpcyBwbGFjZWQKLSBgQkFMQU5DRV9ERUJJVEVEYDogRW1pdHRlZCB3aGVuIHRoZSBzdGFrZSBpcyBkZWJpdGVkIGZyb20gdGhlIGJhbGFuY2UAAAAAFWJldF9iZWFyX2Zyb21fYmFsYW5jZQAAAAAAAAUAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAEdXNlcgAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAexGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYnVsbCBzaWRlIGZ1bmRlZCB1bmRlciBhIHRva2VuIGFsbG93YW5jZQpgdXNlcmAgbXVzdCBoYXZlIGFwcHJvdmVkIGBzcGVuZGVyYDsgd2hlbiBgc3BlbmRlcmAgaXMgdGhpcyBjb250cmFjdCB0aGUgdXNlcgphdXRob3JpemVzIHRoZSBiZXQsIG90aGVyd2lzZSB0aGUgc3BlbmRlciBkb2VzCiMgUGFyYW1ldGVycwotIGBzcGVuZGVyYDogVGhlIGFkZHJlc3Mgc3BlbmRpbmcgdGhlIGFsbG93YW5jZQotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIgZnVuZGluZyBhbmQgb3duaW5nIHRoZSBiZXQKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBzZXJpZXMKLSBgZXBvY2hgOiBUaGUgZXBvY2ggb2YgdGhlIHJvdW5kIHRvIGJldCBvbgotIGBhbW91bnRgOiBUaGUgYW1vdW50IG9mIHRva2VucyB0byBiZXQKIyBFdmVudHMKLSBgQkVUX1BMQUNFRGA6IEVtaXR0ZWQgd2hlbiBhIGJldCBpcyBwbGFjZWQAAAANYmV0X2J1bGxfZnJvbQAAAAAAAAUAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEdXNlcgAAABMAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAexGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYmVhciBzaWRlIGZ1bmRlZCB1bmRlciBhIHRva2VuIGFsbG93YW5jZQpgdXNlcmAgbXVzdCBoYXZlIGFwcHJvdmVkIGBzcGVuZGVyYDsgd2hlbiBgc3BlbmRlcmAgaXMgdGhpcyBjb250cmFjdCB0aGUgdXNlcgphdXRob3JpemVzIHRoZSBiZXQsIG90aGVyd2lzZSB0aGUgc3BlbmRlciBkb2VzCiMgUGFyYW1ldGVycwotIGBzcGVuZGVyYDogVGhlIGFkZHJlc3Mgc3BlbmRpbmcgdGhlIGFsbG93YW5jZQotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIgZnVuZGluZyBhbmQgb3duaW5nIHRoZSBiZXQKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBzZXJpZXMKLSBgZXBvY2hgOiBUaGUgZXBvY2ggb2YgdGhlIHJvdW5kIHRvIGJldCBvbgotIGBhbW91bnRgOiBUaGUgYW1vdW50IG9mIHRva2VucyB0byBiZXQKIyBFdmVudHMKLSBgQkVUX1BMQUNFRGA6IEVtaXR0ZWQgd2hlbiBhIGJldCBpcyBwbGFjZWQAAAANYmV0X2JlYXJfZnJvbQAAAAAAAAUAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEdXNlcgAAABMAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAmRGdW5jdGlvbiB0byBwbGFjZSBhIGJ1bGwgYmV0IHBhaWQgYnkgYHBheWVyYCBvbiBiZWhhbGYgb2YgYGJlbmVmaWNpYXJ5YApUaGUgYmV0IGFuZCBpdHMgY2xhaW0gcmlnaHRzIGJlbG9uZyB0byB0aGUgYmVuZWZpY2lhcnkKT25seSB0aGUgcGF5ZXIgYXV0aG9yaXplcyB0aGUgYmV0LCBzbyBpdCBjYW4gYmUgZ2lmdGVkIHRvIGFuIG9mZmxpbmUgYmVuZWZpY2lhcnksCnVubGVzcyB0aGUgYmVuZWZpY2lhcnkgb3B0ZWQgaW4gdG8gYW4gYWxsb3dsaXN0IG9mIHBheWVycyB3aXRoIGBzZXRfZ2lmdF9wYXllcnNgCiMgUGFyYW1ldGVycwotIGBwYXllcmA6IFRoZSBhZGRyZXNzIGZ1bmRpbmcgdGhlIGJldAotIGBiZW5lZmljaWFyeWA6IFRoZSBhZGRyZXNzIG93bmluZyB0aGUgYmV0Ci0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgc2VyaWVzCi0gYGVwb2NoYDogVGhlIGVwb2NoIG9mIHRoZSByb3VuZCB0byBiZXQgb24KLSBgYW1vdW50YDogVGhlIGFtb3VudCBvZiB0b2tlbnMgdG8gYmV0CiMgRXZlbnRzCi0gYEJFVF9QTEFDRURgOiBFbWl0dGVkIHdoZW4gYSBiZXQgaXMgcGxhY2VkCi0gYEJFVF9TUE9OU09SRURgOiBFbWl0dGVkIHdpdGggdGhlIHBheWVyIG9mIHRoZSBiZXQAAAAMYmV0X2J1bGxfZm9yAAAABQAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAAAAAAtiZW5lZmljaWFyeQAAAAATAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAVlcG9jaAAAAAAAAAoAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAmRGdW5jdGlvbiB0byBwbGFjZSBhIGJlYXIgYmV0IHBhaWQgYnkgYHBheWVyYCBvbiBiZWhhbGYgb2YgYGJlbmVmaWNpYXJ5YApUaGUgYmV0IGFuZCBpdHMgY2xhaW0gcmlnaHRzIGJlbG9uZyB0byB0aGUgYmVuZWZpY2lhcnkKT25seSB0aGUgcGF5ZXIgYXV0aG9yaXplcyB0aGUgYmV0LCBzbyBpdCBjYW4gYmUgZ2lmdGVkIHRvIGFuIG9mZmxpbmUgYmVuZWZpY2lhcnksCnVubGVzcyB0aGUgYmVuZWZpY2lhcnkgb3B0ZWQgaW4gdG8gYW4gYWxsb3dsaXN0IG9mIHBheWVycyB3aXRoIGBzZXRfZ2lmdF9wYXllcnNgCiMgUGFyYW1ldGVycwotIGBwYXllcmA6IFRoZSBhZGRyZXNzIGZ1bmRpbmcgdGhlIGJldAotIGBiZW5lZmljaWFyeWA6IFRoZSBhZGRyZXNzIG93bmluZyB0aGUgYmV0Ci0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgc2VyaWVzCi0gYGVwb2NoYDogVGhlIGVwb2NoIG9mIHRoZSByb3VuZCB0byBiZXQgb24KLSBgYW1vdW50YDogVGhlIGFtb3VudCBvZiB0b2tlbnMgdG8gYmV0CiMgRXZlbnRzCi0gYEJFVF9QTEFDRURgOiBFbWl0dGVkIHdoZW4gYSBiZXQgaXMgcGxhY2VkCi0gYEJFVF9TUE9OU09SRURgOiBFbWl0dGVkIHdpdGggdGhlIHBheWVyIG9mIHRoZSBiZXQAAAAMYmV0X2JlYXJfZm9yAAAABQAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAAAAAAtiZW5lZmljaWFyeQAAAAATAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAVlcG9jaAAAAAAAAAoAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAUtGdW5jdGlvbiBmb3IgYSBiZW5lZmljaWFyeSB0byBvbmx5IGFjY2VwdCBnaWZ0ZWQgYmV0cyBmcm9tIGEgbGlzdCBvZiBwYXllcnMKQW4gZW1wdHkgbGlzdCBhY2NlcHRzIGdpZnRzIGZyb20gYW55IHBheWVyLCB3aGljaCBpcyB0aGUgZGVmYXVsdAojIFBhcmFtZXRlcnMKLSBgYmVuZWZpY2lhcnlgOiBUaGUgYWRkcmVzcyByZWNlaXZpbmcgdGhlIGdpZnRlZCBiZXRzCi0gYHBheWVyc2A6IFRoZSBwYXllcnMgYWxsb3dlZCB0byBnaWZ0IGJldHMsIGF0IG1vc3QgMTAKIyBFdmVudHMKLSBgR0lGVF9QQVlFUlNfVVBEQVRFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgbGlzdCBpcyB1cGRhdGVkAAAAAA9zZXRfZ2lmdF9wYXllcnMAAAAAAgAAAAAAAAALYmVuZWZpY2lhcnkAAAAAEwAAAAAAAAAGcGF5ZXJzAAAAAAPqAAAAEwAAAAA=",
+        "AAAAAAAAAgNGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYnVsbCBzaWRlIHRocm91Z2ggYSByZWZlcnJhbCBsaW5rClRoZSByZWZlcnJlciBpcyBib3VuZCB0byB0aGUgdXNlciBvbiBpdHMgZmlyc3QgYmV0LCBpdCBpcyBpZ25vcmVkIG9uY2UgdGhlIHVzZXIgaGFzIGJldAojIFBhcmFtZXRlcnMKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBzZXJpZXMKLSBgZXBvY2hgOiBUaGUgZXBvY2ggb2YgdGhlIHJvdW5kIHRvIGJldCBvbgotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIgcGxhY2luZyB0aGUgYmV0Ci0gYGFtb3VudGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHRvIGJldAotIGByZWZlcnJlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSByZWZlcnJlciBvZiB0aGUgdXNlcgojIEV2ZW50cwotIGBSRUZFUlJFUl9SRUdJU1RFUkVEYDogRW1pdHRlZCB3aGVuIHRoZSByZWZlcnJlciBpcyBib3VuZCB0byB0aGUgdXNlcgotIGBCRVRfUExBQ0VEYDogRW1pdHRlZCB3aGVuIGEgYmV0IGlzIHBsYWNlZAAAAAARYmV0X2J1bGxfcmVmZXJyZWQAAAAAAAAFAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAVlcG9jaAAAAAAAAAoAAAAAAAAABHVzZXIAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACHJlZmVycmVyAAAAEwAAAAA=",
+        "AAAAAAAAAgNGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBvbiB0aGUgYmVhciBzaWRlIHRocm91Z2ggYSByZWZlcnJhbCBsaW5rClRoZSByZWZlcnJlciBpcyBib3VuZCB0byB0aGUgdXNlciBvbiBpdHMgZmlyc3QgYmV0LCBpdCBpcyBpZ25vcmVkIG9uY2UgdGhlIHVzZXIgaGFzIGJldAojIFBhcmFtZXRlcnMKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBzZXJpZXMKLSBgZXBvY2hgOiBUaGUgZXBvY2ggb2YgdGhlIHJvdW5kIHRvIGJldCBvbgotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIgcGxhY2luZyB0aGUgYmV0Ci0gYGFtb3VudGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHRvIGJldAotIGByZWZlcnJlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSByZWZlcnJlciBvZiB0aGUgdXNlcgojIEV2ZW50cwotIGBSRUZFUlJFUl9SRUdJU1RFUkVEYDogRW1pdHRlZCB3aGVuIHRoZSByZWZlcnJlciBpcyBib3VuZCB0byB0aGUgdXNlcgotIGBCRVRfUExBQ0VEYDogRW1pdHRlZCB3aGVuIGEgYmV0IGlzIHBsYWNlZAAAAAARYmV0X2JlYXJfcmVmZXJyZWQAAAAAAAAFAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAVlcG9jaAAAAAAAAAoAAAAAAAAABHVzZXIAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACHJlZmVycmVyAAAAEwAAAAA=",
+        "AAAAAAAAAXRGdW5jdGlvbiB0byByZWdpc3RlciB0aGUgcmVmZXJyZXIgb2YgYSB1c2VyIGFoZWFkIG9mIGl0cyBmaXJzdCBiZXQKVGhlIHJlZmVycmVyIGNhbiBvbmx5IGJlIHNldCBvbmNlLCBiZWZvcmUgdGhlIGZpcnN0IGJldCBvZiB0aGUgdXNlciwgdGhlIGJldCBmdW5jdGlvbnMKd2l0aCBhIHJlZmVycmVyIGJpbmQgaXQgb24gdGhlIGZpcnN0IGJldCBpbnN0ZWFkCiMgUGFyYW1ldGVycwotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHJlZmVycmVkIHVzZXIKLSBgcmVmZXJyZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgcmVmZXJyZXIKIyBFdmVudHMKLSBgUkVGRVJSRVJfUkVHSVNURVJFRGA6IEVtaXR0ZWQgd2hlbiBhIHJlZmVycmVyIGlzIHJlZ2lzdGVyZWQAAAARcmVnaXN0ZXJfcmVmZXJyZXIAAAAAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAIcmVmZXJyZXIAAAATAAAAAA==",
+        "AAAAAAAAALxGdW5jdGlvbiB0byBjbGFpbSB0aGUgcmVmZXJyYWwgcmV3YXJkcyBhY2NydWVkIGluIGV2ZXJ5IHRva2VuCiMgUGFyYW1ldGVycwotIGByZWZlcnJlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSByZWZlcnJlcgojIEV2ZW50cwotIGBSRUZFUlJBTF9SRVdBUkRTX0NMQUlNRURgOiBFbWl0dGVkIGZvciBldmVyeSB0b2tlbiBwYWlkIG91dAAAABZjbGFpbV9yZWZlcnJhbF9yZXdhcmRzAAAAAAABAAAAAAAAAAhyZWZlcnJlcgAAABMAAAAA",
+        "AAAAAAAAAQZGdW5jdGlvbiB0byBhZGQgYmV0dGluZyB0b2tlbnMgdG8gdGhlIHByaXplIHBvb2wgb2YgYSBzZWFzb24KIyBQYXJhbWV0ZXJzCi0gYGZ1bmRlcmA6IFRoZSBhZGRyZXNzIGZ1bmRpbmcgdGhlIHByaXplIHBvb2wKLSBgc2Vhc29uX2lkYDogVGhlIGlkIG9mIHRoZSBzZWFzb24KLSBgYW1vdW50YDogVGhlIGFtb3VudCBvZiB0b2tlbnMgdG8gYWRkCiMgRXZlbnRzCi0gYFNFQVNPTl9GVU5ERURgOiBFbWl0dGVkIHdoZW4gdGhlIHByaXplIHBvb2wgaXMgZnVuZGVkAAAAAAALZnVuZF9zZWFzb24AAAAAAwAAAAAAAAAGZnVuZGVyAAAAAAATAAAAAAAAAAlzZWFzb25faWQAAAAAAAAEAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAAQVGdW5jdGlvbiB0byBjbGFpbSB0aGUgcHJpemUgb2YgYSBmaW5hbGl6ZWQgc2Vhc29uClByaXplcyBjYW4gYmUgY2xhaW1lZCB1bnRpbCB0aGUgb3duZXIgc3dlZXBzIHRoZSBzZWFzb24KIyBQYXJhbWV0ZXJzCi0gYHNlYXNvbl9pZGA6IFRoZSBpZCBvZiB0aGUgc2Vhc29uCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiBhIHRvcCBmaW5pc2hlcgojIEV2ZW50cwotIGBTRUFTT05fUFJJWkVfQ0xBSU1FRGA6IEVtaXR0ZWQgd2hlbiBhIHByaXplIGlzIGNsYWltZWQAAAAAAAASY2xhaW1fc2Vhc29uX3ByaXplAAAAAAACAAAAAAAAAAlzZWFzb25faWQAAAAAAAAEAAAAAAAAAAR1c2VyAAAAEwAAAAA=",
+        "AAAAAAAAAO1GdW5jdGlvbiB0byByZWdpc3RlciB0aGUgZWQyNTUxOSBrZXkgdXNlZCB0byBzaWduIGJldCBpbnRlbnRzCiMgUGFyYW1ldGVycwotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIKLSBgcHVibGljX2tleWA6IFRoZSBlZDI1NTE5IHB1YmxpYyBrZXkgb2YgdGhlIHVzZXIKIyBFdmVudHMKLSBgU0lHTklOR19LRVlfUkVHSVNURVJFRGA6IEVtaXR0ZWQgd2hlbiBhIHNpZ25pbmcga2V5IGlzIHJlZ2lzdGVyZWQAAAAAAAAUcmVnaXN0ZXJfc2lnbmluZ19rZXkAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAXdGdW5jdGlvbiB0byBwbGFjZSBhIGJldCBmcm9tIGFuIGludGVudCBzaWduZWQgb2ZmLWNoYWluIGJ5IHRoZSB1c2VyCk9ubHkgY2FsbGFibGUgYnkgdGhlIG9wZXJhdG9yLCB3aG8gcGF5cyB0aGUgdHJhbnNhY3Rpb24gZmVlcwojIFBhcmFtZXRlcnMKLSBgaW50ZW50YDogVGhlIGJldCBpbnRlbnQgc2lnbmVkIGJ5IHRoZSB1c2VyCi0gYHNpZ25hdHVyZWA6IFRoZSBlZDI1NTE5IHNpZ25hdHVyZSBvZiBgZ2V0X2JldF9pbnRlbnRfcGF5bG9hZChpbnRlbnQpYAojIEV2ZW50cwotIGBCRVRfUExBQ0VEYDogRW1pdHRlZCB3aGVuIGEgYmV0IGlzIHBsYWNlZAotIGBCRVRfSU5URU5UX0VYRUNVVEVEYDogRW1pdHRlZCB3aGVuIHRoZSBpbnRlbnQgaXMgY29uc3VtZWQAAAAAEmV4ZWN1dGVfYmV0X2ludGVudAAAAAAAAgAAAAAAAAAGaW50ZW50AAAAAAfQAAAACUJldEludGVudAAAAAAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAA",
+        "AAAAAAAAAQNGdW5jdGlvbiB0byBjbGFpbSB3aW5uaW5ncyBvciByZWZ1bmRzIGFuZCB0cmFuc2ZlciB0aGVtIHRvIHRoZSB1c2VyCiMgUGFyYW1ldGVycwotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIgY2xhaW1pbmcKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBzZXJpZXMKLSBgZXBvY2hzYDogVGhlIGVwb2NocyBvZiB0aGUgcm91bmRzIHRvIGNsYWltCiMgRXZlbnRzCi0gYENMQUlNRURgOiBFbWl0dGVkIGZvciBldmVyeSBjbGFpbWVkIHJvdW5kAAAAAAVjbGFpbQAAAAAAAAMAAAAAAAAABHVzZXIAAAATAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAZlcG9jaHMAAAAAA+oAAAAKAAAAAA==",
+        "AAAAAAAAAUVGdW5jdGlvbiB0byBjbGFpbSB3aW5uaW5ncyBvciByZWZ1bmRzIGludG8gdGhlIGludGVybmFsIGJhbGFuY2UKIyBQYXJhbWV0ZXJzCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlciBjbGFpbWluZwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwotIGBlcG9jaHNgOiBUaGUgZXBvY2hzIG9mIHRoZSByb3VuZHMgdG8gY2xhaW0KIyBFdmVudHMKLSBgQ0xBSU1FRGA6IEVtaXR0ZWQgZm9yIGV2ZXJ5IGNsYWltZWQgcm91bmQKLSBgQkFMQU5DRV9DUkVESVRFRGA6IEVtaXR0ZWQgZm9yIGV2ZXJ5IHRva2VuIGNyZWRpdGVkIHRvIHRoZSBiYWxhbmNlAAAAAAAAEGNsYWltX3RvX2JhbGFuY2UAAAADAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAGZXBvY2hzAAAAAAPqAAAACgAAAAA=",
+        "AAAAAAAAAnBGdW5jdGlvbiB0byBjbGFpbSB3aW5uaW5ncyBvciByZWZ1bmRzIGFuZCBiZXQgdGhlbSBvbiB0aGUgY3VycmVudCByb3VuZCBvZiB0aGUgc2FtZSBzZXJpZXMKVGhlIGJldCBpcyBwbGFjZWQgaW4gdGhlIGJldHRpbmcgdG9rZW4sIHRoZSByZXN0IG9mIHRoZSBwYXlvdXRzIGlzIGNyZWRpdGVkIHRvIHRoZSBpbnRlcm5hbCBiYWxhbmNlCiMgUGFyYW1ldGVycwotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIgY2xhaW1pbmcKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBzZXJpZXMKLSBgY2xhaW1fZXBvY2hzYDogVGhlIGVwb2NocyBvZiB0aGUgcm91bmRzIHRvIGNsYWltCi0gYHBvc2l0aW9uYDogVGhlIHNpZGUgb2YgdGhlIG5ldyBiZXQKLSBgYW1vdW50YDogVGhlIGFtb3VudCB0byBiZXQsIG9yIGBOb25lYCB0byBiZXQgYWxsIG9mIHRoZSBiZXR0aW5nIHRva2VuIHBheW91dHMKIyBFdmVudHMKLSBgQ0xBSU1FRGA6IEVtaXR0ZWQgZm9yIGV2ZXJ5IGNsYWltZWQgcm91bmQKLSBgQkVUX1BMQUNFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgYmV0IGlzIHBsYWNlZAotIGBCQUxBTkNFX0NSRURJVEVEYDogRW1pdHRlZCBmb3IgZXZlcnkgdG9rZW4gY3JlZGl0ZWQgdG8gdGhlIGJhbGFuY2UAAAANY2xhaW1fYW5kX2JldAAAAAAAAAUAAAAAAAAABHVzZXIAAAATAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAxjbGFpbV9lcG9jaHMAAAPqAAAACgAAAAAAAAAIcG9zaXRpb24AAAfQAAAACFBvc2l0aW9uAAAAAAAAAAZhbW91bnQAAAAAA+gAAAALAAAAAA==",
+        "AAAAAAAAAO9GdW5jdGlvbiB0byBzZXR0bGUgdGhlIGVuZGVkIHJvdW5kcyBvZiBhIHVzZXIgaW50byBpdHMgc3RhdHMgYW5kIHRoZSBzZWFzb24gbGVhZGVyYm9hcmRzClN0YXRzIGFyZSBzZXR0bGVkIHdoZW5ldmVyIHRoZSB1c2VyIGJldHMgb3IgY2xhaW1zLCB0aGlzIGNvdmVycyB1c2VycyB3aG8gZG8gbmVpdGhlcgpDYWxsYWJsZSBieSBhbnlvbmUKIyBQYXJhbWV0ZXJzCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlcgAAAAAPc3luY191c2VyX3N0YXRzAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAA==",
+        "AAAAAAAAAQpGdW5jdGlvbiB0byBkZXBvc2l0IHRva2VucyBpbnRvIHRoZSBpbnRlcm5hbCBiYWxhbmNlCiMgUGFyYW1ldGVycwotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIgZGVwb3NpdGluZwotIGB0b2tlbmA6IFRoZSBhZGRyZXNzIG9mIGFuIGFjY2VwdGVkIHRva2VuCi0gYGFtb3VudGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHRvIGRlcG9zaXQKIyBFdmVudHMKLSBgQkFMQU5DRV9DUkVESVRFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgYmFsYW5jZSBpcyBjcmVkaXRlZAAAAAAAB2RlcG9zaXQAAAAAAwAAAAAAAAAEdXNlcgAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAQNGdW5jdGlvbiB0byB3aXRoZHJhdyB0b2tlbnMgZnJvbSB0aGUgaW50ZXJuYWwgYmFsYW5jZQojIFBhcmFtZXRlcnMKLSBgdXNlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB1c2VyIHdpdGhkcmF3aW5nCi0gYHRva2VuYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHRva2VuCi0gYGFtb3VudGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHRvIHdpdGhkcmF3CiMgRXZlbnRzCi0gYEJBTEFOQ0VfREVCSVRFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgYmFsYW5jZSBpcyBkZWJpdGVkAAAAAAh3aXRoZHJhdwAAAAMAAAAAAAAABHVzZXIAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAdpGdW5jdGlvbiB0byBjcmVhdGUgYSBzdGFuZGluZyBvcmRlciBiZXR0aW5nIHRoZSBzYW1lIGFtb3VudCBvbiB0aGUgc2FtZSBzaWRlIGZvciBzZXZlcmFsIHJvdW5kcwpUaGUgc3Rha2Ugb2YgZXZlcnkgcm91bmQgaXMgZXNjcm93ZWQgdXBmcm9udCBpbiB0aGUgYmV0dGluZyB0b2tlbgojIFBhcmFtZXRlcnMKLSBgdXNlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB1c2VyIGNyZWF0aW5nIHRoZSBvcmRlcgotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcyB0byBiZXQgb24KLSBgcG9zaXRpb25gOiBUaGUgc2lkZSB0byBiZXQgb24gZXZlcnkgcm91bmQKLSBgYW1vdW50YDogVGhlIGFtb3VudCB0byBiZXQgZXZlcnkgcm91bmQKLSBgcm91bmRzYDogVGhlIG51bWJlciBvZiByb3VuZHMgdG8gYmV0IG9uCiMgRXZlbnRzCi0gYFNUQU5ESU5HX09SREVSX0NSRUFURURgOiBFbWl0dGVkIHdoZW4gdGhlIG9yZGVyIGlzIGNyZWF0ZWQAAAAAABVjcmVhdGVfc3RhbmRpbmdfb3JkZXIAAAAAAAAFAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAIcG9zaXRpb24AAAfQAAAACFBvc2l0aW9uAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABnJvdW5kcwAAAAAABAAAAAA=",
+        "AAAAAAAAANBGdW5jdGlvbiB0byBjYW5jZWwgYSBzdGFuZGluZyBvcmRlciBhbmQgcmVmdW5kIHRoZSB1bnNwZW50IGVzY3JvdwojIFBhcmFtZXRlcnMKLSBgdXNlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB1c2VyIGNhbmNlbGxpbmcgdGhlIG9yZGVyCiMgRXZlbnRzCi0gYFNUQU5ESU5HX09SREVSX0NBTkNFTExFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgb3JkZXIgaXMgY2FuY2VsbGVkAAAAFWNhbmNlbF9zdGFuZGluZ19vcmRlcgAAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAA==",
+        "AAAAAAAAAWpLZWVwZXIgZnVuY3Rpb24gdG8gcGxhY2UgdGhlIGJldHMgb2YgdGhlIHN0YW5kaW5nIG9yZGVycyBvbiB0aGUgY3VycmVudCByb3VuZCBvZiBhIHNlcmllcwpDYWxsYWJsZSBieSBhbnlvbmUgd2hpbGUgdGhlIGN1cnJlbnQgcm91bmQgaXMgYmV0dGFibGUKT3JkZXJzIHdob3NlIHVzZXIgYWxyZWFkeSBiZXQgb24gdGhlIHJvdW5kIGFyZSBza2lwcGVkIGZvciB0aGF0IHJvdW5kCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwojIFJldHVybnMKLSBgdTMyYDogVGhlIG51bWJlciBvZiBiZXRzIHBsYWNlZAojIEV2ZW50cwotIGBCRVRfUExBQ0VEYDogRW1pdHRlZCBmb3IgZXZlcnkgYmV0IHBsYWNlZAAAAAAAF2V4ZWN1dGVfc3RhbmRpbmdfb3JkZXJzAAAAAAEAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAABAAAABA==",
+        "AAAAAAAAAt5GdW5jdGlvbiB0byBwcm9wb3NlIGEgcGVlci10by1wZWVyIGJldCBvbiBhIHJvdW5kLCBlc2Nyb3dpbmcgdGhlIHN0YWtlIG9mIHRoZSBwcm9wb3NlcgpUaGUgYmV0IGlzIGFjY2VwdGVkIGJ5IHRha2luZyB0aGUgb3Bwb3NpdGUgc2lkZSwgYW5kIGlzIHNldHRsZWQgYnkgdGhlIG91dGNvbWUgb2YgdGhlIHJvdW5kCndpdGhvdXQgZW50ZXJpbmcgaXRzIHBvb2xzCiMgUGFyYW1ldGVycwotIGBwcm9wb3NlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB1c2VyIHByb3Bvc2luZyB0aGUgYmV0Ci0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgc2VyaWVzCi0gYGVwb2NoYDogVGhlIGVwb2NoIG9mIHRoZSByb3VuZCB0byBiZXQgb24KLSBgcG9zaXRpb25gOiBUaGUgc2lkZSBvZiB0aGUgcHJvcG9zZXIsIHRoZSB0YWtlciBnZXRzIHRoZSBvcHBvc2l0ZSBzaWRlCi0gYHN0YWtlYDogVGhlIGFtb3VudCBzdGFrZWQgYnkgdGhlIHByb3Bvc2VyCi0gYGNvdW50ZXJfc3Rha2VgOiBUaGUgYW1vdW50IHRoZSB0YWtlciBzaG91bGQgc3Rha2UsIGVxdWFsIHRvIHRoZSBzdGFrZSBpZiBub3Qgc2V0Ci0gYGNvdW50ZXJwYXJ0eWA6IFRoZSBvbmx5IHVzZXIgYWxsb3dlZCB0byBhY2NlcHQgdGhlIGJldCwgYW55b25lIGlmIG5vdCBzZXQKIyBSZXR1cm5zCi0gYHUzMmA6IFRoZSBpZCBvZiB0aGUgYmV0CiMgRXZlbnRzCi0gYFAyUF9CRVRfUFJPUE9TRURgOiBFbWl0dGVkIHdoZW4gdGhlIGJldCBpcyBwcm9wb3NlZAAAAAAAD3Byb3Bvc2VfcDJwX2JldAAAAAAHAAAAAAAAAAhwcm9wb3NlcgAAABMAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAIcG9zaXRpb24AAAfQAAAACFBvc2l0aW9uAAAAAAAAAAVzdGFrZQAAAAAAAAsAAAAAAAAADWNvdW50ZXJfc3Rha2UAAAAAAAPoAAAACwAAAAAAAAAMY291bnRlcnBhcnR5AAAD6AAAABMAAAABAAAABA==",
+        "AAAAAAAAAPdGdW5jdGlvbiB0byBhY2NlcHQgYSBwZWVyLXRvLXBlZXIgYmV0IG9uIHRoZSBvcHBvc2l0ZSBzaWRlLCBlc2Nyb3dpbmcgdGhlIGNvdW50ZXIgc3Rha2UKIyBQYXJhbWV0ZXJzCi0gYHRha2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIgYWNjZXB0aW5nIHRoZSBiZXQKLSBgYmV0X2lkYDogVGhlIGlkIG9mIHRoZSBiZXQKIyBFdmVudHMKLSBgUDJQX0JFVF9BQ0NFUFRFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgYmV0IGlzIGFjY2VwdGVkAAAAAA5hY2NlcHRfcDJwX2JldAAAAAAAAgAAAAAAAAAFdGFrZXIAAAAAAAATAAAAAAAAAAZiZXRfaWQAAAAAAAQAAAAA",
+        "AAAAAAAAAL1GdW5jdGlvbiB0byBjYW5jZWwgYSBwZWVyLXRvLXBlZXIgYmV0IHRoYXQgd2FzIG5vdCBhY2NlcHRlZCBhbmQgcmVmdW5kIHRoZSBzdGFrZQojIFBhcmFtZXRlcnMKLSBgYmV0X2lkYDogVGhlIGlkIG9mIHRoZSBiZXQKIyBFdmVudHMKLSBgUDJQX0JFVF9DQU5DRUxMRURgOiBFbWl0dGVkIHdoZW4gdGhlIGJldCBpcyBjYW5jZWxsZWQAAAAAAAAOY2FuY2VsX3AycF9iZXQAAAAAAAEAAAAAAAAABmJldF9pZAAAAAAABAAAAAA=",
+        "AAAAAAAAAVBGdW5jdGlvbiB0byBzZXR0bGUgYSBtYXRjaGVkIHBlZXItdG8tcGVlciBiZXQgd2l0aCB0aGUgb3V0Y29tZSBvZiBpdHMgcm91bmQKVGhlIHdpbm5lciB0YWtlcyBib3RoIHN0YWtlcyBtaW51cyB0aGUgdHJlYXN1cnkgZmVlLCBib3RoIHN0YWtlcyBhcmUgcmVmdW5kZWQKaWYgdGhlIHJvdW5kIGVuZHMgd2l0aG91dCBhIHdpbm5pbmcgc2lkZSBvciBpcyBuZXZlciBlbmRlZApDYWxsYWJsZSBieSBhbnlvbmUKIyBQYXJhbWV0ZXJzCi0gYGJldF9pZGA6IFRoZSBpZCBvZiB0aGUgYmV0CiMgRXZlbnRzCi0gYFAyUF9CRVRfU0VUVExFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgYmV0IGlzIHNldHRsZWQAAAAOc2V0dGxlX3AycF9iZXQAAAAAAAEAAAAAAAAABmJldF9pZAAAAAAABAAAAAA=",
+        "AAAAAAAAARpGdW5jdGlvbiBmb3IgYSB1c2VyIHRvIGxvY2sgdGhlbXNlbHZlcyBvdXQgb2YgYmV0dGluZyB1bnRpbCBhIHRpbWVzdGFtcApBbiBleGNsdXNpb24gY2FuIGJlIGV4dGVuZGVkIGJ1dCBub3Qgc2hvcnRlbmVkCiMgUGFyYW1ldGVycwotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIKLSBgdW50aWxfdGltZXN0YW1wYDogVGhlIHRpbWUgYmV0dGluZyBpcyBhbGxvd2VkIGFnYWluCiMgRXZlbnRzCi0gYFNFTEZfRVhDTFVERURgOiBFbWl0dGVkIHdoZW4gdGhlIGV4Y2x1c2lvbiBpcyBzZXQAAAAAAAxzZWxmX2V4Y2x1ZGUAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAPdW50aWxfdGltZXN0YW1wAAAAAAYAAAAA",
+        "AAAAAAAAAWtGdW5jdGlvbiBmb3IgYSB1c2VyIHRvIHNldCB0aGUgbWF4aW11bSBhbW91bnQgdGhleSBjYW4gd2FnZXIgcGVyIGRheSwgdmFsdWVkIGluIHRoZSBiZXR0aW5nIHRva2VuCkEgdGlnaHRlciBsaW1pdCBhcHBsaWVzIGF0IG9uY2UsIGEgbG9vc2VyIG9uZSBvbmx5IGFmdGVyIGEgY29vbGRvd24gb2YgNyBkYXlzCiMgUGFyYW1ldGVycwotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIKLSBgbWF4X3dhZ2VyX3Blcl9kYXlgOiBUaGUgbWF4aW11bSBhbW91bnQgd2FnZXJlZCBwZXIgZGF5LCAwIGZvciBubyBsaW1pdAojIEV2ZW50cwotIGBVU0VSX0xJTUlUX1VQREFURURgOiBFbWl0dGVkIHdoZW4gdGhlIGxpbWl0IGlzIHVwZGF0ZWQAAAAADnNldF91c2VyX2xpbWl0AAAAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAARbWF4X3dhZ2VyX3Blcl9kYXkAAAAAAAALAAAAAA==",
+        "AAAAAAAAAR5GbGFzaCBsb2FuIGZ1bmN0aW9uIHRvIGJvcnJvdyB0b2tlbnMgdGVtcG9yYXJpbHkKTG9hbnMgYXJlIGJhY2tlZCBieSB0aGUgaG91c2UgdmF1bHQgbGlxdWlkaXR5IGFuZCB0aGUgZmVlIGFjY3J1ZXMgdG8gdmF1bHQgTFBzCiMgUGFyYW1ldGVycwotIGBpbml0aWF0b3JgOiBUaGUgYWRkcmVzcyByZXF1ZXN0aW5nIHRoZSBsb2FuCi0gYGFtb3VudGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHRvIGJvcnJvdwotIGByZWNlaXZlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSByZWNlaXZlciBvZiB0aGUgdG9rZW5zAAAAAAAKZmxhc2hfbG9hbgAAAAAAAwAAAAAAAAAJaW5pdGlhdG9yAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAhyZWNlaXZlcgAAABMAAAAA",
+        "AAAAAAAAASFGdW5jdGlvbiB0byBkZXBvc2l0IGJldHRpbmcgdG9rZW5zIGludG8gdGhlIGhvdXNlIHZhdWx0CiMgUGFyYW1ldGVycwotIGBscGA6IFRoZSBhZGRyZXNzIG9mIHRoZSBsaXF1aWRpdHkgcHJvdmlkZXIKLSBgYW1vdW50YDogVGhlIGFtb3VudCBvZiB0b2tlbnMgdG8gZGVwb3NpdAojIFJldHVybnMKLSBgaTEyOGA6IFRoZSBhbW91bnQgb2YgdmF1bHQgc2hhcmVzIG1pbnRlZAojIEV2ZW50cwotIGBWQVVMVF9ERVBPU0lUYDogRW1pdHRlZCB3aGVuIHRva2VucyBhcmUgZGVwb3NpdGVkIGludG8gdGhlIHZhdWx0AAAAAAAADXZhdWx0X2RlcG9zaXQAAAAAAAACAAAAAAAAAAJscAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAAAs=",
+        "AAAAAAAAAR9GdW5jdGlvbiB0byByZWRlZW0gdmF1bHQgc2hhcmVzIGZvciBiZXR0aW5nIHRva2VucwojIFBhcmFtZXRlcnMKLSBgbHBgOiBUaGUgYWRkcmVzcyBvZiB0aGUgbGlxdWlkaXR5IHByb3ZpZGVyCi0gYHNoYXJlc2A6IFRoZSBhbW91bnQgb2YgdmF1bHQgc2hhcmVzIHRvIHJlZGVlbQojIFJldHVybnMKLSBgaTEyOGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHdpdGhkcmF3bgojIEV2ZW50cwotIGBWQVVMVF9XSVRIRFJBV2A6IEVtaXR0ZWQgd2hlbiB0b2tlbnMgYXJlIHdpdGhkcmF3biBmcm9tIHRoZSB2YXVsdAAAAAAOdmF1bHRfd2l0aGRyYXcAAAAAAAIAAAAAAAAAAmxwAAAAAAATAAAAAAAAAAZzaGFyZXMAAAAAAAsAAAABAAAACw==",
+        "AAAAAAAAAZxGdW5jdGlvbiB0byBjcmVhdGUgYSBuZXcgc2VyaWVzIG9mIHJvdW5kcyB3aXRoIGl0cyBvd24gdGltZWZyYW1lClNlcmllcyBzaGFyZSB0aGUgdG9rZW4sIHRyZWFzdXJ5IGFuZCBvcmFjbGUgY29uZmlndXJhdGlvbgpPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgaW50ZXJ2YWxfc2Vjb25kc2A6IER1cmF0aW9uIG9mIGVhY2ggcm91bmQgb2YgdGhlIHNlcmllcyBpbiBzZWNvbmRzCi0gYGJ1ZmZlcl9zZWNvbmRzYDogQnVmZmVyIHRvIGV4ZWN1dGUgdGhlIHJvdW5kcyBvZiB0aGUgc2VyaWVzIGluIHNlY29uZHMKIyBSZXR1cm5zCi0gYHUzMmA6IFRoZSBpZCBvZiB0aGUgbmV3IHNlcmllcwojIEV2ZW50cwotIGBTRVJJRVNfQ1JFQVRFRGA6IEVtaXR0ZWQgd2hlbiBhIHNlcmllcyBpcyBjcmVhdGVkAAAADWNyZWF0ZV9zZXJpZXMAAAAAAAACAAAAAAAAABBpbnRlcnZhbF9zZWNvbmRzAAAABgAAAAAAAAAOYnVmZmVyX3NlY29uZHMAAAAAAAYAAAABAAAABA==",
+        "AAAAAAAAAlxGdW5jdGlvbiB0byBjcmVhdGUgYSBuZXcgc2VyaWVzIHdob3NlIHJvdW5kcyBhcmUgYmV0IG9uIHByaWNlIGJ1Y2tldHMgaW5zdGVhZCBvZiB1cC9kb3duCkJvdW5kYXJpZXMgc3BsaXQgdGhlIHByaWNlIGNoYW5nZSBmcm9tIGxvY2sgdG8gY2xvc2UgaW50byBidWNrZXRzLCBhIGNoYW5nZSBlcXVhbAp0byBhIGJvdW5kYXJ5IGZhbGxzIGluIHRoZSBidWNrZXQgYWJvdmUgaXQKT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYGludGVydmFsX3NlY29uZHNgOiBEdXJhdGlvbiBvZiBlYWNoIHJvdW5kIG9mIHRoZSBzZXJpZXMgaW4gc2Vjb25kcwotIGBidWZmZXJfc2Vjb25kc2A6IEJ1ZmZlciB0byBleGVjdXRlIHRoZSByb3VuZHMgb2YgdGhlIHNlcmllcyBpbiBzZWNvbmRzCi0gYGJvdW5kYXJpZXNgOiBBc2NlbmRpbmcgcHJpY2UgY2hhbmdlcyByZWxhdGl2ZSB0byB0aGUgbG9jayBwcmljZSAoc2NhbGVkIGJ5IDEwMCwgZS5nLiwgLTEwMCA9IC0xJSkKIyBSZXR1cm5zCi0gYHUzMmA6IFRoZSBpZCBvZiB0aGUgbmV3IHNlcmllcwojIEV2ZW50cwotIGBTRVJJRVNfQ1JFQVRFRGA6IEVtaXR0ZWQgd2hlbiBhIHNlcmllcyBpcyBjcmVhdGVkAAAAFGNyZWF0ZV9idWNrZXRfc2VyaWVzAAAAAwAAAAAAAAAQaW50ZXJ2YWxfc2Vjb25kcwAAAAYAAAAAAAAADmJ1ZmZlcl9zZWNvbmRzAAAAAAAGAAAAAAAAAApib3VuZGFyaWVzAAAAAAPqAAAABQAAAAEAAAAE",
+        "AAAAAAAAAZBGdW5jdGlvbiB0byBjcmVhdGUgYSBuZXcgc2VyaWVzIG9mIHN0cmlrZSByb3VuZHMKU3RyaWtlIHJvdW5kcyBhcmUgY3JlYXRlZCBvbmUgYnkgb25lIHdpdGggdGhlaXIgb3duIHN0cmlrZSBwcmljZSBhbmQgZXhwaXJ5LCBhbmQKc2V2ZXJhbCBvZiB0aGVtIGNhbiBiZSBvcGVuIGF0IHRoZSBzYW1lIHRpbWUKT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYGJ1ZmZlcl9zZWNvbmRzYDogQnVmZmVyIHRvIHNldHRsZSBhIHN0cmlrZSByb3VuZCBhZnRlciBpdHMgZXhwaXJ5IGluIHNlY29uZHMKIyBSZXR1cm5zCi0gYHUzMmA6IFRoZSBpZCBvZiB0aGUgbmV3IHNlcmllcwojIEV2ZW50cwotIGBTRVJJRVNfQ1JFQVRFRGA6IEVtaXR0ZWQgd2hlbiBhIHNlcmllcyBpcyBjcmVhdGVkAAAAFGNyZWF0ZV9zdHJpa2Vfc2VyaWVzAAAAAQAAAAAAAAAOYnVmZmVyX3NlY29uZHMAAAAAAAYAAAABAAAABA==",
+        "AAAAAAAAAjdGdW5jdGlvbiB0byBjcmVhdGUgYSBuZXcgc2VyaWVzIHdob3NlIHJvdW5kcyBhcmUgYmV0IG9uIHdoaWNoIG9mIHR3byBvcmFjbGUgYXNzZXRzCmhhcyB0aGUgaGlnaGVyIHBlcmNlbnRhZ2UgY2hhbmdlIGJldHdlZW4gbG9jayBhbmQgY2xvc2UKT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYGludGVydmFsX3NlY29uZHNgOiBEdXJhdGlvbiBvZiBlYWNoIHJvdW5kIG9mIHRoZSBzZXJpZXMgaW4gc2Vjb25kcwotIGBidWZmZXJfc2Vjb25kc2A6IEJ1ZmZlciB0byBleGVjdXRlIHRoZSByb3VuZHMgb2YgdGhlIHNlcmllcyBpbiBzZWNvbmRzCi0gYGFzc2V0YDogVGhlIGZpcnN0IGFzc2V0LCBwb29sZWQgb24gdGhlIGJ1bGwgc2lkZSBvZiB0aGUgcm91bmRzCi0gYHJpdmFsX2Fzc2V0YDogVGhlIHNlY29uZCBhc3NldCwgcG9vbGVkIG9uIHRoZSBiZWFyIHNpZGUgb2YgdGhlIHJvdW5kcwojIFJldHVybnMKLSBgdTMyYDogVGhlIGlkIG9mIHRoZSBuZXcgc2VyaWVzCiMgRXZlbnRzCi0gYFNFUklFU19DUkVBVEVEYDogRW1pdHRlZCB3aGVuIGEgc2VyaWVzIGlzIGNyZWF0ZWQAAAAAGmNyZWF0ZV9oZWFkX3RvX2hlYWRfc2VyaWVzAAAAAAAEAAAAAAAAABBpbnRlcnZhbF9zZWNvbmRzAAAABgAAAAAAAAAOYnVmZmVyX3NlY29uZHMAAAAAAAYAAAAAAAAABWFzc2V0AAAAAAAAEQAAAAAAAAALcml2YWxfYXNzZXQAAAAAEQAAAAEAAAAE",
+        "AAAAAAAAAmNGdW5jdGlvbiB0byBjcmVhdGUgYSBuZXcgc2VyaWVzIHdob3NlIHJvdW5kcyBhcmUgYmV0IG9uIHRoZSBzaXplIG9mIHRoZSBwcmljZSBtb3ZlCkEgcm91bmQgaXMgYSBiaWcgbW92ZSB3aGVuIHRoZSBwcmljZSBjaGFuZ2VzIGZyb20gbG9jayB0byBjbG9zZSBieSBtb3JlIHRoYW4gdGhlIHRocmVzaG9sZAppbiBlaXRoZXIgZGlyZWN0aW9uLCBhbmQgYSBzbWFsbCBtb3ZlIG90aGVyd2lzZQpPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgaW50ZXJ2YWxfc2Vjb25kc2A6IER1cmF0aW9uIG9mIGVhY2ggcm91bmQgb2YgdGhlIHNlcmllcyBpbiBzZWNvbmRzCi0gYGJ1ZmZlcl9zZWNvbmRzYDogQnVmZmVyIHRvIGV4ZWN1dGUgdGhlIHJvdW5kcyBvZiB0aGUgc2VyaWVzIGluIHNlY29uZHMKLSBgbW92ZV90aHJlc2hvbGRgOiBUaGUgcHJpY2UgY2hhbmdlIHNlcGFyYXRpbmcgc21hbGwgYW5kIGJpZyBtb3ZlcyAoc2NhbGVkIGJ5IDEwMCwgZS5nLiwgMTAwID0gMSUpCiMgUmV0dXJucwotIGB1MzJgOiBUaGUgaWQgb2YgdGhlIG5ldyBzZXJpZXMKIyBFdmVudHMKLSBgU0VSSUVTX0NSRUFURURgOiBFbWl0dGVkIHdoZW4gYSBzZXJpZXMgaXMgY3JlYXRlZAAAAAAYY3JlYXRlX3ZvbGF0aWxpdHlfc2VyaWVzAAAAAwAAAAAAAAAQaW50ZXJ2YWxfc2Vjb25kcwAAAAYAAAAAAAAADmJ1ZmZlcl9zZWNvbmRzAAAAAAAGAAAAAAAAAA5tb3ZlX3RocmVzaG9sZAAAAAAABAAAAAEAAAAE",
+        "AAAAAAAAAepGdW5jdGlvbiB0byBjcmVhdGUgYSBuZXcgc2VyaWVzIG9mIHllcy9ubyBldmVudCByb3VuZHMgc2V0dGxlZCBieSByZXNvbHZlcnMKRXZlbnQgcm91bmRzIGFyZSBjcmVhdGVkIG9uZSBieSBvbmUsIGVhY2ggd2l0aCBpdHMgb3duIHF1ZXN0aW9uIGFuZCByZXNvbHZlcgpPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgYnVmZmVyX3NlY29uZHNgOiBUaW1lIGFmdGVyIHRoZSBjbG9zZSBvZiBhIHJvdW5kIHRvIHJlc29sdmUgYW5kIHNldHRsZSBpdCwgYWZ0ZXIgd2hpY2ggYmV0cyBhcmUgcmVmdW5kZWQKLSBgZGlzcHV0ZV9zZWNvbmRzYDogVGltZSBhZnRlciBhIHJlc29sdXRpb24gZHVyaW5nIHdoaWNoIHRoZSBvd25lciBjYW4gb3ZlcnJpZGUgaXQKIyBSZXR1cm5zCi0gYHUzMmA6IFRoZSBpZCBvZiB0aGUgbmV3IHNlcmllcwojIEV2ZW50cwotIGBTRVJJRVNfQ1JFQVRFRGA6IEVtaXR0ZWQgd2hlbiBhIHNlcmllcyBpcyBjcmVhdGVkAAAAAAATY3JlYXRlX2V2ZW50X3NlcmllcwAAAAACAAAAAAAAAA5idWZmZXJfc2Vjb25kcwAAAAAABgAAAAAAAAAPZGlzcHV0ZV9zZWNvbmRzAAAAAAYAAAABAAAABA==",
+        "AAAAAAAAAJlGdW5jdGlvbiB0byBzZXQgdGhlIGNvb2xkb3duIGJldHdlZW4gdmF1bHQgZGVwb3NpdHMgYW5kIHdpdGhkcmF3YWxzCk9ubHkgY2FsbGFibGUgYnkgdGhlIG93bmVyCiMgUGFyYW1ldGVycwotIGBjb29sZG93bl9zZWNvbmRzYDogVGhlIGNvb2xkb3duIGluIHNlY29uZHMAAAAAAAAac2V0X3ZhdWx0X2Nvb2xkb3duX3NlY29uZHMAAAAAAAEAAAAAAAAAEGNvb2xkb3duX3NlY29uZHMAAAAGAAAAAA==",
+        "AAAAAAAAALRGdW5jdGlvbiB0byBzZXQgdGhlIHNoYXJlIG9mIHRoZSB0cmVhc3VyeSBmZWUgcm91dGVkIHRvIHRoZSB2YXVsdApPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgZmVlX3NoYXJlYDogU2hhcmUgb2YgdGhlIHRyZWFzdXJ5IGZlZSAoc2NhbGVkIGJ5IDEwMCwgZS5nLiwgNTAwMCA9IDUwJSkAAAAcc2V0X3ZhdWx0X3RyZWFzdXJ5X2ZlZV9zaGFyZQAAAAEAAAAAAAAACWZlZV9zaGFyZQAAAAAAAAQAAAAA",
+        "AAAAAAAAAYZGdW5jdGlvbiB0byBhbGxvd2xpc3QgYW4gYWRkaXRpb25hbCBiZXR0aW5nIHRva2VuCk9ubHkgY2FsbGFibGUgYnkgdGhlIG93bmVyCiMgUGFyYW1ldGVycwotIGB0b2tlbmA6IFRoZSBhZGRyZXNzIG9mIHRoZSBTQUMgb3IgZnVuZ2libGUgdG9rZW4KLSBgbWluX2JldF9hbW91bnRgOiBNaW5pbXVtIGFtb3VudCByZXF1aXJlZCB0byBwbGFjZSBhIGJldCB3aXRoIHRoaXMgdG9rZW4KLSBgcmF0ZWA6IFRoZSB2YWx1ZSBvZiB0aGUgdG9rZW4gaW4gdGhlIGJldHRpbmcgdG9rZW4gKHNjYWxlZCBieSAxMF8wMDBfMDAwKSwgdXNlZCB0bwpob2xkIGl0cyBiZXRzIHRvIHRoZSBjYXBzCiMgRXZlbnRzCi0gYFRPS0VOX0FEREVEYDogRW1pdHRlZCB3aGVuIGEgdG9rZW4gaXMgYWxsb3dsaXN0ZWQAAAAAAAlhZGRfdG9rZW4AAAAAAAADAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAADm1pbl9iZXRfYW1vdW50AAAAAAALAAAAAAAAAARyYXRlAAAACwAAAAA=",
+        "AAAAAAAAASFGdW5jdGlvbiB0byB1cGRhdGUgdGhlIHJhdGUgb2YgYW4gYWxsb3dsaXN0ZWQgdG9rZW4gYXMgaXRzIHByaWNlIG1vdmVzCk9ubHkgY2FsbGFibGUgYnkgdGhlIG93bmVyCiMgUGFyYW1ldGVycwotIGB0b2tlbmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB0b2tlbgotIGByYXRlYDogVGhlIHZhbHVlIG9mIHRoZSB0b2tlbiBpbiB0aGUgYmV0dGluZyB0b2tlbiAoc2NhbGVkIGJ5IDEwXzAwMF8wMDApCiMgRXZlbnRzCi0gYFRPS0VOX1JBVEVfVVBEQVRFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgcmF0ZSBpcyB1cGRhdGVkAAAAAAAADnNldF90b2tlbl9yYXRlAAAAAAACAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABHJhdGUAAAALAAAAAA==",
+        "AAAAAAAAAPtGdW5jdGlvbiB0byByZW1vdmUgYSB0b2tlbiBmcm9tIHRoZSBhbGxvd2xpc3QKRXhpc3RpbmcgYmV0cyBwbGFjZWQgd2l0aCB0aGUgdG9rZW4gYXJlIHVuYWZmZWN0ZWQKT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYHRva2VuYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHRva2VuCiMgRXZlbnRzCi0gYFRPS0VOX1JFTU9WRURgOiBFbWl0dGVkIHdoZW4gYSB0b2tlbiBpcyByZW1vdmVkIGZyb20gdGhlIGFsbG93bGlzdAAAAAAMcmVtb3ZlX3Rva2VuAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAA==",
+        "AAAAAAAAAURGdW5jdGlvbiB0byBzZXQgdGhlIGFjY2VzcyBtb2RlIGdhdGluZyBiZXRzLCBmbGFzaCBsb2FucyBhbmQgZGVwb3NpdHMKQ2xhaW1zIGFuZCByZWZ1bmRzIG9mIHBsYWNlZCBiZXRzIGFyZSBuZXZlciBnYXRlZApPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgbW9kZWA6IE9wZW4gdG8gZXZlcnlvbmUsIGNsb3NlZCB0byBibG9ja2VkIGFkZHJlc3Nlcywgb3Igb3BlbiB0byBhbGxvd2VkIGFkZHJlc3NlcyBvbmx5CiMgRXZlbnRzCi0gYEFDQ0VTU19NT0RFX1VQREFURURgOiBFbWl0dGVkIHdoZW4gdGhlIGFjY2VzcyBtb2RlIGlzIHVwZGF0ZWQAAAAPc2V0X2FjY2Vzc19tb2RlAAAAAAEAAAAAAAAABG1vZGUAAAfQAAAACkFjY2Vzc01vZGUAAAAAAAA=",
+        "AAAAAAAAAPZGdW5jdGlvbiB0byBzZXQgdGhlIGNvbXBsaWFuY2UgbWFuYWdlciBtYWludGFpbmluZyB0aGUgYWNjZXNzIGxpc3RzCk9ubHkgY2FsbGFibGUgYnkgdGhlIG93bmVyCiMgUGFyYW1ldGVycwotIGBtYW5hZ2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIGNvbXBsaWFuY2UgbWFuYWdlcgojIEV2ZW50cwotIGBDT01QTElBTkNFX01BTkFHRVJfVVBEQVRFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgY29tcGxpYW5jZSBtYW5hZ2VyIGlzIHVwZGF0ZWQAAAAAABZzZXRfY29tcGxpYW5jZV9tYW5hZ2VyAAAAAAABAAAAAAAAAAdtYW5hZ2VyAAAAABMAAAAA",
+        "AAAAAAAAAUFGdW5jdGlvbiB0byBhZGQgb3IgcmVtb3ZlIGFuIGFkZHJlc3MgZnJvbSB0aGUgYmxvY2tsaXN0CkJsb2NrZWQgYWRkcmVzc2VzIGFyZSByZWZ1c2VkIGluIHRoZSBibG9ja2xpc3QgYW5kIGFsbG93bGlzdCBtb2RlcwpPbmx5IGNhbGxhYmxlIGJ5IHRoZSBjb21wbGlhbmNlIG1hbmFnZXIKIyBQYXJhbWV0ZXJzCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyB0byB1cGRhdGUKLSBgYmxvY2tlZGA6IFdoZXRoZXIgdGhlIGFkZHJlc3MgaXMgYmxvY2tlZAojIEV2ZW50cwotIGBCTE9DS0xJU1RfVVBEQVRFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgYmxvY2tsaXN0IGlzIHVwZGF0ZWQAAAAAAAALc2V0X2Jsb2NrZWQAAAAAAgAAAAAAAAAEdXNlcgAAABMAAAAAAAAAB2Jsb2NrZWQAAAAAAQAAAAA=",
+        "AAAAAAAAAP5GdW5jdGlvbiB0byBhZGQgb3IgcmVtb3ZlIGFuIGFkZHJlc3MgZnJvbSB0aGUgYWxsb3dsaXN0Ck9ubHkgY2FsbGFibGUgYnkgdGhlIGNvbXBsaWFuY2UgbWFuYWdlcgojIFBhcmFtZXRlcnMKLSBgdXNlcmA6IFRoZSBhZGRyZXNzIHRvIHVwZGF0ZQotIGBhbGxvd2VkYDogV2hldGhlciB0aGUgYWRkcmVzcyBpcyBhbGxvd2VkCiMgRXZlbnRzCi0gYEFMTE9XTElTVF9VUERBVEVEYDogRW1pdHRlZCB3aGVuIHRoZSBhbGxvd2xpc3QgaXMgdXBkYXRlZAAAAAAAC3NldF9hbGxvd2VkAAAAAAIAAAAAAAAABHVzZXIAAAATAAAAAAAAAAdhbGxvd2VkAAAAAAEAAAAA",
+        "AAAAAAAAAIpGdW5jdGlvbiB0byBzZXQgdGhlIG9wZXJhdG9yIHJlbGF5aW5nIHNpZ25lZCBiZXQgaW50ZW50cwpPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgb3BlcmF0b3JgOiBUaGUgYWRkcmVzcyBvZiB0aGUgb3BlcmF0b3IAAAAAAAxzZXRfb3BlcmF0b3IAAAABAAAAAAAAAAhvcGVyYXRvcgAAABMAAAAA",
+        "AAAAAAAAALJGdW5jdGlvbiB0byBzZXQgdGhlIHNoYXJlIG9mIHRoZSB0cmVhc3VyeSBmZWUgcGFpZCB0byByZWZlcnJlcnMKT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYGZlZV9zaGFyZWA6IFNoYXJlIG9mIHRoZSB0cmVhc3VyeSBmZWUgKHNjYWxlZCBieSAxMDAsIGUuZy4sIDIwMDAgPSAyMCUpAAAAAAAWc2V0X3JlZmVycmFsX2ZlZV9zaGFyZQAAAAAAAQAAAAAAAAAJZmVlX3NoYXJlAAAAAAAABAAAAAA=",
+        "AAAAAAAAAfRGdW5jdGlvbiB0byBjcmVhdGUgYSBzZWFzb24gb3ZlciBhIHJhbmdlIG9mIGZ1dHVyZSBlcG9jaHMgb2YgYSBzZXJpZXMKT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgc2VyaWVzIHRoZSBzZWFzb24gZm9sbG93cwotIGBzdGFydF9lcG9jaGA6IFRoZSBmaXJzdCBlcG9jaCBvZiB0aGUgc2Vhc29uCi0gYGVuZF9lcG9jaGA6IFRoZSBsYXN0IGVwb2NoIG9mIHRoZSBzZWFzb24KLSBgbWV0cmljYDogVGhlIG1ldHJpYyByYW5raW5nIHRoZSBzZWFzb24gbGVhZGVyYm9hcmQKLSBgcGF5b3V0X3RhYmxlYDogU2hhcmUgb2YgdGhlIHByaXplIHBvb2wgcGVyIHJhbmsgKHNjYWxlZCBieSAxMDAsIGUuZy4sIDUwMDAgPSA1MCUpCiMgUmV0dXJucwotIGB1MzJgOiBUaGUgaWQgb2YgdGhlIG5ldyBzZWFzb24KIyBFdmVudHMKLSBgU0VBU09OX0NSRUFURURgOiBFbWl0dGVkIHdoZW4gYSBzZWFzb24gaXMgY3JlYXRlZAAAAA1jcmVhdGVfc2Vhc29uAAAAAAAABQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAALc3RhcnRfZXBvY2gAAAAACgAAAAAAAAAJZW5kX2Vwb2NoAAAAAAAACgAAAAAAAAAGbWV0cmljAAAAAAfQAAAADVJhbmtpbmdNZXRyaWMAAAAAAAAAAAAADHBheW91dF90YWJsZQAAA+oAAAAEAAAAAQAAAAQ=",
+        "AAAAAAAAAPxGdW5jdGlvbiB0byBtb3ZlIHBhcnQgb2YgdGhlIHRyZWFzdXJ5IGludG8gdGhlIHByaXplIHBvb2wgb2YgYSBzZWFzb24KT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYHNlYXNvbl9pZGA6IFRoZSBpZCBvZiB0aGUgc2Vhc29uCi0gYGFtb3VudGA6IFRoZSBhbW91bnQgb2YgdG9rZW5zIHRvIG1vdmUKIyBFdmVudHMKLSBgU0VBU09OX0ZVTkRFRGA6IEVtaXR0ZWQgd2hlbiB0aGUgcHJpemUgcG9vbCBpcyBmdW5kZWQAAAAZZnVuZF9zZWFzb25fZnJvbV90cmVhc3VyeQAAAAAAAAIAAAAAAAAACXNlYXNvbl9pZAAAAAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAcVGdW5jdGlvbiB0byBmcmVlemUgdGhlIGxlYWRlcmJvYXJkIG9mIGFuIGVuZGVkIHNlYXNvbiBhbmQgb3BlbiBwcml6ZSBjbGFpbXMKQWxsb3dlZCBvbmNlIGV2ZXJ5IHJvdW5kIG9mIHRoZSBzZWFzb24gaGFzIGVuZGVkIGFuZCB0aGUgZ3JhY2UgcGVyaW9kIGFmdGVyIHRoZSBsYXN0IG9uZQp0byBjbG9zZSBoYXMgcGFzc2VkLCBzbyB0aGF0IHN0YXRzIG9mIGV2ZXJ5IGVudHJhbnQgY2FuIGJlIHNldHRsZWQgd2l0aCBgc3luY191c2VyX3N0YXRzYApPbmx5IHVzZXJzIHdpdGggYSBwb3NpdGl2ZSBzY29yZSBmaWxsIHRoZSBwYXlvdXQgc2xvdHMKT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYHNlYXNvbl9pZGA6IFRoZSBpZCBvZiB0aGUgc2Vhc29uCiMgRXZlbnRzCi0gYFNFQVNPTl9GSU5BTElaRURgOiBFbWl0dGVkIHdoZW4gdGhlIHNlYXNvbiBpcyBmaW5hbGl6ZWQAAAAAAAAPZmluYWxpemVfc2Vhc29uAAAAAAEAAAAAAAAACXNlYXNvbl9pZAAAAAAAAAQAAAAA",
+        "AAAAAAAAARNGdW5jdGlvbiB0byBtb3ZlIHRoZSB1bmFsbG9jYXRlZCBhbmQgdW5jbGFpbWVkIHByaXplcyBvZiBhIHNlYXNvbiBiYWNrIHRvIHRoZSB0cmVhc3VyeQpPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lciwgb25jZSB0aGUgY2xhaW0gcGVyaW9kIGFmdGVyIGZpbmFsaXphdGlvbiBoYXMgcGFzc2VkCiMgUGFyYW1ldGVycwotIGBzZWFzb25faWRgOiBUaGUgaWQgb2YgdGhlIHNlYXNvbgojIEV2ZW50cwotIGBTRUFTT05fU1dFUFRgOiBFbWl0dGVkIHdoZW4gdGhlIHNlYXNvbiBpcyBzd2VwdAAAAAAMc3dlZXBfc2Vhc29uAAAAAQAAAAAAAAAJc2Vhc29uX2lkAAAAAAAABAAAAAA=",
+        "AAAAAAAAALRGdW5jdGlvbiB0byBzZXQgdGhlIHNoYXJlIG9mIHRoZSB0cmVhc3VyeSBmZWUgZnVuZGluZyB0aGUgamFja3BvdApPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgZmVlX3NoYXJlYDogU2hhcmUgb2YgdGhlIHRyZWFzdXJ5IGZlZSAoc2NhbGVkIGJ5IDEwMCwgZS5nLiwgMTAwMCA9IDEwJSkAAAAVc2V0X2phY2twb3RfZmVlX3NoYXJlAAAAAAAAAQAAAAAAAAAJZmVlX3NoYXJlAAAAAAAABAAAAAA=",
+        "AAAAAAAAAJhGdW5jdGlvbiB0byBzZXQgdGhlIG51bWJlciBvZiByb3VuZHMgYmV0d2VlbiBqYWNrcG90IGRyYXdzCk9ubHkgY2FsbGFibGUgYnkgdGhlIG93bmVyCiMgUGFyYW1ldGVycwotIGBpbnRlcnZhbGA6IFRoZSBudW1iZXIgb2Ygcm91bmRzIHBlciBqYWNrcG90IHBlcmlvZAAAABRzZXRfamFja3BvdF9pbnRlcnZhbAAAAAEAAAAAAAAACGludGVydmFsAAAACgAAAAA=",
+        "AAAAAAAAAQpGdW5jdGlvbiB0byBzZXQgdGhlIG1pbmltdW0gZXNjcm93IG9mIGEgbmV3IHN0YW5kaW5nIG9yZGVyIGluIHRoZSBiZXR0aW5nIHRva2VuClJhaXNlcyB0aGUgY29zdCBvZiBmaWxsaW5nIHRoZSBzdGFuZGluZyBvcmRlciBzbG90cyBvZiBhIHNlcmllcwpPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgbWluX2VzY3Jvd2A6IFRoZSBtaW5pbXVtIG9mIHRoZSBhbW91bnQgdGltZXMgdGhlIHJvdW5kcyBvZiBhbiBvcmRlciwgMCBmb3Igbm9uZQAAAAAAHXNldF9zdGFuZGluZ19vcmRlcl9taW5fZXNjcm93AAAAAAAAAQAAAAAAAAAKbWluX2VzY3JvdwAAAAAACwAAAAA=",
+        "AAAAAAAAAStGdW5jdGlvbiB0byBzZXQgdGhlIGZlZSBjdXJ2ZSByZXBsYWNpbmcgdGhlIGZsYXQgdHJlYXN1cnkgZmVlClRoZSBmZWUgb2YgYSByb3VuZCBnb2VzIGZyb20gdGhlIG1pbmltdW0gZmVlIG9uIGEgYmFsYW5jZWQgcG9vbCB0byB0aGUgbWF4aW11bSBmZWUKb24gYSBvbmUtc2lkZWQgcG9vbApPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgZmVlX2N1cnZlYDogVGhlIG1pbmltdW0gYW5kIG1heGltdW0gZmVlcyAoc2NhbGVkIGJ5IDEwMCksIE5vbmUgdG8gdXNlIHRoZSBmbGF0IHRyZWFzdXJ5IGZlZQAAAAANc2V0X2ZlZV9jdXJ2ZQAAAAAAAAEAAAAAAAAACWZlZV9jdXJ2ZQAAAAAAA+gAAAfQAAAACEZlZUN1cnZlAAAAAA==",
+        "AAAAAAAAAVVGdW5jdGlvbiB0byBzZXQgdGhlIGZpeGVkIG9kZHMgcXVvdGVkIGJ5IHRoZSBob3VzZSBvbiBlYWNoIHNpZGUgb2YgYSBzZXJpZXMKT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgc2VyaWVzCi0gYGJ1bGxfb2Rkc2A6IFRoZSBvZGRzIG9mIHRoZSBidWxsIHNpZGUgKHNjYWxlZCBieSAxMF8wMDAsIGUuZy4sIDE5XzAwMCA9IDEuOXgpLCAwIHRvIGRpc2FibGUKLSBgYmVhcl9vZGRzYDogVGhlIG9kZHMgb2YgdGhlIGJlYXIgc2lkZSAoc2NhbGVkIGJ5IDEwXzAwMCwgZS5nLiwgMTlfMDAwID0gMS45eCksIDAgdG8gZGlzYWJsZQAAAAAAAA5zZXRfaG91c2Vfb2RkcwAAAAAAAwAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAJYnVsbF9vZGRzAAAAAAAABAAAAAAAAAAJYmVhcl9vZGRzAAAAAAAABAAAAAA=",
+        "AAAAAAAAAO9GdW5jdGlvbiB0byBzZXQgdGhlIHJpc2sgYnVkZ2V0IG9mIHRoZSBob3VzZSBmb3IgZml4ZWQtb2RkcyBiZXRzCk9ubHkgY2FsbGFibGUgYnkgdGhlIG93bmVyCiMgUGFyYW1ldGVycwotIGBtYXhfc2lkZV9leHBvc3VyZWA6IFRoZSBtYXhpbXVtIHBheW91dCBvd2VkIHRvIG9uZSBzaWRlIG9mIGEgcm91bmQKLSBgbWF4X3JvdW5kX2V4cG9zdXJlYDogVGhlIG1heGltdW0gbG9zcyBvZiB0aGUgaG91c2Ugb24gYSByb3VuZAAAAAAQc2V0X2hvdXNlX2xpbWl0cwAAAAIAAAAAAAAAEW1heF9zaWRlX2V4cG9zdXJlAAAAAAAACwAAAAAAAAASbWF4X3JvdW5kX2V4cG9zdXJlAAAAAAALAAAAAA==",
+        "AAAAAAAAAMlGdW5jdGlvbiB0byBzZXQgdGhlIG1heGltdW0gYW1vdW50IG9mIGEgc2luZ2xlIGJldCwgYmV0cyBpbiBvdGhlciB0b2tlbnMgYXJlIHZhbHVlZCBpbgp0aGUgYmV0dGluZyB0b2tlbgpPbmx5IGNhbGxhYmxlIGJ5IHRoZSBvd25lcgojIFBhcmFtZXRlcnMKLSBgbWF4X2JldF9hbW91bnRgOiBUaGUgbWF4aW11bSBiZXQgYW1vdW50LCAwIGZvciBubyBjYXAAAAAAAAASc2V0X21heF9iZXRfYW1vdW50AAAAAAABAAAAAAAAAA5tYXhfYmV0X2Ftb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAANRGdW5jdGlvbiB0byBzZXQgdGhlIG1heGltdW0gdG90YWwgb2YgYSByb3VuZCBhY3Jvc3MgaXRzIHRva2VuIHBvb2xzLCB2YWx1ZWQgaW4gdGhlIGJldHRpbmcgdG9rZW4KT25seSBjYWxsYWJsZSBieSB0aGUgb3duZXIKIyBQYXJhbWV0ZXJzCi0gYG1heF9yb3VuZF9hbW91bnRgOiBUaGUgbWF4aW11bSB0b3RhbCBhbW91bnQgYmV0IG9uIGEgcm91bmQsIDAgZm9yIG5vIGNhcAAAABRzZXRfbWF4X3JvdW5kX2Ftb3VudAAAAAEAAAAAAAAAEG1heF9yb3VuZF9hbW91bnQAAAALAAAAAA==",
+        "AAAAAAAAATlGdW5jdGlvbiB0byBzZXQgdGhlIHJvbGxpbmcgY2FwIG9mIGV2ZXJ5IHVzZXIsIHZhbHVlZCBpbiB0aGUgYmV0dGluZyB0b2tlbgpBIHVzZXIgY2FuIGJldCBhdCBtb3N0IGBhbW91bnRgIG92ZXIgdGhlIGxhc3QgYHJvdW5kc2Agcm91bmRzIG9mIGEgc2VyaWVzCk9ubHkgY2FsbGFibGUgYnkgdGhlIG93bmVyCiMgUGFyYW1ldGVycwotIGBhbW91bnRgOiBUaGUgbWF4aW11bSBhbW91bnQgYmV0IG92ZXIgdGhlIHdpbmRvdywgMCBmb3Igbm8gY2FwCi0gYHJvdW5kc2A6IFRoZSBudW1iZXIgb2Ygcm91bmRzIGluIHRoZSB3aW5kb3csIGF0IG1vc3QgMTAwAAAAAAAADHNldF91c2VyX2NhcAAAAAIAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAGcm91bmRzAAAAAAAEAAAAAA==",
         "AAAAAAAAAFtJbnRlcm5hbCBmdW5jdGlvbiB0byBnZXQgWExNIHByaWNlIGZyb20gdGhlIG9yYWNsZQojIFJldHVybnMKLSBgaTEyOGA6IFhMTSBwcmljZSBpbiBzdHJvb3BzAAAAABRnZXRfeGxtX29yYWNsZV9wcmljZQAAAAAAAAABAAAACw==",
-        "AAAAAAAAAAAAAAAWZ2V0X2lzX2dlbmVzaXNfc3RhcnRlZAAAAAAAAAAAAAEAAAAB",
-        "AAAAAAAAAAAAAAAVZ2V0X2lzX2dlbmVzaXNfbG9ja2VkAAAAAAAAAAAAAAEAAAAB",
-        "AAAAAAAAAAAAAAARZ2V0X2N1cnJlbnRfZXBvY2gAAAAAAAAAAAAAAQAAAAo=",
+        "AAAAAAAAAAAAAAAQZ2V0X21hcmtldF9hc3NldAAAAAAAAAABAAAAEQ==",
+        "AAAAAAAAAAAAAAAQZ2V0X3Nlcmllc19jb3VudAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAK9SZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIGJ1Y2tldCBib3VuZGFyaWVzIG9mIGEgc2VyaWVzCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwojIFJldHVybnMKLSBgVmVjPGkzMj5gOiBUaGUgYm91bmRhcmllcywgZW1wdHkgZm9yIGFuIHVwL2Rvd24gc2VyaWVzAAAAABJnZXRfc2VyaWVzX2J1Y2tldHMAAAAAAAEAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAABAAAD6gAAAAU=",
+        "AAAAAAAAAMRSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIGFzc2V0cyBvZiBhIGhlYWQtdG8taGVhZCBzZXJpZXMKIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgc2VyaWVzCiMgUmV0dXJucwotIGBWZWM8U3ltYm9sPmA6IFRoZSBmaXJzdCBhbmQgc2Vjb25kIGFzc2V0LCBlbXB0eSBmb3IgYSBzaW5nbGUgYXNzZXQgc2VyaWVzAAAAEWdldF9zZXJpZXNfYXNzZXRzAAAAAAAAAQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAEAAAPqAAAAEQ==",
+        "AAAAAAAAAMBSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIG1vdmUgdGhyZXNob2xkIG9mIGEgdm9sYXRpbGl0eSBzZXJpZXMKIyBQYXJhbWV0ZXJzCi0gYHNlcmllc19pZGA6IFRoZSBpZCBvZiB0aGUgc2VyaWVzCiMgUmV0dXJucwotIGB1MzJgOiBUaGUgdGhyZXNob2xkIChzY2FsZWQgYnkgMTAwKSwgMCBmb3IgYSBkaXJlY3Rpb25hbCBzZXJpZXMAAAASZ2V0X21vdmVfdGhyZXNob2xkAAAAAAABAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAPaXNfZXZlbnRfc2VyaWVzAAAAAAEAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAABAAAAAQ==",
+        "AAAAAAAAAAAAAAAZZ2V0X2V2ZW50X2Rpc3B1dGVfc2Vjb25kcwAAAAAAAAEAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAOZ2V0X2V2ZW50X2luZm8AAAAAAAIAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAEAAAfQAAAACUV2ZW50SW5mbwAAAA==",
+        "AAAAAAAAAAAAAAAQaXNfc3RyaWtlX3NlcmllcwAAAAEAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAABAAAAAQ==",
+        "AAAAAAAAAAAAAAAWZ2V0X2lzX2dlbmVzaXNfc3RhcnRlZAAAAAAAAQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAEAAAAB",
+        "AAAAAAAAAAAAAAAVZ2V0X2lzX2dlbmVzaXNfbG9ja2VkAAAAAAAAAQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAEAAAAB",
+        "AAAAAAAAAAAAAAARZ2V0X2N1cnJlbnRfZXBvY2gAAAAAAAABAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAQAAAAo=",
         "AAAAAAAAAAAAAAARZ2V0X3Rva2VuX2FkZHJlc3MAAAAAAAAAAAAAAQAAABM=",
         "AAAAAAAAAAAAAAASZ2V0X21pbl9iZXRfYW1vdW50AAAAAAAAAAAAAQAAAAs=",
         "AAAAAAAAAAAAAAAQZ2V0X3RyZWFzdXJ5X2ZlZQAAAAAAAAABAAAABA==",
         "AAAAAAAAAAAAAAATZ2V0X3RyZWFzdXJ5X2Ftb3VudAAAAAAAAAAAAQAAAAs=",
-        "AAAAAAAAAAAAAAAJZ2V0X3JvdW5kAAAAAAAAAQAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAQAAB9AAAAAFUm91bmQAAAA=",
-        "AAAAAAAAAAAAAAAMZ2V0X2JldF9pbmZvAAAAAgAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAfQAAAAB0JldEluZm8A",
-        "AAAAAAAAAAAAAAAPZ2V0X3VzZXJfcm91bmRzAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAA+oAAAAK",
+        "AAAAAAAAAAAAAAAJZ2V0X3JvdW5kAAAAAAAAAgAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAQAAB9AAAAAFUm91bmQAAAA=",
+        "AAAAAAAAAAAAAAAMZ2V0X2JldF9pbmZvAAAAAwAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAfQAAAAB0JldEluZm8A",
+        "AAAAAAAAAAAAAAAPZ2V0X3VzZXJfcm91bmRzAAAAAAIAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABHVzZXIAAAATAAAAAQAAA+oAAAAK",
         "AAAAAAAAAAAAAAASZ2V0X29yYWNsZV9hZGRyZXNzAAAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAAAAAAAUZ2V0X2ludGVydmFsX3NlY29uZHMAAAAAAAAAAQAAAAY=",
-        "AAAAAAAAAAAAAAASZ2V0X2J1ZmZlcl9zZWNvbmRzAAAAAAAAAAAAAQAAAAY=",
-        "AAAAAAAAAKxSZWFkb25seSBmdW5jdGlvbiB0byBjaGVjayBpZiBhIHJvdW5kIGlzIGJldHRhYmxlCiMgUGFyYW1ldGVycwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gY2hlY2sKIyBSZXR1cm5zCi0gYGJvb2xgOiBUcnVlIGlmIHRoZSByb3VuZCBpcyBiZXR0YWJsZSwgZmFsc2Ugb3RoZXJ3aXNlAAAAC2lzX2JldHRhYmxlAAAAAAEAAAAAAAAABWVwb2NoAAAAAAAACgAAAAEAAAAB",
+        "AAAAAAAAAAAAAAAUZ2V0X2ludGVydmFsX3NlY29uZHMAAAABAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAQAAAAY=",
+        "AAAAAAAAAAAAAAASZ2V0X2J1ZmZlcl9zZWNvbmRzAAAAAAABAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAQAAAAY=",
+        "AAAAAAAAAEZSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHRva2VucyBhY2NlcHRlZCBiZXNpZGVzIHRoZSBiZXR0aW5nIHRva2VuAAAAAAASZ2V0X2FsbG93ZWRfdG9rZW5zAAAAAAAAAAAAAQAAA+oAAAAT",
+        "AAAAAAAAAHVSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIG1pbmltdW0gYmV0IGFtb3VudCBvZiBhbiBhY2NlcHRlZCB0b2tlbgojIFBhcmFtZXRlcnMKLSBgdG9rZW5gOiBUaGUgYWRkcmVzcyBvZiB0aGUgdG9rZW4AAAAAAAAYZ2V0X3Rva2VuX21pbl9iZXRfYW1vdW50AAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAALRSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHZhbHVlIG9mIGEgdG9rZW4gaW4gdGhlIGJldHRpbmcgdG9rZW4KIyBQYXJhbWV0ZXJzCi0gYHRva2VuYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHRva2VuCiMgUmV0dXJucwotIGBpMTI4YDogVGhlIHJhdGUgb2YgdGhlIHRva2VuIChzY2FsZWQgYnkgMTBfMDAwXzAwMCkAAAAOZ2V0X3Rva2VuX3JhdGUAAAAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAHJSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHRyZWFzdXJ5IGFtb3VudCBjb2xsZWN0ZWQgaW4gYSB0b2tlbgojIFBhcmFtZXRlcnMKLSBgdG9rZW5gOiBUaGUgYWRkcmVzcyBvZiB0aGUgdG9rZW4AAAAAABlnZXRfdG9rZW5fdHJlYXN1cnlfYW1vdW50AAAAAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAIpSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIGludGVybmFsIGJhbGFuY2Ugb2YgYSB1c2VyCiMgUGFyYW1ldGVycwotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIKLSBgdG9rZW5gOiBUaGUgYWRkcmVzcyBvZiB0aGUgdG9rZW4AAAAAAAtnZXRfYmFsYW5jZQAAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAALhSZWFkb25seSBmdW5jdGlvbiB0byBjaGVjayBpZiBhIHVzZXIgY2FuIGNsYWltIHdpbm5pbmdzIGZvciBhIHJvdW5kCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQKLSBgdXNlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB1c2VyAAAACWNsYWltYWJsZQAAAAAAAAMAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAEdXNlcgAAABMAAAABAAAAAQ==",
+        "AAAAAAAAALZSZWFkb25seSBmdW5jdGlvbiB0byBjaGVjayBpZiBhIHVzZXIgY2FuIGdldCBhIHJlZnVuZCBmb3IgYSByb3VuZAojIFBhcmFtZXRlcnMKLSBgc2VyaWVzX2lkYDogVGhlIGlkIG9mIHRoZSBzZXJpZXMKLSBgZXBvY2hgOiBUaGUgZXBvY2ggb2YgdGhlIHJvdW5kCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlcgAAAAAACnJlZnVuZGFibGUAAAAAAAMAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAEdXNlcgAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAAAAAAAMZ2V0X3JlZmVycmVyAAAAAQAAAAAAAAAEdXNlcgAAABMAAAABAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAAWZ2V0X3JlZmVycmFsX2ZlZV9zaGFyZQAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAN5SZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHBheWVycyBhbGxvd2VkIHRvIGdpZnQgYmV0cyB0byBhIGJlbmVmaWNpYXJ5CiMgUGFyYW1ldGVycwotIGBiZW5lZmljaWFyeWA6IFRoZSBhZGRyZXNzIHJlY2VpdmluZyB0aGUgZ2lmdGVkIGJldHMKIyBSZXR1cm5zCi0gYFZlYzxBZGRyZXNzPmA6IFRoZSBhbGxvd2VkIHBheWVycywgZW1wdHkgd2hlbiBhbnkgcGF5ZXIgaXMgYWNjZXB0ZWQAAAAAAA9nZXRfZ2lmdF9wYXllcnMAAAAAAQAAAAAAAAALYmVuZWZpY2lhcnkAAAAAEwAAAAEAAAPqAAAAEw==",
+        "AAAAAAAAAKNSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHJlZmVycmFsIHN0YXRzIG9mIGEgcmVmZXJyZXIKRWFybmVkIGFuZCBjbGFpbWFibGUgYW1vdW50cyBhcmUgdHJhY2tlZCBwZXIgdG9rZW4KIyBQYXJhbWV0ZXJzCi0gYHJlZmVycmVyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHJlZmVycmVyAAAAABJnZXRfcmVmZXJyYWxfc3RhdHMAAAAAAAEAAAAAAAAACHJlZmVycmVyAAAAEwAAAAEAAAfQAAAADVJlZmVycmFsU3RhdHMAAAA=",
+        "AAAAAAAAAAAAAAAQZ2V0X3NlYXNvbl9jb3VudAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAKZ2V0X3NlYXNvbgAAAAAAAQAAAAAAAAAJc2Vhc29uX2lkAAAAAAAABAAAAAEAAAfQAAAABlNlYXNvbgAA",
+        "AAAAAAAAAO5SZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHNlYXNvbiBhbiBlcG9jaCBvZiBhIHNlcmllcyBjb3VudHMgdG93YXJkCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQKIyBSZXR1cm5zCi0gYHUzMmA6IFRoZSBpZCBvZiB0aGUgc2Vhc29uLCBvciAwIHdoZW4gdGhlIGVwb2NoIGlzIG91dHNpZGUgZXZlcnkgc2Vhc29uAAAAAAANZ2V0X3NlYXNvbl9pZAAAAAAAAAIAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAEAAAAE",
+        "AAAAAAAAAMJSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIGxpZmV0aW1lIHN0YXRzIG9mIGEgdXNlcgpXaW5zLCBsb3NzZXMgYW5kIG5ldCBwcm9maXQgb25seSBpbmNsdWRlIHJvdW5kcyBzZXR0bGVkIGludG8gdGhlIHN0YXRzLCBzZWUgYHN5bmNfdXNlcl9zdGF0c2AKIyBQYXJhbWV0ZXJzCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlcgAAAAAADmdldF91c2VyX3N0YXRzAAAAAAABAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAfQAAAACVVzZXJTdGF0cwAAAA==",
+        "AAAAAAAAAL5SZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHN0YXRzIG9mIGEgdXNlciB3aXRoaW4gYSBzZWFzb24KV2luIHN0cmVha3MgYXJlIG9ubHkgdHJhY2tlZCBpbiBsaWZldGltZSBzdGF0cwojIFBhcmFtZXRlcnMKLSBgc2Vhc29uX2lkYDogVGhlIGlkIG9mIHRoZSBzZWFzb24KLSBgdXNlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB1c2VyAAAAAAAVZ2V0X3NlYXNvbl91c2VyX3N0YXRzAAAAAAAAAgAAAAAAAAAJc2Vhc29uX2lkAAAAAAAABAAAAAAAAAAEdXNlcgAAABMAAAABAAAH0AAAAAlVc2VyU3RhdHMAAAA=",
+        "AAAAAAAAAX9SZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHRvcCB1c2VycyBvZiBhIHNlYXNvbiwgYmVzdCBmaXJzdApUaGUgbGVhZGVyYm9hcmQga2VlcHMgdGhlIHRvcCAxMCBlbnRyaWVzIGFuZCBpcyBvbmx5IHVwZGF0ZWQgd2hlbiBhIHVzZXIncyBvd24gc2NvcmUgY2hhbmdlcywKc28gYSB1c2VyIHB1c2hlZCBvdXQgb2YgaXQgZG9lcyBub3QgY29tZSBiYWNrIHdoZW4gdGhlIHVzZXJzIGFib3ZlIGxvc2UgZ3JvdW5kLCBvbmx5IG9uIHRoZQpuZXh0IHNldHRsZWQgcm91bmQgb2YgaXRzIG93bi4gT2ZmLWNoYWluIHJhbmtpbmdzIHNob3VsZCByZWFkIGBnZXRfc2Vhc29uX3VzZXJfc3RhdHNgCiMgUGFyYW1ldGVycwotIGBzZWFzb25faWRgOiBUaGUgaWQgb2YgdGhlIHNlYXNvbgAAAAAPZ2V0X2xlYWRlcmJvYXJkAAAAAAEAAAAAAAAACXNlYXNvbl9pZAAAAAAAAAQAAAABAAAD6gAAB9AAAAAQTGVhZGVyYm9hcmRFbnRyeQ==",
+        "AAAAAAAAAAAAAAASZ2V0X2phY2twb3RfYW1vdW50AAAAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAAVZ2V0X2phY2twb3RfZmVlX3NoYXJlAAAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAAAAAAAUZ2V0X2phY2twb3RfaW50ZXJ2YWwAAAAAAAAAAQAAAAo=",
+        "AAAAAAAAAMlSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHN0YWtlIG9mIGV2ZXJ5IGJldHRvciBpbiBhIGphY2twb3QgcGVyaW9kCk9ubHkgc3Rha2VzIG9mIHNldHRsZWQgcm91bmRzIGVudGVyIHRoZSBkcmF3IG9mIHRoZWlyIHBlcmlvZAojIFBhcmFtZXRlcnMKLSBgcGVyaW9kYDogVGhlIGphY2twb3QgcGVyaW9kLCBgKGVwb2NoIC0gMSkgLyBpbnRlcnZhbGAAAAAAAAATZ2V0X2phY2twb3RfZW50cmllcwAAAAABAAAAAAAAAAZwZXJpb2QAAAAAAAoAAAABAAAD7AAAABMAAAAL",
+        "AAAAAAAAAJpSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHN0YWtlIG9mIGV2ZXJ5IGJldHRvciBpbiBhIHJvdW5kIG9mIHRoZSBkZWZhdWx0IHNlcmllcwp0aGF0IGlzIG5vdCBzZXR0bGVkIHlldAojIFBhcmFtZXRlcnMKLSBgZXBvY2hgOiBUaGUgZXBvY2ggb2YgdGhlIHJvdW5kAAAAAAAZZ2V0X2phY2twb3Rfcm91bmRfZW50cmllcwAAAAAAAAEAAAAAAAAABWVwb2NoAAAAAAAACgAAAAEAAAPsAAAAEwAAAAs=",
+        "AAAAAAAAAAAAAAASZ2V0X3NlbGZfZXhjbHVzaW9uAAAAAAABAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAOZ2V0X3VzZXJfbGltaXQAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAA+gAAAfQAAAACVVzZXJMaW1pdAAAAA==",
+        "AAAAAAAAAMFSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIGRhaWx5IHdhZ2VyIGxpbWl0IG9mIGEgdXNlciBjdXJyZW50bHkgaW4gZm9yY2UKIyBQYXJhbWV0ZXJzCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlcgojIFJldHVybnMKLSBgaTEyOGA6IFRoZSBtYXhpbXVtIGFtb3VudCB3YWdlcmVkIHBlciBkYXksIDAgZm9yIG5vIGxpbWl0AAAAAAAAFGdldF91c2VyX3dhZ2VyX2xpbWl0AAAAAQAAAAAAAAAEdXNlcgAAABMAAAABAAAACw==",
+        "AAAAAAAAAIRSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIGFtb3VudCBhIHVzZXIgd2FnZXJlZCB0b2RheSwgdmFsdWVkIGluIHRoZSBiZXR0aW5nIHRva2VuCiMgUGFyYW1ldGVycwotIGB1c2VyYDogVGhlIGFkZHJlc3Mgb2YgdGhlIHVzZXIAAAAUZ2V0X3VzZXJfZGFpbHlfd2FnZXIAAAABAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAANZ2V0X2ZlZV9jdXJ2ZQAAAAAAAAAAAAABAAAD6AAAB9AAAAAIRmVlQ3VydmU=",
+        "AAAAAAAAAAAAAAASZ2V0X21heF9iZXRfYW1vdW50AAAAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAAUZ2V0X21heF9yb3VuZF9hbW91bnQAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAAMZ2V0X3VzZXJfY2FwAAAAAAAAAAEAAAfQAAAAB1VzZXJDYXAA",
+        "AAAAAAAAAOJSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIGFtb3VudCBhIHVzZXIgYmV0IG92ZXIgdGhlIHJvbGxpbmcgY2FwIHdpbmRvdywgdmFsdWVkIGluIHRoZSBiZXR0aW5nIHRva2VuCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwotIGBlcG9jaGA6IFRoZSBsYXN0IGVwb2NoIG9mIHRoZSB3aW5kb3cKLSBgdXNlcmA6IFRoZSBhZGRyZXNzIG9mIHRoZSB1c2VyAAAAAAAXZ2V0X3VzZXJfcm9sbGluZ19hbW91bnQAAAAAAwAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAFZXBvY2gAAAAAAAAKAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAOZ2V0X2hvdXNlX29kZHMAAAAAAAEAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAABAAAH0AAAAAlIb3VzZU9kZHMAAAA=",
+        "AAAAAAAAAAAAAAAbZ2V0X2hvdXNlX21heF9zaWRlX2V4cG9zdXJlAAAAAAAAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAcZ2V0X2hvdXNlX21heF9yb3VuZF9leHBvc3VyZQAAAAAAAAABAAAACw==",
+        "AAAAAAAAAF9SZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHZhdWx0IGFzc2V0cyByZXNlcnZlZCBmb3IgdGhlIHdvcnN0IGNhc2Ugb2Ygb3BlbiBmaXhlZC1vZGRzIHJvdW5kcwAAAAASZ2V0X2hvdXNlX2V4cG9zdXJlAAAAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAASZ2V0X2ZpeGVkX29kZHNfYmV0AAAAAAADAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAVlcG9jaAAAAAAAAAoAAAAAAAAABHVzZXIAAAATAAAAAQAAA+gAAAfQAAAADEZpeGVkT2Rkc0JldA==",
+        "AAAAAAAAAAAAAAASZ2V0X3N0YW5kaW5nX29yZGVyAAAAAAABAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAPoAAAH0AAAAA1TdGFuZGluZ09yZGVyAAAA",
+        "AAAAAAAAAAAAAAAYZ2V0X3N0YW5kaW5nX29yZGVyX3VzZXJzAAAAAQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAEAAAPqAAAAEw==",
+        "AAAAAAAAAAAAAAAdZ2V0X3N0YW5kaW5nX29yZGVyX21pbl9lc2Nyb3cAAAAAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAARZ2V0X3AycF9iZXRfY291bnQAAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAALZ2V0X3AycF9iZXQAAAAAAQAAAAAAAAAGYmV0X2lkAAAAAAAEAAAAAQAAB9AAAAAGUDJQQmV0AAA=",
+        "AAAAAAAAAAAAAAAMZ2V0X29wZXJhdG9yAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAAPZ2V0X2FjY2Vzc19tb2RlAAAAAAAAAAABAAAH0AAAAApBY2Nlc3NNb2RlAAA=",
+        "AAAAAAAAAAAAAAAWZ2V0X2NvbXBsaWFuY2VfbWFuYWdlcgAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAAKaXNfYmxvY2tlZAAAAAAAAQAAAAAAAAAEdXNlcgAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAAAAAAAKaXNfYWxsb3dlZAAAAAAAAQAAAAAAAAAEdXNlcgAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAHpSZWFkb25seSBmdW5jdGlvbiB0byBjaGVjayBpZiBhbiBhZGRyZXNzIGNhbiBiZXQsIHRha2UgZmxhc2ggbG9hbnMgYW5kIGRlcG9zaXQKIyBQYXJhbWV0ZXJzCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyB0byBjaGVjawAAAAAACmhhc19hY2Nlc3MAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAPZ2V0X3NpZ25pbmdfa2V5AAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAA+gAAAPuAAAAIA==",
+        "AAAAAAAAAHdSZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIG5vbmNlIGV4cGVjdGVkIGluIHRoZSBuZXh0IGludGVudCBvZiBhIHVzZXIKIyBQYXJhbWV0ZXJzCi0gYHVzZXJgOiBUaGUgYWRkcmVzcyBvZiB0aGUgdXNlcgAAAAAQZ2V0X2ludGVudF9ub25jZQAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAAAY=",
+        "AAAAAAAAAL9SZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIGJ5dGVzIGEgdXNlciBzaWducyBmb3IgYSBiZXQgaW50ZW50ClRoZSBwYXlsb2FkIGJpbmRzIHRoZSBpbnRlbnQgdG8gdGhpcyBjb250cmFjdCB0byBwcmV2ZW50IGNyb3NzLWNvbnRyYWN0IHJlcGxheQojIFBhcmFtZXRlcnMKLSBgaW50ZW50YDogVGhlIGJldCBpbnRlbnQgdG8gc2lnbgAAAAAWZ2V0X2JldF9pbnRlbnRfcGF5bG9hZAAAAAAAAQAAAAAAAAAGaW50ZW50AAAAAAfQAAAACUJldEludGVudAAAAAAAAAEAAAAO",
+        "AAAAAAAAAK5SZWFkb25seSBmdW5jdGlvbiB0byBnZXQgdGhlIHBvb2wgb2YgYSB0b2tlbiBpbiBhIHJvdW5kCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQKLSBgdG9rZW5gOiBUaGUgYWRkcmVzcyBvZiB0aGUgdG9rZW4AAAAAABRnZXRfcm91bmRfdG9rZW5fcG9vbAAAAAMAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAB9AAAAAJVG9rZW5Qb29sAAAA",
+        "AAAAAAAAAAAAAAAZZ2V0X2ZsYXNoX3RyZWFzdXJ5X2Ftb3VudAAAAAAAAAAAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAWZ2V0X3ZhdWx0X3RvdGFsX2Fzc2V0cwAAAAAAAAAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAWZ2V0X3ZhdWx0X3RvdGFsX3NoYXJlcwAAAAAAAAAAAAEAAAAL",
+        "AAAAAAAAAAAAAAASZ2V0X3ZhdWx0X3Bvc2l0aW9uAAAAAAABAAAAAAAAAAJscAAAAAAAEwAAAAEAAAfQAAAADVZhdWx0UG9zaXRpb24AAAA=",
+        "AAAAAAAAAAAAAAAaZ2V0X3ZhdWx0X2Nvb2xkb3duX3NlY29uZHMAAAAAAAAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAcZ2V0X3ZhdWx0X3RyZWFzdXJ5X2ZlZV9zaGFyZQAAAAAAAAABAAAABA==",
+        "AAAAAAAAANBSZWFkb25seSBmdW5jdGlvbiB0byBjaGVjayBpZiBhIHJvdW5kIGlzIGJldHRhYmxlCiMgUGFyYW1ldGVycwotIGBzZXJpZXNfaWRgOiBUaGUgaWQgb2YgdGhlIHNlcmllcwotIGBlcG9jaGA6IFRoZSBlcG9jaCBvZiB0aGUgcm91bmQgdG8gY2hlY2sKIyBSZXR1cm5zCi0gYGJvb2xgOiBUcnVlIGlmIHRoZSByb3VuZCBpcyBiZXR0YWJsZSwgZmFsc2Ugb3RoZXJ3aXNlAAAAC2lzX2JldHRhYmxlAAAAAAIAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABWVwb2NoAAAAAAAACgAAAAEAAAAB",
         "AAAAAAAAAAAAAAAJZ2V0X293bmVyAAAAAAAAAAAAAAEAAAPoAAAAEw==",
         "AAAAAAAAAAAAAAASdHJhbnNmZXJfb3duZXJzaGlwAAAAAAACAAAAAAAAAAluZXdfb3duZXIAAAAAAAATAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAAA",
         "AAAAAAAAAAAAAAAQYWNjZXB0X293bmVyc2hpcAAAAAAAAAAA",
@@ -813,10 +5651,98 @@ export class Client extends ContractClient {
     genesis_start_round: this.txFromJSON<null>,
     genesis_lock_round: this.txFromJSON<null>,
     execute_round: this.txFromJSON<null>,
+    create_strike_round: this.txFromJSON<u128>,
+    settle_strike_round: this.txFromJSON<null>,
+    create_event_round: this.txFromJSON<u128>,
+    resolve_event: this.txFromJSON<null>,
+    override_event_outcome: this.txFromJSON<null>,
+    settle_event_round: this.txFromJSON<null>,
     bet_bull: this.txFromJSON<null>,
     bet_bear: this.txFromJSON<null>,
+    bet_bucket: this.txFromJSON<null>,
+    bet_asset: this.txFromJSON<null>,
+    bet_big_move: this.txFromJSON<null>,
+    bet_small_move: this.txFromJSON<null>,
+    bet_yes: this.txFromJSON<null>,
+    bet_no: this.txFromJSON<null>,
+    bet_fixed_odds: this.txFromJSON<null>,
+    claim_fixed_odds: this.txFromJSON<i128>,
+    release_house_exposure: this.txFromJSON<null>,
+    bet_bull_with_token: this.txFromJSON<null>,
+    bet_bear_with_token: this.txFromJSON<null>,
+    bet_bull_from_balance: this.txFromJSON<null>,
+    bet_bear_from_balance: this.txFromJSON<null>,
+    bet_bull_from: this.txFromJSON<null>,
+    bet_bear_from: this.txFromJSON<null>,
+    bet_bull_for: this.txFromJSON<null>,
+    bet_bear_for: this.txFromJSON<null>,
+    set_gift_payers: this.txFromJSON<null>,
+    bet_bull_referred: this.txFromJSON<null>,
+    bet_bear_referred: this.txFromJSON<null>,
+    register_referrer: this.txFromJSON<null>,
+    claim_referral_rewards: this.txFromJSON<null>,
+    fund_season: this.txFromJSON<null>,
+    claim_season_prize: this.txFromJSON<null>,
+    register_signing_key: this.txFromJSON<null>,
+    execute_bet_intent: this.txFromJSON<null>,
+    claim: this.txFromJSON<null>,
+    claim_to_balance: this.txFromJSON<null>,
+    claim_and_bet: this.txFromJSON<null>,
+    sync_user_stats: this.txFromJSON<null>,
+    deposit: this.txFromJSON<null>,
+    withdraw: this.txFromJSON<null>,
+    create_standing_order: this.txFromJSON<null>,
+    cancel_standing_order: this.txFromJSON<null>,
+    execute_standing_orders: this.txFromJSON<u32>,
+    propose_p2p_bet: this.txFromJSON<u32>,
+    accept_p2p_bet: this.txFromJSON<null>,
+    cancel_p2p_bet: this.txFromJSON<null>,
+    settle_p2p_bet: this.txFromJSON<null>,
+    self_exclude: this.txFromJSON<null>,
+    set_user_limit: this.txFromJSON<null>,
     flash_loan: this.txFromJSON<null>,
+    vault_deposit: this.txFromJSON<i128>,
+    vault_withdraw: this.txFromJSON<i128>,
+    create_series: this.txFromJSON<u32>,
+    create_bucket_series: this.txFromJSON<u32>,
+    create_strike_series: this.txFromJSON<u32>,
+    create_head_to_head_series: this.txFromJSON<u32>,
+    create_volatility_series: this.txFromJSON<u32>,
+    create_event_series: this.txFromJSON<u32>,
+    set_vault_cooldown_seconds: this.txFromJSON<null>,
+    set_vault_treasury_fee_share: this.txFromJSON<null>,
+    add_token: this.txFromJSON<null>,
+    set_token_rate: this.txFromJSON<null>,
+    remove_token: this.txFromJSON<null>,
+    set_access_mode: this.txFromJSON<null>,
+    set_compliance_manager: this.txFromJSON<null>,
+    set_blocked: this.txFromJSON<null>,
+    set_allowed: this.txFromJSON<null>,
+    set_operator: this.txFromJSON<null>,
+    set_referral_fee_share: this.txFromJSON<null>,
+    create_season: this.txFromJSON<u32>,
+    fund_season_from_treasury: this.txFromJSON<null>,
+    finalize_season: this.txFromJSON<null>,
+    sweep_season: this.txFromJSON<null>,
+    set_jackpot_fee_share: this.txFromJSON<null>,
+    set_jackpot_interval: this.txFromJSON<null>,
+    set_standing_order_min_escrow: this.txFromJSON<null>,
+    set_fee_curve: this.txFromJSON<null>,
+    set_house_odds: this.txFromJSON<null>,
+    set_house_limits: this.txFromJSON<null>,
+    set_max_bet_amount: this.txFromJSON<null>,
+    set_max_round_amount: this.txFromJSON<null>,
+    set_user_cap: this.txFromJSON<null>,
     get_xlm_oracle_price: this.txFromJSON<i128>,
+    get_market_asset: this.txFromJSON<string>,
+    get_series_count: this.txFromJSON<u32>,
+    get_series_buckets: this.txFromJSON<Array<i32>>,
+    get_series_assets: this.txFromJSON<Array<string>>,
+    get_move_threshold: this.txFromJSON<u32>,
+    is_event_series: this.txFromJSON<boolean>,
+    get_event_dispute_seconds: this.txFromJSON<u64>,
+    get_event_info: this.txFromJSON<EventInfo>,
+    is_strike_series: this.txFromJSON<boolean>,
     get_is_genesis_started: this.txFromJSON<boolean>,
     get_is_genesis_locked: this.txFromJSON<boolean>,
     get_current_epoch: this.txFromJSON<u128>,
@@ -830,6 +5756,63 @@ export class Client extends ContractClient {
     get_oracle_address: this.txFromJSON<string>,
     get_interval_seconds: this.txFromJSON<u64>,
     get_buffer_seconds: this.txFromJSON<u64>,
+    get_allowed_tokens: this.txFromJSON<Array<string>>,
+    get_token_min_bet_amount: this.txFromJSON<i128>,
+    get_token_rate: this.txFromJSON<i128>,
+    get_token_treasury_amount: this.txFromJSON<i128>,
+    get_balance: this.txFromJSON<i128>,
+    claimable: this.txFromJSON<boolean>,
+    refundable: this.txFromJSON<boolean>,
+    get_referrer: this.txFromJSON<Option<string>>,
+    get_referral_fee_share: this.txFromJSON<u32>,
+    get_gift_payers: this.txFromJSON<Array<string>>,
+    get_referral_stats: this.txFromJSON<ReferralStats>,
+    get_season_count: this.txFromJSON<u32>,
+    get_season: this.txFromJSON<Season>,
+    get_season_id: this.txFromJSON<u32>,
+    get_user_stats: this.txFromJSON<UserStats>,
+    get_season_user_stats: this.txFromJSON<UserStats>,
+    get_leaderboard: this.txFromJSON<Array<LeaderboardEntry>>,
+    get_jackpot_amount: this.txFromJSON<i128>,
+    get_jackpot_fee_share: this.txFromJSON<u32>,
+    get_jackpot_interval: this.txFromJSON<u128>,
+    get_jackpot_entries: this.txFromJSON<Map<string, i128>>,
+    get_jackpot_round_entries: this.txFromJSON<Map<string, i128>>,
+    get_self_exclusion: this.txFromJSON<u64>,
+    get_user_limit: this.txFromJSON<Option<UserLimit>>,
+    get_user_wager_limit: this.txFromJSON<i128>,
+    get_user_daily_wager: this.txFromJSON<i128>,
+    get_fee_curve: this.txFromJSON<Option<FeeCurve>>,
+    get_max_bet_amount: this.txFromJSON<i128>,
+    get_max_round_amount: this.txFromJSON<i128>,
+    get_user_cap: this.txFromJSON<UserCap>,
+    get_user_rolling_amount: this.txFromJSON<i128>,
+    get_house_odds: this.txFromJSON<HouseOdds>,
+    get_house_max_side_exposure: this.txFromJSON<i128>,
+    get_house_max_round_exposure: this.txFromJSON<i128>,
+    get_house_exposure: this.txFromJSON<i128>,
+    get_fixed_odds_bet: this.txFromJSON<Option<FixedOddsBet>>,
+    get_standing_order: this.txFromJSON<Option<StandingOrder>>,
+    get_standing_order_users: this.txFromJSON<Array<string>>,
+    get_standing_order_min_escrow: this.txFromJSON<i128>,
+    get_p2p_bet_count: this.txFromJSON<u32>,
+    get_p2p_bet: this.txFromJSON<P2PBet>,
+    get_operator: this.txFromJSON<string>,
+    get_access_mode: this.txFromJSON<AccessMode>,
+    get_compliance_manager: this.txFromJSON<string>,
+    is_blocked: this.txFromJSON<boolean>,
+    is_allowed: this.txFromJSON<boolean>,
+    has_access: this.txFromJSON<boolean>,
+    get_signing_key: this.txFromJSON<Option<Buffer>>,
+    get_intent_nonce: this.txFromJSON<u64>,
+    get_bet_intent_payload: this.txFromJSON<Buffer>,
+    get_round_token_pool: this.txFromJSON<TokenPool>,
+    get_flash_treasury_amount: this.txFromJSON<i128>,
+    get_vault_total_assets: this.txFromJSON<i128>,
+    get_vault_total_shares: this.txFromJSON<i128>,
+    get_vault_position: this.txFromJSON<VaultPosition>,
+    get_vault_cooldown_seconds: this.txFromJSON<u64>,
+    get_vault_treasury_fee_share: this.txFromJSON<u32>,
     is_bettable: this.txFromJSON<boolean>,
     get_owner: this.txFromJSON<Option<string>>,
     transfer_ownership: this.txFromJSON<null>,
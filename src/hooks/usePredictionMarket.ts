@@ -17,6 +17,10 @@ import { useWallet } from "./useWallet";
 import { submitAndWaitForTransaction } from "../util/contract";
 
 const CONTRACT_ID = import.meta.env.PUBLIC_PREDICTION_MARKET_CONTRACT_ID || "";
+// Market series the frontend trades (series 0 is the default series)
+const SERIES_ID = Number(
+  import.meta.env.PUBLIC_PREDICTION_MARKET_SERIES_ID || 0
+);
 const TOKEN_CONTRACT_ID =
   "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
 export const RPC_URL =
@@ -116,7 +120,9 @@ export const usePredictionMarket = () => {
 
     try {
       setLoadingStates((prev) => ({ ...prev, epoch: true }));
-      const result = await retryOperation(() => client.get_current_epoch());
+      const result = await retryOperation(() =>
+        client.get_current_epoch({ series_id: SERIES_ID })
+      );
       const epoch = result.result;
       console.log("currentEpoch:", epoch);
       setCurrentEpoch(epoch);
@@ -137,7 +143,7 @@ export const usePredictionMarket = () => {
       if (!client) return null;
 
       try {
-        const result = await client.get_round({ epoch });
+        const result = await client.get_round({ series_id: SERIES_ID, epoch });
         setError(null);
         return result.result as Round;
       } catch (err: any) {
@@ -229,7 +235,7 @@ export const usePredictionMarket = () => {
       try {
         setLoadingStates((prev) => ({ ...prev, userdata: true }));
         const result = await retryOperation(() =>
-          client.get_bet_info({ epoch, user })
+          client.get_bet_info({ series_id: SERIES_ID, epoch, user })
         );
         setError(null);
         return result.result as BetInfo;
@@ -252,7 +258,7 @@ export const usePredictionMarket = () => {
       try {
         setLoadingStates((prev) => ({ ...prev, userdata: true }));
         const result = await retryOperation(() =>
-          client.get_user_rounds({ user })
+          client.get_user_rounds({ series_id: SERIES_ID, user })
         );
         setError(null);
         return result.result as bigint[];
@@ -357,6 +363,7 @@ export const usePredictionMarket = () => {
         console.log("🎲 Step 2: Placing bet...");
 
         const tx = await client.bet_bull({
+          series_id: SERIES_ID,
           epoch,
           user: address,
           amount: amount,
@@ -412,6 +419,7 @@ export const usePredictionMarket = () => {
         console.log("🎲 Step 2: Placing bet...");
         // eslint-disable-next-line @typescript-eslint/no-explicit-any
         const tx = await client.bet_bear({
+          series_id: SERIES_ID,
           epoch,
           user: address,
           amount: amount as any, // Type assertion for i128
@@ -475,7 +483,9 @@ export const usePredictionMarket = () => {
     if (!client) return null;
 
     try {
-      const result = await retryOperation(() => client.get_interval_seconds());
+      const result = await retryOperation(() =>
+        client.get_interval_seconds({ series_id: SERIES_ID })
+      );
       return result.result as bigint;
     } catch (err) {
       console.error("Failed to fetch interval seconds:", err);